@@ -58,6 +58,7 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 self.grid_stroke_width = t.grid_stroke_width;
 
                 self.series_colors = t.series_colors.clone();
+                self.series_patterns = t.series_patterns.clone();
                 self.series_label_font_color = t.series_label_font_color;
                 self.series_label_font_size = t.series_label_font_size;
                 self.series_stroke_width = t.series_stroke_width;
@@ -70,7 +71,7 @@ pub fn my_default(input: TokenStream) -> TokenStream {
             /// Fills the options from json config.
             fn fill_option(&mut self, data: &str) -> canvas::Result<serde_json::Value> {
                 let data: serde_json::Value = serde_json::from_str(data)?;
-                let series_list = get_series_list_from_value(&data).unwrap_or_default();
+                let series_list = get_series_list_from_value(&data)?.unwrap_or_default();
                 let theme = get_string_from_value(&data, "theme").unwrap_or_default();
                 let theme = get_theme(&theme);
                 self.fill_theme(theme.clone());
@@ -97,6 +98,12 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 if let Some(title_text) = get_string_from_value(&data, "title_text") {
                     self.title_text = title_text;
                 }
+                if let Some(accessible) = get_bool_from_value(&data, "accessible") {
+                    self.accessible = Some(accessible);
+                }
+                if let Some(accessible_table) = get_bool_from_value(&data, "accessible_table") {
+                    self.accessible_table = Some(accessible_table);
+                }
                 if let Some(title_font_size) = get_f32_from_value(&data, "title_font_size") {
                     self.title_font_size = title_font_size;
                 }
@@ -223,6 +230,9 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 if let Some(series_colors) = get_color_slice_from_value(&data, "series_colors") {
                     self.series_colors = series_colors;
                 }
+                if let Some(series_patterns) = get_string_slice_from_value(&data, "series_patterns") {
+                    self.series_patterns = Some(series_patterns.iter().map(|item| item.as_str().into()).collect());
+                }
                 if let Some(series_symbol) = get_series_symbol_from_value(&data, "series_symbol") {
                     self.series_symbol = Some(series_symbol);
                 }
@@ -246,17 +256,34 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                     self.y_axis_configs[0].clone()
                 }
             }
-            /// Gets y axis values by index.
-            fn get_y_axis_values(&self, y_axis_index: usize) -> (AxisValues, f32) {
+            /// Gets y axis values by index, it returns error if the values are invalid for log axis.
+            fn get_y_axis_values(&self, y_axis_index: usize) -> canvas::Result<(AxisValues, f32)> {
                 let y_axis_config = self.get_y_axis_config(y_axis_index);
                 let mut data_list = vec![];
-                for series in self.series_list.iter() {
-                    if series.y_axis_index == y_axis_index {
+                let series_list: Vec<&Series> = self.series_list.iter().collect();
+                let stack_bases_list = get_series_stack_bases(&series_list);
+                for (index, series) in self.series_list.iter().enumerate() {
+                    if series.y_axis_index != y_axis_index {
+                        continue;
+                    }
+                    if series.stack.is_none() {
                         data_list.append(series.data.clone().as_mut());
+                        continue;
+                    }
+                    // stacked series use the sum of the previous series
+                    for (i, value) in series.data.iter().enumerate() {
+                        if *value == NIL_VALUE {
+                            data_list.push(NIL_VALUE);
+                        } else {
+                            data_list.push(value + stack_bases_list[index][i]);
+                        }
                     }
                 }
                 if data_list.is_empty() {
-                   return (AxisValues::default(), 0.0);
+                   return Ok((AxisValues::default(), 0.0));
+                }
+                if let Some(log_base) = y_axis_config.axis_log_base {
+                    check_log_axis_values(&data_list, log_base)?;
                 }
                 let mut thousands_format = false;
                 if let Some(ref value) = y_axis_config.axis_formatter {
@@ -269,6 +296,7 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                     min: y_axis_config.axis_min,
                     max: y_axis_config.axis_max,
                     thousands_format,
+                    log_base: y_axis_config.axis_log_base,
                 });
                 let y_axis_width = if let Some(value) = y_axis_config.axis_width {
                     value
@@ -286,7 +314,7 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                         DEFAULT_Y_AXIS_WIDTH
                     }
                 };
-                (y_axis_values, y_axis_width)
+                Ok((y_axis_values, y_axis_width))
             }
             /// Renders background for canvas.
             fn render_background(&self, c: Canvas) {
@@ -405,7 +433,13 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                         legend_left = 0.0;
                         legend_top += legend_unit_height;
                     }
-                    let b = legend_canvas.legend(Legend {
+                    let pattern = get_series_pattern(
+                        series,
+                        series.index.unwrap_or(index),
+                        &self.series_patterns,
+                        color,
+                    );
+                    let legend = Legend {
                         text: series.name.to_string(),
                         font_size: self.legend_font_size,
                         font_family: self.font_family.clone(),
@@ -416,7 +450,15 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                         left: legend_left,
                         top: legend_top,
                         category: self.legend_category.clone(),
-                    });
+                        pattern,
+                    };
+                    let b = if legend_canvas.interactive {
+                        legend_canvas
+                            .interactive_child(Interactive::new_legend(&series.name))
+                            .legend(legend)
+                    } else {
+                        legend_canvas.legend(legend)
+                    };
                     legend_left += b.width() + LEGEND_MARGIN;
                 }
 
@@ -427,7 +469,13 @@ pub fn my_default(input: TokenStream) -> TokenStream {
             fn render_grid(&self, c: Canvas, axis_width: f32, axis_height: f32) {
                 let mut c1 = c;
                 let y_axis_config = self.get_y_axis_config(0);
-                let axis_split_number = y_axis_config.axis_split_number;
+                let mut axis_split_number = y_axis_config.axis_split_number;
+                // the split number of log axis depends on the powers of base
+                if y_axis_config.axis_log_base.is_some() {
+                    if let Ok((y_axis_values, _)) = self.get_y_axis_values(0) {
+                        axis_split_number = y_axis_values.data.len().saturating_sub(1);
+                    }
+                }
                 c1.grid(Grid {
                     right: axis_width,
                     bottom: axis_height,
@@ -450,12 +498,16 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 if let Some(value) = &y_axis_config.axis_name_align {
                     name_align = value.clone();
                 }
+                let mut split_number = y_axis_config.axis_split_number;
+                if y_axis_config.axis_log_base.is_some() {
+                    split_number = data.len().saturating_sub(1);
+                }
                 let margin = y_axis_config.axis_margin.clone().unwrap_or_default();
                 c1.child(margin).axis(Axis {
                     position,
                     height: axis_height,
                     width: axis_width,
-                    split_number: y_axis_config.axis_split_number,
+                    split_number,
                     font_family: self.font_family.clone(),
                     stroke_color: Some(y_axis_config.axis_stroke_color),
                     name_align,
@@ -497,6 +549,28 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                     ..Default::default()
                 });
             }
+            /// Renders time x axis widget for canvas, the ticks and labels are placed
+            /// at the positions of time axis values.
+            fn render_time_x_axis(&self, c: Canvas, time_axis_values: &TimeAxisValues, axis_width: f32) {
+                let mut c1 = c;
+
+                let margin = self.x_axis_margin.clone().unwrap_or_default();
+                c1.child(margin).axis(Axis {
+                    height: self.x_axis_height,
+                    width: axis_width,
+                    split_number: time_axis_values.data.len(),
+                    font_family: self.font_family.clone(),
+                    data: time_axis_values.data.clone(),
+                    font_color: Some(self.x_axis_font_color),
+                    font_weight: self.x_axis_font_weight.clone(),
+                    stroke_color: Some(self.x_axis_stroke_color),
+                    font_size: self.x_axis_font_size,
+                    name_gap: self.x_axis_name_gap,
+                    name_rotate: self.x_axis_name_rotate,
+                    positions: time_axis_values.get_tick_positions(),
+                    ..Default::default()
+                });
+            }
             /// Renders series label widget for canvas.
             fn render_series_label(&self, c:Canvas, series_labels_list: Vec<Vec<SeriesLabel>>) {
                 if series_labels_list.is_empty() {
@@ -529,6 +603,7 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 }
             }
             /// Renders the bar widget for canvas.
+            #[allow(clippy::too_many_arguments)]
             fn render_bar(
                 &self,
                 c: Canvas,
@@ -537,19 +612,51 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 max_height: f32,
                 series_data_count: usize,
                 radius: Option<f32>,
+                x_axis_data: &[String],
+                time_axis_values: Option<&TimeAxisValues>,
             ) -> Vec<Vec<SeriesLabel>> {
                 if series_list.is_empty() {
                     return vec![];
                 }
                 let mut c1 = c;
 
-                let unit_width = c1.width() / series_data_count as f32;
+                let mut unit_width = c1.width() / series_data_count as f32;
+                if let Some(time_axis_values) = time_axis_values {
+                    // the bar width of time axis depends on the min distance of timestamps
+                    let mut timestamps = vec![];
+                    for series in series_list.iter() {
+                        if let Some(ref value) = series.timestamps {
+                            timestamps.extend(value.iter());
+                        }
+                    }
+                    unit_width = time_axis_values.get_min_unit_width(&timestamps, c1.width());
+                }
                 let bar_chart_margin = 5.0_f32;
                 let bar_chart_gap = 3.0_f32;
 
+                // the series of the same stack share one bar column
+                let stack_bases_list = get_series_stack_bases(series_list);
+                let mut stack_columns: Vec<(&str, usize, usize)> = vec![];
+                let mut series_columns = vec![];
+                let mut column_count = 0;
+                for series in series_list.iter() {
+                    if let Some(ref stack) = series.stack {
+                        if let Some(item) = stack_columns
+                            .iter()
+                            .find(|item| item.0 == stack.as_str() && item.1 == series.y_axis_index)
+                        {
+                            series_columns.push(item.2);
+                            continue;
+                        }
+                        stack_columns.push((stack.as_str(), series.y_axis_index, column_count));
+                    }
+                    series_columns.push(column_count);
+                    column_count += 1;
+                }
+
                 let bar_chart_margin_width = bar_chart_margin * 2.0;
-                let bar_chart_gap_width = bar_chart_gap * (series_list.len() - 1) as f32;
-                let bar_width = (unit_width - bar_chart_margin_width - bar_chart_gap_width) / series_list.len() as f32;
+                let bar_chart_gap_width = bar_chart_gap * (column_count - 1) as f32;
+                let bar_width = (unit_width - bar_chart_margin_width - bar_chart_gap_width) / column_count as f32;
                 let half_bar_width = bar_width / 2.0;
 
                 let mut series_labels_list = vec![];
@@ -571,6 +678,12 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                         y_axis_values_list[series.y_axis_index]
                     };
                     let color = get_color(&self.series_colors, series.index.unwrap_or(index));
+                    let series_paint = get_series_paint(
+                        series,
+                        series.index.unwrap_or(index),
+                        &self.series_patterns,
+                        color,
+                    );
                     let mut series_labels = vec![];
                     for (i, p) in series.data.iter().enumerate() {
                         let value = p.to_owned();
@@ -579,28 +692,65 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                             continue;
                         }
                         let mut left = unit_width * (i + series.start_index) as f32 + bar_chart_margin;
-                        left += (bar_width + bar_chart_gap) * index as f32;
-
-                        let y = y_axis_values.get_offset_height(value, max_height);
-
-                        let mut fill = get_bar_color(&series.colors, i);
-                        if fill.is_none() {
-                            fill = Some(color);
+                        if let Some(time_axis_values) = time_axis_values {
+                            // ignore the value without timestamp
+                            match series.timestamps.as_ref().and_then(|item| item.get(i)) {
+                                Some(timestamp) => {
+                                    // the bars are centered on timestamp, but they are kept inside the axis
+                                    let unit_left = time_axis_values.get_offset_width(*timestamp, c1.width()) - unit_width / 2.0;
+                                    left = unit_left.min(c1.width() - unit_width).max(0.0) + bar_chart_margin;
+                                },
+                                None => continue,
+                            }
                         }
+                        left += (bar_width + bar_chart_gap) * series_columns[index] as f32;
+
+                        let mut y = y_axis_values.get_offset_height(value, max_height);
+                        let mut height = max_height - y;
+                        if series.stack.is_some() {
+                            let base = stack_bases_list[index][i];
+                            let base_y = y_axis_values.get_offset_height(base, max_height);
+                            y = y_axis_values.get_offset_height(base + value, max_height);
+                            height = (base_y - y).abs();
+                            y = y.min(base_y);
+                        }
+
+                        // the color of item takes precedence over the gradient and pattern of series
+                        let fill: Paint = match (get_bar_color(&series.colors, i), &series_paint) {
+                            (Some(value), _) => value.into(),
+                            (None, Some(paint)) => paint.clone(),
+                            (None, None) => color.into(),
+                        };
 
-                        c1.rect(Rect {
-                            fill,
+                        let rect = Rect {
+                            paint: Some(fill),
                             left,
                             top: y,
                             width: bar_width,
-                            height: max_height - y,
+                            height,
                             rx: radius,
                             ry: radius,
                             ..Default::default()
-                        });
+                        };
+                        let text = format_series_value(value, &self.series_label_formatter);
+                        let mut rect_canvas = c1.clone();
+                        if c1.interactive {
+                            let category = get_series_category(series, i, x_axis_data, time_axis_values.is_some());
+                            rect_canvas = rect_canvas.interactive_child(Interactive::new_item(&series.name, category, format_float(value), &text));
+                        }
+                        if c1.animation {
+                            // the bar of negative value grows from top
+                            let position = if value < 0.0 {
+                                Position::Top
+                            } else {
+                                Position::Bottom
+                            };
+                            rect_canvas = rect_canvas.animation_child(Animation::Grow(position));
+                        }
+                        rect_canvas.rect(rect);
                         series_labels.push(SeriesLabel{
                             point: (left + half_bar_width, y).into(),
-                            text: format_series_value(p.to_owned(), &self.series_label_formatter),
+                            text,
                         })
                     }
                     if series.label_show {
@@ -610,6 +760,7 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 series_labels_list
             }
             /// Renders the line widget for canvas.
+            #[allow(clippy::too_many_arguments)]
             fn render_line(
                 &self,
                 c: Canvas,
@@ -618,6 +769,8 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                 max_height: f32,
                 axis_height: f32,
                 series_data_count: usize,
+                x_axis_data: &[String],
+                time_axis_values: Option<&TimeAxisValues>,
             ) -> Vec<Vec<SeriesLabel>> {
                 if series_list.is_empty() {
                     return vec![];
@@ -629,6 +782,7 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                     split_unit_offset = 1.0;
                 }
                 let mut series_labels_list = vec![];
+                let stack_bases_list = get_series_stack_bases(series_list);
 
                 for (index, series) in series_list.iter().enumerate() {
                     let y_axis_values = if series.y_axis_index >= y_axis_values_list.len() {
@@ -640,7 +794,12 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                     let unit_width = c1.width() / split_unit_count;
                     let mut points: Vec<Point> = vec![];
                     let mut points_list: Vec<Vec<Point>> = vec![];
+                    // the bottom points of stacked area
+                    let mut base_points: Vec<Point> = vec![];
+                    let mut base_points_list: Vec<Vec<Point>> = vec![];
                     let mut series_labels = vec![];
+                    // the hover items of interactive mode
+                    let mut interactive_items = vec![];
 
                     let mut max_value = f32::MIN;
                     let mut min_value = f32::MAX;
@@ -652,6 +811,8 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                             if !points.is_empty() {
                                 points_list.push(points);
                                 points = vec![];
+                                base_points_list.push(base_points);
+                                base_points = vec![];
                             }
                             continue;
                         }
@@ -668,11 +829,31 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                         if x_boundary_gap {
                             x += unit_width / 2.0;
                         }
-                        let y = y_axis_values.get_offset_height(value, max_height);
+                        if let Some(time_axis_values) = time_axis_values {
+                            // ignore the value without timestamp
+                            match series.timestamps.as_ref().and_then(|item| item.get(i)) {
+                                Some(timestamp) => x = time_axis_values.get_offset_width(*timestamp, c1.width()),
+                                None => continue,
+                            }
+                        }
+                        let mut y = y_axis_values.get_offset_height(value, max_height);
+                        if series.stack.is_some() {
+                            let base = stack_bases_list[index][i];
+                            y = y_axis_values.get_offset_height(base + value, max_height);
+                            base_points.push((x, y_axis_values.get_offset_height(base, max_height)).into());
+                        }
                         points.push((x, y).into());
+                        let text = format_series_value(value, &self.series_label_formatter);
+                        if c1.interactive {
+                            let category = get_series_category(series, i, x_axis_data, time_axis_values.is_some());
+                            interactive_items.push((
+                                Point::from((x, y)),
+                                Interactive::new_item(&series.name, category, format_float(value), &text),
+                            ));
+                        }
                         series_labels.push(SeriesLabel{
                             point: (x, y).into(),
-                            text: format_series_value(value, &self.series_label_formatter),
+                            text,
                         })
                     }
                     if series.label_show {
@@ -680,47 +861,97 @@ pub fn my_default(input: TokenStream) -> TokenStream {
                     }
                     if !points.is_empty() {
                         points_list.push(points);
+                        base_points_list.push(base_points);
                     }
 
                     let color = get_color(&self.series_colors, series.index.unwrap_or(index));
 
-                    let fill = color.with_alpha(100);
+                    let fill = get_series_paint(
+                        series,
+                        series.index.unwrap_or(index),
+                        &self.series_patterns,
+                        color,
+                    )
+                    .unwrap_or_else(|| color.with_alpha(100).into());
                     let series_fill = self.series_fill;
-                    for points in points_list.iter() {
-                        if self.series_smooth {
-                            if series_fill {
-                                c1.smooth_line_fill(SmoothLineFill {
-                                    fill,
+                    let mut series_canvas = if c1.interactive {
+                        c1.interactive_child(Interactive::new_series(&series.name))
+                    } else {
+                        c1.clone()
+                    };
+                    // the dashed line fades in instead of drawing in
+                    let draw_animation = c1.animation && series.stroke_dash_array.is_none();
+                    for (points, base_points) in points_list.iter().zip(base_points_list.iter()) {
+                        if series_fill {
+                            let mut fill_canvas = if c1.animation {
+                                series_canvas.animation_child(Animation::Fade)
+                            } else {
+                                series_canvas.clone()
+                            };
+                            if self.series_smooth {
+                                fill_canvas.smooth_line_fill(SmoothLineFill {
+                                    fill: fill.color(),
+                                    paint: Some(fill.clone()),
+                                    points: points.clone(),
+                                    bottom: axis_height,
+                                    base_points: base_points.clone(),
+                                });
+                            } else {
+                                fill_canvas.straight_line_fill(StraightLineFill {
+                                    fill: fill.color(),
+                                    paint: Some(fill.clone()),
                                     points: points.clone(),
                                     bottom: axis_height,
+                                    base_points: base_points.clone(),
+                                    ..Default::default()
                                 });
                             }
-                            c1.smooth_line(SmoothLine {
+                        }
+                        if self.series_smooth {
+                            let line = SmoothLine {
                                 points: points.clone(),
                                 color: Some(color),
                                 stroke_width: self.series_stroke_width,
                                 symbol: self.series_symbol.clone(),
                                 stroke_dash_array: series.stroke_dash_array.clone(),
-                            });
+                            };
+                            let mut line_canvas = if draw_animation {
+                                series_canvas.animation_child(Animation::Draw { length: line.length() })
+                            } else if c1.animation {
+                                series_canvas.animation_child(Animation::Fade)
+                            } else {
+                                series_canvas.clone()
+                            };
+                            line_canvas.smooth_line(line);
                         } else {
-                            if series_fill {
-                                c1.straight_line_fill(StraightLineFill {
-                                    fill,
-                                    points: points.clone(),
-                                    bottom: axis_height,
-                                    ..Default::default()
-                                });
-                            }
-                            c1.straight_line(StraightLine {
+                            let line = StraightLine {
                                 points: points.clone(),
                                 color: Some(color),
                                 stroke_width: self.series_stroke_width,
                                 symbol: self.series_symbol.clone(),
                                 stroke_dash_array: series.stroke_dash_array.clone(),
                                 ..Default::default()
-                            });
+                            };
+                            let mut line_canvas = if draw_animation {
+                                series_canvas.animation_child(Animation::Draw { length: line.length() })
+                            } else if c1.animation {
+                                series_canvas.animation_child(Animation::Fade)
+                            } else {
+                                series_canvas.clone()
+                            };
+                            line_canvas.straight_line(line);
                         }
                     }
+                    // the transparent circle is the hover area of the point
+                    for (point, interactive) in interactive_items {
+                        c1.interactive_child(interactive).circle(Circle {
+                            fill: Some(color.with_alpha(0)),
+                            cx: point.x,
+                            cy: point.y,
+                            r: 8.0,
+                            ..Default::default()
+                        });
+                    }
                     for mark_point in series.mark_points.iter() {
                         let index = match mark_point.category {
                             MarkPointCategory::Max => max_index,
@@ -3,6 +3,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 mod bar_chart;
+mod box_plot_chart;
 mod candlestick_chart;
 mod canvas;
 mod color;
@@ -11,51 +12,75 @@ mod component;
 #[cfg(feature = "image-encoder")]
 mod encoder;
 mod font;
+mod funnel_chart;
+mod gauge_chart;
 mod heatmap_chart;
+mod histogram_chart;
 mod horizontal_bar_chart;
 mod line_chart;
 mod multi_chart;
 mod params;
 mod path;
+#[cfg(feature = "pdf-encoder")]
+mod pdf;
 mod pie_chart;
 mod radar_chart;
+mod sankey_chart;
 mod scatter_chart;
 mod table_chart;
 mod theme;
+mod treemap_chart;
 mod util;
 
 pub use bar_chart::BarChart;
+pub use box_plot_chart::{BoxPlotChart, BoxPlotData};
 pub use canvas::Canvas;
 pub use canvas::Error as CanvasError;
 pub use canvas::Result as CanvasResult;
 pub use color::*;
 pub use common::*;
 pub use component::{
-    Axis, Circle, Grid, Legend, LegendCategory, Line, Pie, Polygon, Polyline, Rect, SmoothLine,
-    SmoothLineFill, StraightLine, StraightLineFill, Text,
+    Accessibility, Animation, Axis, BezierBand, Circle, Grid, Interactive, Legend, LegendCategory,
+    Line, Pie, Polygon, Polyline, Rect, SmoothLine, SmoothLineFill, StraightLine, StraightLineFill,
+    Text, VisualMap,
 };
 #[cfg(feature = "image-encoder")]
-pub(crate) use encoder::get_or_init_fontdb;
-#[cfg(feature = "image-encoder")]
 pub use encoder::Error as EncoderError;
 #[cfg(feature = "image-encoder")]
 pub use encoder::*;
 
 pub use candlestick_chart::CandlestickChart;
+#[cfg(feature = "system-fonts")]
+pub use font::load_system_fonts;
 pub use font::Error as FontError;
 pub use font::{
-    get_font, get_font_families, get_or_try_init_fonts, measure_text_width_family,
+    add_font, get_font, get_font_arc, get_font_families, get_font_with_weight, get_fonts,
+    get_or_try_init_font_registry, get_or_try_init_fonts, measure_text, measure_text_fonts,
+    measure_text_width_family, measure_text_width_family_weight, remove_font, FontRegistry,
     DEFAULT_FONT_DATA, DEFAULT_FONT_FAMILY,
 };
-pub use heatmap_chart::{HeatmapChart, HeatmapData, HeatmapSeries};
+pub use funnel_chart::{FunnelChart, FunnelSort};
+pub use gauge_chart::{GaugeBand, GaugeChart};
+pub use heatmap_chart::{HeatmapCalendar, HeatmapChart, HeatmapData, HeatmapSeries};
+pub use histogram_chart::{
+    get_histogram_counts, get_histogram_edges, HistogramBin, HistogramChart,
+};
 pub use horizontal_bar_chart::HorizontalBarChart;
 pub use line_chart::LineChart;
-pub use multi_chart::{ChildChart, MultiChart};
+pub use multi_chart::{ChildChart, GridCell, GridLayout, MultiChart};
 pub use path::*;
+#[cfg(feature = "pdf-encoder")]
+pub use pdf::Error as PdfError;
+#[cfg(feature = "pdf-encoder")]
+pub use pdf::{svg_to_pdf, svgs_to_pdf};
 pub use pie_chart::PieChart;
 pub use radar_chart::{RadarChart, RadarIndicator};
-pub use scatter_chart::ScatterChart;
+pub use sankey_chart::{SankeyChart, SankeyLink};
+pub use scatter_chart::{ScatterBubble, ScatterChart};
 pub use table_chart::{TableCellStyle, TableChart};
 pub use theme::Theme;
-pub use theme::{add_theme, get_theme, list_theme_name, THEME_ANT, THEME_DARK, THEME_GRAFANA};
+pub use theme::{
+    add_theme, get_theme, list_theme_name, THEME_ANT, THEME_DARK, THEME_GRAFANA, THEME_PRINT,
+};
+pub use treemap_chart::{TreemapChart, TreemapNode};
 pub use util::*;
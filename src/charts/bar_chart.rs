@@ -66,6 +66,9 @@ pub struct BarChart {
     pub x_axis_margin: Option<Box>,
     pub x_axis_hidden: bool,
     pub x_boundary_gap: Option<bool>,
+    // time x axis, the timestamps of series are used instead of x axis data
+    pub x_axis_time: Option<bool>,
+    pub x_axis_time_format: Option<String>,
 
     // y axis
     pub y_axis_hidden: bool,
@@ -82,11 +85,20 @@ pub struct BarChart {
     pub series_label_font_weight: Option<String>,
     pub series_label_formatter: String,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
 
     pub radius: Option<f32>,
+    // tooltip of each bar and point, the legend toggles its series
+    pub interactive: Option<bool>,
+    // bars grow from the axis and lines draw in
+    pub animation: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the values of each series, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl BarChart {
@@ -99,12 +111,24 @@ impl BarChart {
         if let Some(x_axis_hidden) = get_bool_from_value(&value, "x_axis_hidden") {
             b.x_axis_hidden = x_axis_hidden;
         }
+        if let Some(x_axis_time) = get_bool_from_value(&value, "x_axis_time") {
+            b.x_axis_time = Some(x_axis_time);
+        }
+        if let Some(x_axis_time_format) = get_string_from_value(&value, "x_axis_time_format") {
+            b.x_axis_time_format = Some(x_axis_time_format);
+        }
         if let Some(y_axis_hidden) = get_bool_from_value(&value, "y_axis_hidden") {
             b.y_axis_hidden = y_axis_hidden;
         }
         if let Some(radius) = get_f32_from_value(&value, "radius") {
             b.radius = Some(radius);
         }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            b.interactive = Some(interactive);
+        }
+        if let Some(animation) = get_bool_from_value(&value, "animation") {
+            b.animation = Some(animation);
+        }
         Ok(b)
     }
     /// Creates a bar chart with custom theme.
@@ -137,6 +161,16 @@ impl BarChart {
     /// Converts bar chart to svg.
     pub fn svg(&self) -> canvas::Result<String> {
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.animation = self.animation.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
 
         self.render_background(c.child(Box::default()));
         let mut x_axis_height = self.x_axis_height;
@@ -155,7 +189,7 @@ impl BarChart {
             title_height
         };
 
-        let (left_y_axis_values, mut left_y_axis_width) = self.get_y_axis_values(0);
+        let (left_y_axis_values, mut left_y_axis_width) = self.get_y_axis_values(0)?;
         if self.y_axis_hidden {
             left_y_axis_width = 0.0;
         }
@@ -169,7 +203,7 @@ impl BarChart {
         let mut right_y_axis_values = AxisValues::default();
         let mut right_y_axis_width = 0.0_f32;
         if !self.y_axis_hidden && exist_right_y_axis {
-            (right_y_axis_values, right_y_axis_width) = self.get_y_axis_values(1);
+            (right_y_axis_values, right_y_axis_width) = self.get_y_axis_values(1)?;
         }
 
         let axis_height = c.height() - x_axis_height - axis_top;
@@ -216,17 +250,27 @@ impl BarChart {
         }
 
         // x axis
+        let time_axis_values = if self.x_axis_time.unwrap_or_default() {
+            Some(get_series_time_axis_values(
+                &self.series_list,
+                self.x_axis_time_format.clone(),
+                true,
+            ))
+        } else {
+            None
+        };
         if !self.x_axis_hidden {
-            self.render_x_axis(
-                c.child(Box {
-                    top: c.height() - x_axis_height,
-                    left: left_y_axis_width,
-                    right: right_y_axis_width,
-                    ..Default::default()
-                }),
-                self.x_axis_data.clone(),
-                axis_width,
-            );
+            let x_axis_canvas = c.child(Box {
+                top: c.height() - x_axis_height,
+                left: left_y_axis_width,
+                right: right_y_axis_width,
+                ..Default::default()
+            });
+            if let Some(ref time_axis_values) = time_axis_values {
+                self.render_time_x_axis(x_axis_canvas, time_axis_values, axis_width);
+            } else {
+                self.render_x_axis(x_axis_canvas, self.x_axis_data.clone(), axis_width);
+            }
         }
 
         // bar point
@@ -256,6 +300,8 @@ impl BarChart {
             max_height,
             self.x_axis_data.len(),
             self.radius,
+            &self.x_axis_data,
+            time_axis_values.as_ref(),
         );
 
         let mut line_series_labels_list = self.render_line(
@@ -269,6 +315,8 @@ impl BarChart {
             max_height,
             axis_height,
             self.x_axis_data.len(),
+            &self.x_axis_data,
+            time_axis_values.as_ref(),
         );
 
         bar_series_labels_list.append(&mut line_series_labels_list);
@@ -290,7 +338,8 @@ impl BarChart {
 mod tests {
     use super::BarChart;
     use crate::{
-        Box, LegendCategory, SeriesCategory, NIL_VALUE, THEME_ANT, THEME_DARK, THEME_GRAFANA,
+        Box, Color, Gradient, LegendCategory, SeriesCategory, NIL_VALUE, THEME_ANT, THEME_DARK,
+        THEME_GRAFANA, THEME_PRINT,
     };
     use pretty_assertions::assert_eq;
     #[test]
@@ -612,6 +661,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bar_chart_interactive() {
+        let mut bar_chart = BarChart::new(
+            vec![
+                ("Email", vec![120.0, -132.0, 101.0, 134.0, 90.0]).into(),
+                ("Union Ads", vec![220.0, 182.0, 191.0, 234.0, 290.0]).into(),
+                ("Direct", vec![320.0, 332.0, 301.0, 334.0, 390.0]).into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+            ],
+        );
+        bar_chart.series_list[2].category = Some(SeriesCategory::Line);
+        bar_chart.title_text = "Bar Chart".to_string();
+        bar_chart.legend_margin = Some(Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        bar_chart.interactive = Some(true);
+        bar_chart.animation = Some(true);
+        assert_eq!(
+            include_str!("../../asset/bar_chart/interactive.svg"),
+            bar_chart.svg().unwrap()
+        );
+    }
+    #[test]
+    fn bar_chart_gradient() {
+        let mut bar_chart = BarChart::new(
+            vec![
+                ("Email", vec![120.0, 132.0, 101.0, 134.0, 90.0]).into(),
+                ("Union Ads", vec![220.0, 182.0, 191.0, 234.0, 290.0]).into(),
+                ("Direct", vec![320.0, 332.0, 301.0, 334.0, 390.0]).into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+            ],
+        );
+        bar_chart.series_list[0].gradient = Some(Gradient::new_linear(vec![
+            (0.0, Color::from("#83bff6")).into(),
+            (1.0, Color::from("#188df0")).into(),
+        ]));
+        bar_chart.series_list[2].category = Some(SeriesCategory::Line);
+        bar_chart.series_list[2].gradient = Some(Gradient::new_linear(vec![
+            (0.0, Color::from("#fac858").with_alpha(200)).into(),
+            (1.0, Color::from("#fac858").with_alpha(0)).into(),
+        ]));
+        bar_chart.series_fill = true;
+        bar_chart.title_text = "Bar Chart".to_string();
+        bar_chart.legend_margin = Some(Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            include_str!("../../asset/bar_chart/gradient.svg"),
+            bar_chart.svg().unwrap()
+        );
+    }
+    #[test]
+    fn bar_chart_pattern() {
+        let mut bar_chart = BarChart::new_with_theme(
+            vec![
+                ("Email", vec![120.0, 132.0, 101.0, 134.0, 90.0]).into(),
+                ("Union Ads", vec![220.0, 182.0, 191.0, 234.0, 290.0]).into(),
+                ("Direct", vec![320.0, 332.0, 301.0, 334.0, 390.0]).into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+            ],
+            THEME_PRINT,
+        );
+        bar_chart.title_text = "Bar Chart".to_string();
+        bar_chart.legend_category = LegendCategory::Rect;
+        bar_chart.legend_margin = Some(Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            include_str!("../../asset/bar_chart/pattern.svg"),
+            bar_chart.svg().unwrap()
+        );
+    }
     #[test]
     fn bar_chart_two_y_axis() {
         let mut bar_chart = BarChart::new(
@@ -802,4 +947,55 @@ mod tests {
             bar_chart.svg().unwrap()
         );
     }
+    #[test]
+    fn bar_chart_stack() {
+        let mut bar_chart = BarChart::new(
+            vec![
+                (
+                    "Email",
+                    vec![120.0, 132.0, 101.0, 134.0, 90.0, 230.0, 210.0],
+                )
+                    .into(),
+                (
+                    "Union Ads",
+                    vec![220.0, 182.0, 191.0, 234.0, 290.0, 330.0, 310.0],
+                )
+                    .into(),
+                (
+                    "Direct",
+                    vec![320.0, 332.0, 301.0, 334.0, 390.0, 330.0, 320.0],
+                )
+                    .into(),
+                (
+                    "Search Engine",
+                    vec![820.0, 932.0, 901.0, 934.0, 1290.0, 1330.0, 1320.0],
+                )
+                    .into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+                "Sat".to_string(),
+                "Sun".to_string(),
+            ],
+        );
+        bar_chart.y_axis_configs[0].axis_width = Some(55.0);
+        bar_chart.title_text = "Stacked Bar Chart".to_string();
+        bar_chart.legend_margin = Some(Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        bar_chart.series_list[0].stack = Some("ad".to_string());
+        bar_chart.series_list[1].stack = Some("ad".to_string());
+        bar_chart.series_list[2].stack = Some("ad".to_string());
+        bar_chart.series_list[1].label_show = true;
+        assert_eq!(
+            include_str!("../../asset/bar_chart/stack.svg"),
+            bar_chart.svg().unwrap()
+        );
+    }
 }
@@ -0,0 +1,629 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::canvas;
+use super::color::*;
+use super::common::*;
+use super::component::*;
+use super::params::*;
+use super::theme::{get_default_theme_name, get_theme, Theme, DEFAULT_Y_AXIS_WIDTH};
+use super::util::*;
+use super::Canvas;
+use crate::charts::measure_text_width_family;
+use charts_rs_derive::Chart;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The statistics of box plot, the whiskers are computed by the iqr rule:
+/// the value out of [q1 - 1.5 * iqr, q3 + 1.5 * iqr] is an outlier.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct BoxPlotData {
+    pub lower_whisker: f32,
+    pub q1: f32,
+    pub median: f32,
+    pub q3: f32,
+    pub upper_whisker: f32,
+    pub outliers: Vec<f32>,
+}
+
+impl BoxPlotData {
+    /// Computes the box plot from raw samples, the nil value is ignored.
+    pub fn from_samples(samples: &[f32]) -> Option<BoxPlotData> {
+        let mut values: Vec<f32> = samples
+            .iter()
+            .filter(|item| **item != NIL_VALUE && !item.is_nan())
+            .copied()
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(|a, b| a.total_cmp(b));
+        let q1 = get_quantile(&values, 0.25);
+        let q3 = get_quantile(&values, 0.75);
+        let iqr = q3 - q1;
+        let lower_fence = q1 - 1.5 * iqr;
+        let upper_fence = q3 + 1.5 * iqr;
+        let mut lower_whisker = q1;
+        let mut upper_whisker = q3;
+        let mut outliers = vec![];
+        for value in values.iter() {
+            if *value < lower_fence || *value > upper_fence {
+                outliers.push(*value);
+                continue;
+            }
+            lower_whisker = lower_whisker.min(*value);
+            upper_whisker = upper_whisker.max(*value);
+        }
+        Some(BoxPlotData {
+            lower_whisker,
+            q1,
+            median: get_quantile(&values, 0.5),
+            q3,
+            upper_whisker,
+            outliers,
+        })
+    }
+    /// Creates the box plot from precomputed quartiles, the whisker ends at the fence
+    /// of iqr rule if the min or max value is out of it, and the value is an outlier.
+    pub fn from_quartiles(min: f32, q1: f32, median: f32, q3: f32, max: f32) -> BoxPlotData {
+        let iqr = q3 - q1;
+        let lower_fence = q1 - 1.5 * iqr;
+        let upper_fence = q3 + 1.5 * iqr;
+        let mut outliers = vec![];
+        if min < lower_fence {
+            outliers.push(min);
+        }
+        if max > upper_fence {
+            outliers.push(max);
+        }
+        BoxPlotData {
+            lower_whisker: min.max(lower_fence),
+            q1,
+            median,
+            q3,
+            upper_whisker: max.min(upper_fence),
+            outliers,
+        }
+    }
+    /// Gets the values of [min, q1, median, q3, max], the outliers are included.
+    pub fn summary(&self) -> [f32; 5] {
+        let mut min = self.lower_whisker;
+        let mut max = self.upper_whisker;
+        for value in self.outliers.iter() {
+            min = min.min(*value);
+            max = max.max(*value);
+        }
+        [min, self.q1, self.median, self.q3, max]
+    }
+}
+
+/// Gets the raw samples of each category from serde json.
+fn get_samples_from_value(value: &serde_json::Value) -> Vec<Vec<f32>> {
+    let Some(samples) = value.get("samples").and_then(|item| item.as_array()) else {
+        return vec![];
+    };
+    samples
+        .iter()
+        .map(|item| {
+            item.as_array()
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(|v| v.as_f64())
+                        .map(|v| v as f32)
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Chart)]
+pub struct BoxPlotChart {
+    pub width: f32,
+    pub height: f32,
+    pub x: f32,
+    pub y: f32,
+    pub margin: Box,
+    // [min1, q1, median1, q3, max1, min2, q1, median2, q3, max2, ...]
+    pub series_list: Vec<Series>,
+    // raw samples of each series by category, the box is computed from samples if it's set
+    pub series_samples: Vec<Vec<Vec<f32>>>,
+    pub font_family: String,
+    pub background_color: Color,
+    pub is_light: bool,
+
+    // title
+    pub title_text: String,
+    pub title_font_size: f32,
+    pub title_font_color: Color,
+    pub title_font_weight: Option<String>,
+    pub title_margin: Option<Box>,
+    pub title_align: Align,
+    pub title_height: f32,
+
+    // sub title
+    pub sub_title_text: String,
+    pub sub_title_font_size: f32,
+    pub sub_title_font_color: Color,
+    pub sub_title_font_weight: Option<String>,
+    pub sub_title_margin: Option<Box>,
+    pub sub_title_align: Align,
+    pub sub_title_height: f32,
+
+    // legend
+    pub legend_font_size: f32,
+    pub legend_font_color: Color,
+    pub legend_font_weight: Option<String>,
+    pub legend_align: Align,
+    pub legend_margin: Option<Box>,
+    pub legend_category: LegendCategory,
+    pub legend_show: Option<bool>,
+
+    // x axis
+    pub x_axis_data: Vec<String>,
+    pub x_axis_height: f32,
+    pub x_axis_stroke_color: Color,
+    pub x_axis_font_size: f32,
+    pub x_axis_font_color: Color,
+    pub x_axis_font_weight: Option<String>,
+    pub x_axis_name_gap: f32,
+    pub x_axis_name_rotate: f32,
+    pub x_axis_margin: Option<Box>,
+    pub x_axis_hidden: bool,
+    pub x_boundary_gap: Option<bool>,
+
+    // y axis
+    pub y_axis_hidden: bool,
+    pub y_axis_configs: Vec<YAxisConfig>,
+
+    // grid
+    pub grid_stroke_color: Color,
+    pub grid_stroke_width: f32,
+
+    // series
+    pub series_stroke_width: f32,
+    pub series_label_font_color: Color,
+    pub series_label_font_size: f32,
+    pub series_label_font_weight: Option<String>,
+    pub series_label_formatter: String,
+    pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
+    pub series_symbol: Option<Symbol>,
+    pub series_smooth: bool,
+    pub series_fill: bool,
+
+    // tooltip of the quartiles and outliers of each box
+    pub interactive: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of min, Q1, median, Q3 and max per box, it implies accessible
+    pub accessible_table: Option<bool>,
+}
+
+impl BoxPlotChart {
+    /// Sets the data of series from samples, so the axis is counted by min and max samples.
+    fn fill_samples_data(&mut self) {
+        for (index, series) in self.series_list.iter_mut().enumerate() {
+            let Some(samples) = self.series_samples.get(index) else {
+                continue;
+            };
+            if samples.is_empty() {
+                continue;
+            }
+            series.data = samples
+                .iter()
+                .flat_map(|item| {
+                    BoxPlotData::from_samples(item)
+                        .map(|data| data.summary())
+                        .unwrap_or([NIL_VALUE; 5])
+                })
+                .collect();
+        }
+    }
+    /// Gets the box plot data list of series.
+    fn get_box_plot_data_list(&self, index: usize) -> Vec<Option<BoxPlotData>> {
+        if let Some(samples) = self.series_samples.get(index) {
+            if !samples.is_empty() {
+                return samples
+                    .iter()
+                    .map(|item| BoxPlotData::from_samples(item))
+                    .collect();
+            }
+        }
+        let Some(series) = self.series_list.get(index) else {
+            return vec![];
+        };
+        series
+            .data
+            .chunks(5)
+            .map(|chunk| {
+                if chunk.len() != 5 || chunk.contains(&NIL_VALUE) {
+                    return None;
+                }
+                Some(BoxPlotData::from_quartiles(
+                    chunk[0], chunk[1], chunk[2], chunk[3], chunk[4],
+                ))
+            })
+            .collect()
+    }
+    /// Creates a box plot chart from json, the series support
+    /// raw samples(`samples`) or precomputed quartiles(`data`).
+    pub fn from_json(data: &str) -> canvas::Result<BoxPlotChart> {
+        let mut value: serde_json::Value = serde_json::from_str(data)?;
+        let mut series_samples = vec![];
+        if let Some(series_list) = value
+            .get_mut("series_list")
+            .and_then(|item| item.as_array_mut())
+        {
+            for series in series_list.iter_mut() {
+                let samples = get_samples_from_value(series);
+                if !samples.is_empty() {
+                    // the data of series is the summary of samples, so it's not ignored
+                    series["data"] = samples
+                        .iter()
+                        .flat_map(|item| {
+                            BoxPlotData::from_samples(item)
+                                .map(|data| data.summary())
+                                .unwrap_or([NIL_VALUE; 5])
+                        })
+                        .map(|v| {
+                            if v == NIL_VALUE {
+                                serde_json::Value::Null
+                            } else {
+                                v.into()
+                            }
+                        })
+                        .collect();
+                }
+                // the series without data is ignored
+                let data = get_f32_slice_from_value(series, "data").unwrap_or_default();
+                if !data.is_empty() {
+                    series_samples.push(samples);
+                }
+            }
+        }
+        let mut b = BoxPlotChart {
+            series_samples,
+            ..Default::default()
+        };
+        let value = b.fill_option(&value.to_string())?;
+        if let Some(x_axis_hidden) = get_bool_from_value(&value, "x_axis_hidden") {
+            b.x_axis_hidden = x_axis_hidden;
+        }
+        if let Some(y_axis_hidden) = get_bool_from_value(&value, "y_axis_hidden") {
+            b.y_axis_hidden = y_axis_hidden;
+        }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            b.interactive = Some(interactive);
+        }
+        Ok(b)
+    }
+    /// Creates a box plot chart with custom theme,
+    /// the data of series is [min, q1, median, q3, max] of each category.
+    pub fn new_with_theme(
+        mut series_list: Vec<Series>,
+        x_axis_data: Vec<String>,
+        theme: &str,
+    ) -> BoxPlotChart {
+        // set the index of series
+        series_list
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, item)| {
+                item.index = Some(index);
+            });
+        let mut b = BoxPlotChart {
+            series_list,
+            x_axis_data,
+            ..Default::default()
+        };
+        let theme = get_theme(theme);
+        b.fill_theme(theme);
+        b
+    }
+    /// Creates a box plot chart with default theme.
+    pub fn new(series_list: Vec<Series>, x_axis_data: Vec<String>) -> BoxPlotChart {
+        BoxPlotChart::new_with_theme(series_list, x_axis_data, &get_default_theme_name())
+    }
+    /// Creates a box plot chart from raw samples of each category with default theme.
+    pub fn new_with_samples(
+        samples_list: Vec<(&str, Vec<Vec<f32>>)>,
+        x_axis_data: Vec<String>,
+    ) -> BoxPlotChart {
+        let series_list = samples_list
+            .iter()
+            .map(|(name, _)| Series::new(name.to_string(), vec![]))
+            .collect();
+        let mut b = BoxPlotChart::new(series_list, x_axis_data);
+        b.series_samples = samples_list
+            .into_iter()
+            .map(|(_, samples)| samples)
+            .collect();
+        b.fill_samples_data();
+        b
+    }
+    /// Converts box plot chart to svg.
+    pub fn svg(&self) -> canvas::Result<String> {
+        // the whiskers and quartiles are drawn on linear axis
+        if let Some(log_base) = self.get_y_axis_config(0).axis_log_base {
+            return Err(canvas::Error::Params {
+                message: format!("box plot chart does not support log axis, got {log_base}"),
+            });
+        }
+        let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.accessibility = get_series_point_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            &self.x_axis_data,
+            &["Min", "Q1", "Median", "Q3", "Max"],
+            self.accessible,
+            self.accessible_table,
+        );
+
+        self.render_background(c.child(Box::default()));
+        let mut x_axis_height = self.x_axis_height;
+        if self.x_axis_hidden {
+            x_axis_height = 0.0;
+        }
+        c.margin = self.margin.clone();
+
+        let title_height = self.render_title(c.child(Box::default()));
+
+        let legend_height = self.render_legend(c.child(Box::default()));
+        // get the max height of title and legend
+        let axis_top = if legend_height > title_height {
+            legend_height
+        } else {
+            title_height
+        };
+
+        let (left_y_axis_values, mut left_y_axis_width) = self.get_y_axis_values(0)?;
+        if self.y_axis_hidden {
+            left_y_axis_width = 0.0;
+        }
+
+        let axis_height = c.height() - x_axis_height - axis_top;
+        let axis_width = c.width() - left_y_axis_width;
+        // minus the height of top text area
+        if axis_top > 0.0 {
+            c = c.child(Box {
+                top: axis_top,
+                ..Default::default()
+            });
+        }
+
+        self.render_grid(
+            c.child(Box {
+                left: left_y_axis_width,
+                ..Default::default()
+            }),
+            axis_width,
+            axis_height,
+        );
+
+        // y axis
+        if !self.y_axis_hidden {
+            self.render_y_axis(
+                c.child(Box::default()),
+                left_y_axis_values.data.clone(),
+                axis_height,
+                left_y_axis_width,
+                0,
+            );
+        }
+
+        // x axis
+        if !self.x_axis_hidden {
+            self.render_x_axis(
+                c.child(Box {
+                    top: c.height() - x_axis_height,
+                    left: left_y_axis_width,
+                    ..Default::default()
+                }),
+                self.x_axis_data.clone(),
+                axis_width,
+            );
+        }
+        if self.x_axis_data.is_empty() || self.series_list.is_empty() {
+            return c.svg();
+        }
+
+        let chunk_width = axis_width / self.x_axis_data.len() as f32;
+        // the boxes of series are side by side in 60% of chunk
+        let unit_width = chunk_width * 0.6 / self.series_list.len() as f32;
+        let box_width = unit_width * 0.8;
+        for (index, series) in self.series_list.iter().enumerate() {
+            let color = get_color(&self.series_colors, series.index.unwrap_or(index));
+            let fill = get_series_paint(
+                series,
+                series.index.unwrap_or(index),
+                &self.series_patterns,
+                color,
+            )
+            .unwrap_or(color.with_alpha(50).into());
+            for (i, item) in self.get_box_plot_data_list(index).iter().enumerate() {
+                let Some(item) = item else {
+                    continue;
+                };
+                if i >= self.x_axis_data.len() {
+                    break;
+                }
+                let left = chunk_width * i as f32
+                    + chunk_width * 0.2
+                    + unit_width * index as f32
+                    + (unit_width - box_width) / 2.0;
+                let center = left + box_width / 2.0;
+                let cap_width = box_width / 2.0;
+                let get_y = |value: f32| left_y_axis_values.get_offset_height(value, axis_height);
+
+                let mut box_canvas = c.child(Box {
+                    left: left_y_axis_width,
+                    ..Default::default()
+                });
+                if box_canvas.interactive {
+                    let values = [
+                        item.lower_whisker,
+                        item.q1,
+                        item.median,
+                        item.q3,
+                        item.upper_whisker,
+                    ]
+                    .map(format_float);
+                    let mut text = format!(
+                        "min: {}, Q1: {}, median: {}, Q3: {}, max: {}",
+                        values[0], values[1], values[2], values[3], values[4]
+                    );
+                    if !item.outliers.is_empty() {
+                        let outliers: Vec<String> =
+                            item.outliers.iter().map(|v| format_float(*v)).collect();
+                        text = format!("{text}, outliers: {}", outliers.join(" "));
+                    }
+                    box_canvas = box_canvas.interactive_child(Interactive::new_item(
+                        &series.name,
+                        self.x_axis_data.get(i).cloned(),
+                        values.join(","),
+                        &text,
+                    ));
+                }
+                // whiskers and caps
+                for (from, to) in [(item.q1, item.lower_whisker), (item.q3, item.upper_whisker)] {
+                    box_canvas.line(Line {
+                        color: Some(color),
+                        stroke_width: 1.0,
+                        left: center,
+                        top: get_y(from),
+                        right: center,
+                        bottom: get_y(to),
+                        ..Default::default()
+                    });
+                    box_canvas.line(Line {
+                        color: Some(color),
+                        stroke_width: 1.0,
+                        left: center - cap_width / 2.0,
+                        top: get_y(to),
+                        right: center + cap_width / 2.0,
+                        bottom: get_y(to),
+                        ..Default::default()
+                    });
+                }
+                let top = get_y(item.q3);
+                box_canvas.rect(Rect {
+                    color: Some(color),
+                    paint: Some(fill.clone()),
+                    left,
+                    top,
+                    width: box_width,
+                    height: (get_y(item.q1) - top).max(1.0),
+                    ..Default::default()
+                });
+                box_canvas.line(Line {
+                    color: Some(color),
+                    stroke_width: 2.0,
+                    left,
+                    top: get_y(item.median),
+                    right: left + box_width,
+                    bottom: get_y(item.median),
+                    ..Default::default()
+                });
+                for value in item.outliers.iter() {
+                    box_canvas.circle(Circle {
+                        stroke_color: Some(color),
+                        fill: Some(self.background_color),
+                        stroke_width: 1.0,
+                        cx: center,
+                        cy: get_y(*value),
+                        r: 3.0,
+                    });
+                }
+            }
+        }
+
+        c.svg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoxPlotChart, BoxPlotData};
+    use pretty_assertions::assert_eq;
+    #[test]
+    fn box_plot_data() {
+        assert_eq!(
+            BoxPlotData {
+                lower_whisker: 0.0,
+                q1: 2.5,
+                median: 5.0,
+                q3: 7.5,
+                upper_whisker: 9.0,
+                outliers: vec![30.0],
+            },
+            BoxPlotData::from_samples(&[9.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 30.0, 0.0])
+                .unwrap()
+        );
+        assert_eq!(None, BoxPlotData::from_samples(&[]));
+
+        let data = BoxPlotData::from_quartiles(0.0, 20.0, 25.0, 30.0, 80.0);
+        assert_eq!(5.0, data.lower_whisker);
+        assert_eq!(45.0, data.upper_whisker);
+        assert_eq!(vec![0.0, 80.0], data.outliers);
+        assert_eq!([0.0, 20.0, 25.0, 30.0, 80.0], data.summary());
+    }
+
+    #[test]
+    fn box_plot_chart_basic() {
+        let mut box_plot_chart = BoxPlotChart::new(
+            vec![
+                (
+                    "Latency",
+                    vec![
+                        12.0, 20.0, 26.0, 32.0, 48.0, 10.0, 18.0, 22.0, 30.0, 95.0, 15.0, 28.0,
+                        35.0, 40.0, 52.0,
+                    ],
+                )
+                    .into(),
+                (
+                    "Latency(cache)",
+                    vec![
+                        5.0, 8.0, 10.0, 14.0, 20.0, 4.0, 7.0, 9.0, 12.0, 18.0, 6.0, 10.0, 13.0,
+                        16.0, 22.0,
+                    ],
+                )
+                    .into(),
+            ],
+            vec!["api".to_string(), "web".to_string(), "worker".to_string()],
+        );
+        box_plot_chart.title_text = "Box Plot Chart".to_string();
+        box_plot_chart.legend_margin = Some(crate::Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            include_str!("../../asset/box_plot_chart/basic.svg"),
+            box_plot_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn box_plot_chart_samples() {
+        let box_plot_chart = BoxPlotChart::new_with_samples(
+            vec![(
+                "Latency",
+                vec![
+                    vec![12.0, 15.0, 18.0, 20.0, 22.0, 25.0, 27.0, 30.0, 70.0],
+                    vec![30.0, 32.0, 35.0, 36.0, 38.0, 40.0, 41.0, 45.0],
+                    vec![2.0, 20.0, 21.0, 23.0, 24.0, 26.0, 28.0, 29.0, 55.0, 60.0],
+                ],
+            )],
+            vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+        );
+        assert_eq!(
+            include_str!("../../asset/box_plot_chart/samples.svg"),
+            box_plot_chart.svg().unwrap()
+        );
+    }
+}
@@ -67,6 +67,9 @@ pub struct CandlestickChart {
     pub x_axis_margin: Option<Box>,
     pub x_axis_hidden: bool,
     pub x_boundary_gap: Option<bool>,
+    // time x axis, the timestamps of series are used instead of x axis data
+    pub x_axis_time: Option<bool>,
+    pub x_axis_time_format: Option<String>,
 
     // y axis
     pub y_axis_hidden: bool,
@@ -83,6 +86,7 @@ pub struct CandlestickChart {
     pub series_label_font_weight: Option<String>,
     pub series_label_formatter: String,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
@@ -91,6 +95,12 @@ pub struct CandlestickChart {
     pub candlestick_up_border_color: Color,
     pub candlestick_down_color: Color,
     pub candlestick_down_border_color: Color,
+    // tooltip of open, close, lowest and highest of each candle
+    pub interactive: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of open, close, lowest and highest per candle, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl CandlestickChart {
@@ -129,10 +139,19 @@ impl CandlestickChart {
         if let Some(x_axis_hidden) = get_bool_from_value(&value, "x_axis_hidden") {
             c.x_axis_hidden = x_axis_hidden;
         }
+        if let Some(x_axis_time) = get_bool_from_value(&value, "x_axis_time") {
+            c.x_axis_time = Some(x_axis_time);
+        }
+        if let Some(x_axis_time_format) = get_string_from_value(&value, "x_axis_time_format") {
+            c.x_axis_time_format = Some(x_axis_time_format);
+        }
         if let Some(y_axis_hidden) = get_bool_from_value(&value, "y_axis_hidden") {
             c.y_axis_hidden = y_axis_hidden;
         }
         c.fill_default();
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            c.interactive = Some(interactive);
+        }
         Ok(c)
     }
     /// Creates a candlestick chart with custom theme.
@@ -165,6 +184,16 @@ impl CandlestickChart {
     /// Converts candlestick chart to svg.
     pub fn svg(&self) -> canvas::Result<String> {
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.accessibility = get_series_point_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            &self.x_axis_data,
+            &["Open", "Close", "Lowest", "Highest"],
+            self.accessible,
+            self.accessible_table,
+        );
 
         self.render_background(c.child(Box::default()));
         let mut x_axis_height = self.x_axis_height;
@@ -183,7 +212,7 @@ impl CandlestickChart {
             title_height
         };
 
-        let (left_y_axis_values, mut left_y_axis_width) = self.get_y_axis_values(0);
+        let (left_y_axis_values, mut left_y_axis_width) = self.get_y_axis_values(0)?;
         if self.y_axis_hidden {
             left_y_axis_width = 0.0;
         }
@@ -219,18 +248,37 @@ impl CandlestickChart {
         }
 
         // x axis
+        let time_axis_values = if self.x_axis_time.unwrap_or_default() {
+            Some(get_series_time_axis_values(
+                &self.series_list,
+                self.x_axis_time_format.clone(),
+                true,
+            ))
+        } else {
+            None
+        };
         if !self.x_axis_hidden {
-            self.render_x_axis(
-                c.child(Box {
-                    top: c.height() - x_axis_height,
-                    left: left_y_axis_width,
-                    ..Default::default()
-                }),
-                self.x_axis_data.clone(),
-                axis_width,
-            );
+            let x_axis_canvas = c.child(Box {
+                top: c.height() - x_axis_height,
+                left: left_y_axis_width,
+                ..Default::default()
+            });
+            if let Some(ref time_axis_values) = time_axis_values {
+                self.render_time_x_axis(x_axis_canvas, time_axis_values, axis_width);
+            } else {
+                self.render_x_axis(x_axis_canvas, self.x_axis_data.clone(), axis_width);
+            }
+        }
+        let mut chunk_width = axis_width / self.x_axis_data.len() as f32;
+        if let Some(ref time_axis_values) = time_axis_values {
+            let mut timestamps = vec![];
+            for series in self.series_list.iter() {
+                if let Some(ref value) = series.timestamps {
+                    timestamps.extend(value.iter());
+                }
+            }
+            chunk_width = time_axis_values.get_min_unit_width(&timestamps, axis_width);
         }
-        let chunk_width = axis_width / self.x_axis_data.len() as f32;
         let half_chunk_width = chunk_width / 2.0;
         for series in self.series_list.iter() {
             if series.category.is_some() {
@@ -244,6 +292,21 @@ impl CandlestickChart {
                 if chunk.len() != 4 {
                     continue;
                 }
+                // the left of chunk
+                let mut chunk_left = chunk_width * index as f32;
+                if let Some(ref time_axis_values) = time_axis_values {
+                    // ignore the chunk without timestamp
+                    match series.timestamps.as_ref().and_then(|item| item.get(index)) {
+                        Some(timestamp) => {
+                            // the chunk is kept inside the axis
+                            chunk_left = (time_axis_values.get_offset_width(*timestamp, axis_width)
+                                - half_chunk_width)
+                                .min(axis_width - chunk_width)
+                                .max(0.0)
+                        }
+                        None => continue,
+                    }
+                }
 
                 let open = left_y_axis_values.get_offset_height(chunk[0], axis_height);
                 let close = left_y_axis_values.get_offset_height(chunk[1], axis_height);
@@ -259,12 +322,31 @@ impl CandlestickChart {
                     border_color = Color::transparent();
                 }
 
-                let line_left = half_chunk_width + chunk_width * index as f32 - 1.0;
-                c.child(Box {
+                let line_left = half_chunk_width + chunk_left - 1.0;
+                let mut candle_canvas = c.child(Box {
                     left: left_y_axis_width,
                     ..Default::default()
-                })
-                .line(Line {
+                });
+                if candle_canvas.interactive {
+                    let category = if time_axis_values.is_some() {
+                        get_series_category(series, index, &[], true)
+                    } else {
+                        self.x_axis_data.get(index).cloned()
+                    };
+                    let values: Vec<String> =
+                        chunk.iter().map(|item| format_float(*item)).collect();
+                    let text = format!(
+                        "open: {}, close: {}, lowest: {}, highest: {}",
+                        values[0], values[1], values[2], values[3]
+                    );
+                    candle_canvas = candle_canvas.interactive_child(Interactive::new_item(
+                        &series.name,
+                        category,
+                        values.join(","),
+                        &text,
+                    ));
+                }
+                candle_canvas.line(Line {
                     color: Some(fill),
                     stroke_width: 1.0,
                     left: line_left,
@@ -274,14 +356,10 @@ impl CandlestickChart {
                     ..Default::default()
                 });
 
-                c.child(Box {
-                    left: left_y_axis_width,
-                    ..Default::default()
-                })
-                .rect(Rect {
+                candle_canvas.rect(Rect {
                     color: Some(border_color),
                     fill: Some(fill),
-                    left: half_chunk_width / 2.0 + chunk_width * index as f32 - 1.0,
+                    left: half_chunk_width / 2.0 + chunk_left - 1.0,
                     top: open.min(close),
                     width: half_chunk_width,
                     height: (open.max(close) - open.min(close)).max(1.0),
@@ -310,6 +388,8 @@ impl CandlestickChart {
             max_height,
             axis_height,
             self.x_axis_data.len(),
+            &self.x_axis_data,
+            time_axis_values.as_ref(),
         );
 
         self.render_series_label(
@@ -3,11 +3,13 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use super::component::{
-    generate_svg, Arrow, Axis, Bubble, Circle, Component, Grid, Legend, Line, Pie, Polygon,
-    Polyline, Rect, SmoothLine, SmoothLineFill, StraightLine, StraightLineFill, Text, LEGEND_WIDTH,
+    generate_accessible_svg, generate_animation_style, generate_interactive_script,
+    generate_paint_defs, generate_svg, Accessibility, Animation, Arrow, Axis, BezierBand, Bubble,
+    Circle, Component, Grid, Interactive, Legend, Line, Pie, Polygon, Polyline, Rect, SmoothLine,
+    SmoothLineFill, StraightLine, StraightLineFill, Text, VisualMap, LEGEND_WIDTH,
 };
 
-use super::{measure_text_width_family, util::*};
+use super::{measure_text_width_family, measure_text_width_family_weight, util::*, Paint};
 use snafu::{ResultExt, Snafu};
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -46,6 +48,12 @@ pub struct Canvas {
     pub y: f32,
     pub components: Rc<RefCell<Vec<Component>>>,
     pub margin: Box,
+    // interactive mode, the svg will include hover style and script
+    pub interactive: bool,
+    // animation mode, the svg will include entrance animation style
+    pub animation: bool,
+    // accessible metadata of svg
+    pub accessibility: Option<Accessibility>,
 }
 
 impl Canvas {
@@ -62,6 +70,9 @@ impl Canvas {
             y,
             components: Rc::new(RefCell::new(vec![])),
             margin: Box::default(),
+            interactive: false,
+            animation: false,
+            accessibility: None,
         }
     }
     /// Gets the width of canvas.
@@ -84,6 +95,9 @@ impl Canvas {
             margin: m,
             x: self.x,
             y: self.y,
+            interactive: self.interactive,
+            animation: self.animation,
+            accessibility: self.accessibility.clone(),
         }
     }
     /// Creates a child canvas.
@@ -100,6 +114,9 @@ impl Canvas {
             margin: m,
             x: self.x,
             y: self.y,
+            interactive: self.interactive,
+            animation: self.animation,
+            accessibility: self.accessibility.clone(),
         }
     }
     /// Appends arrow widget to canvas.
@@ -184,6 +201,7 @@ impl Canvas {
     pub fn text(&mut self, text: Text) -> Box {
         let font_family = text.font_family.clone().unwrap_or_default();
         let font_size = text.font_size.unwrap_or_default();
+        let font_weight = text.font_weight.clone().unwrap_or_default();
         let mut c = text;
 
         if let Some(x) = c.x {
@@ -202,7 +220,9 @@ impl Canvas {
             ..Default::default()
         };
         if !font_family.is_empty() && font_size > 0.0 {
-            if let Ok(result) = measure_text_width_family(&font_family, font_size, &c.text) {
+            if let Ok(result) =
+                measure_text_width_family_weight(&font_family, &font_weight, font_size, &c.text)
+            {
                 b.right = b.left + result.width();
                 b.bottom = b.top + result.height();
             }
@@ -233,6 +253,22 @@ impl Canvas {
         self.append(Component::Pie(c));
         b
     }
+    /// Appends cubic bezier band widget to canvas.
+    pub fn bezier_band(&mut self, band: BezierBand) -> Box {
+        let mut c = band;
+        c.x1 += self.margin.left;
+        c.x2 += self.margin.left;
+        c.y1 += self.margin.top;
+        c.y2 += self.margin.top;
+        let b = Box {
+            left: c.x1.min(c.x2),
+            top: c.y1.min(c.y2),
+            right: c.x1.max(c.x2),
+            bottom: c.y1.max(c.y2) + c.width,
+        };
+        self.append(Component::BezierBand(c));
+        b
+    }
     /// Appends smooth line points widget to canvas.
     pub fn smooth_line(&mut self, line: SmoothLine) -> Box {
         let mut c = line;
@@ -262,6 +298,10 @@ impl Canvas {
             p.x += self.margin.left;
             p.y += self.margin.top
         }
+        for p in c.base_points.iter_mut() {
+            p.x += self.margin.left;
+            p.y += self.margin.top
+        }
         c.bottom += self.margin.top;
         let mut b = get_box_of_points(&c.points);
         b.bottom = c.bottom;
@@ -275,6 +315,10 @@ impl Canvas {
             p.x += self.margin.left;
             p.y += self.margin.top
         }
+        for p in c.base_points.iter_mut() {
+            p.x += self.margin.left;
+            p.y += self.margin.top
+        }
         c.bottom += self.margin.top;
         let mut b = get_box_of_points(&c.points);
         b.bottom = c.bottom;
@@ -327,6 +371,21 @@ impl Canvas {
         self.append(Component::Legend(c));
         b
     }
+    /// Appends visual map widget to canvas.
+    pub fn visual_map(&mut self, visual_map: VisualMap) -> Box {
+        let mut c = visual_map;
+        c.left += self.margin.left;
+        c.top += self.margin.top;
+        let (width, height) = c.size().unwrap_or_default();
+        let b = Box {
+            left: c.left,
+            top: c.top,
+            right: c.left + width,
+            bottom: c.top + height,
+        };
+        self.append(Component::VisualMap(c));
+        b
+    }
     /// Appends bubble widget to canvas.
     pub fn bubble(&mut self, bubble: Bubble) -> Box {
         let mut c = bubble;
@@ -341,33 +400,78 @@ impl Canvas {
         self.append(Component::Bubble(c));
         b
     }
+    /// Creates a child canvas of interactive group,
+    /// the widgets of it will be wrapped by the group.
+    pub fn interactive_child(&mut self, interactive: Interactive) -> Self {
+        self.group_child(|components| Component::Interactive(interactive, components))
+    }
+    /// Creates a child canvas of animation group,
+    /// the widgets of it will be animated together.
+    pub fn animation_child(&mut self, animation: Animation) -> Self {
+        let animation = match animation {
+            Animation::Sweep { cx, cy, r } => Animation::Sweep {
+                cx: cx + self.margin.left,
+                cy: cy + self.margin.top,
+                r,
+            },
+            _ => animation,
+        };
+        self.group_child(|components| Component::Animation(animation, components))
+    }
+    fn group_child(
+        &mut self,
+        new_group: impl FnOnce(Rc<RefCell<Vec<Component>>>) -> Component,
+    ) -> Self {
+        let components = Rc::new(RefCell::new(vec![]));
+        self.append(new_group(Rc::clone(&components)));
+        Canvas {
+            width: self.width,
+            height: self.height,
+            components,
+            margin: self.margin.clone(),
+            x: self.x,
+            y: self.y,
+            interactive: self.interactive,
+            animation: self.animation,
+            accessibility: self.accessibility.clone(),
+        }
+    }
     pub fn append(&mut self, component: Component) {
         let mut components = self.components.borrow_mut();
         components.push(component);
     }
     /// Generates the svg of canvas.
     pub fn svg(&self) -> Result<String> {
-        let mut data = vec![];
-        for c in self.components.borrow().iter() {
-            let value = match c {
-                Component::Line(c) => c.svg(),
-                Component::Rect(c) => c.svg(),
-                Component::Arrow(c) => c.svg(),
-                Component::Bubble(c) => c.svg(),
-                Component::Polyline(c) => c.svg(),
-                Component::Circle(c) => c.svg(),
-                Component::Polygon(c) => c.svg(),
-                Component::Text(c) => c.svg(),
-                Component::SmoothLine(c) => c.svg(),
-                Component::StraightLine(c) => c.svg(),
-                Component::SmoothLineFill(c) => c.svg(),
-                Component::StraightLineFill(c) => c.svg(),
-                Component::Grid(c) => c.svg(),
-                Component::Axis(c) => c.svg().context(ToSVGSnafu)?,
-                Component::Legend(c) => c.svg(),
-                Component::Pie(c) => c.svg(),
-            };
-            data.push(value);
+        let mut data = components_svg(&self.components.borrow())?;
+        let mut paints = vec![];
+        collect_paints(&self.components.borrow(), &mut paints);
+        // the paints are written by the document if they are collected
+        let collected = PAINT_COLLECTOR.with(|collector| {
+            if let Some(items) = collector.borrow_mut().as_mut() {
+                items.append(&mut paints);
+                true
+            } else {
+                false
+            }
+        });
+        if !collected && !paints.is_empty() {
+            data.insert(0, generate_paint_defs(&paints));
+        }
+        if self.animation {
+            data.push(generate_animation_style());
+        }
+        if self.interactive {
+            data.push(generate_interactive_script());
+        }
+        if let Some(accessibility) = &self.accessibility {
+            return Ok(generate_accessible_svg(
+                self.width,
+                self.height,
+                self.x,
+                self.y,
+                accessibility,
+                data.join("\n"),
+            ));
         }
         Ok(generate_svg(
             self.width,
@@ -379,6 +483,91 @@ impl Canvas {
     }
 }
 
+thread_local! {
+    // the gradients and patterns of the canvases in collecting
+    static PAINT_COLLECTOR: RefCell<Option<Vec<Paint>>> = const { RefCell::new(None) };
+}
+
+/// Collects the gradients and patterns of the canvases which are converted to svg
+/// in the function, the canvases don't write defs and the paints are returned,
+/// so a document with several charts can write them once.
+pub(crate) fn collect_canvas_paints<T>(f: impl FnOnce() -> T) -> (T, Vec<Paint>) {
+    let prev = PAINT_COLLECTOR.with(|collector| collector.replace(Some(vec![])));
+    let result = f();
+    let paints = PAINT_COLLECTOR
+        .with(|collector| collector.replace(prev))
+        .unwrap_or_default();
+    (result, paints)
+}
+
+/// Collects the gradients and patterns of components, include the children of group.
+fn collect_paints(components: &[Component], paints: &mut Vec<Paint>) {
+    for c in components.iter() {
+        let paint = match c {
+            Component::Rect(c) => c.paint.as_ref(),
+            Component::SmoothLineFill(c) => c.paint.as_ref(),
+            Component::StraightLineFill(c) => c.paint.as_ref(),
+            Component::Polygon(c) => c.paint.as_ref(),
+            Component::Pie(c) => c.paint.as_ref(),
+            Component::BezierBand(c) => Some(&c.fill),
+            Component::Legend(c) => {
+                if let Some(pattern) = c.pattern {
+                    paints.push(pattern.into());
+                }
+                None
+            }
+            Component::VisualMap(c) => {
+                if let Some(gradient) = c.get_gradient() {
+                    paints.push(gradient.into());
+                }
+                None
+            }
+            Component::Interactive(_, children) | Component::Animation(_, children) => {
+                collect_paints(&children.borrow(), paints);
+                None
+            }
+            _ => None,
+        };
+        if let Some(paint) = paint.filter(|item| item.is_def()) {
+            paints.push(paint.clone());
+        }
+    }
+}
+
+fn components_svg(components: &[Component]) -> Result<Vec<String>> {
+    let mut data = vec![];
+    for c in components.iter() {
+        let value = match c {
+            Component::Line(c) => c.svg(),
+            Component::Rect(c) => c.svg(),
+            Component::Arrow(c) => c.svg(),
+            Component::Bubble(c) => c.svg(),
+            Component::Polyline(c) => c.svg(),
+            Component::Circle(c) => c.svg(),
+            Component::Polygon(c) => c.svg(),
+            Component::Text(c) => c.svg(),
+            Component::SmoothLine(c) => c.svg(),
+            Component::StraightLine(c) => c.svg(),
+            Component::SmoothLineFill(c) => c.svg(),
+            Component::StraightLineFill(c) => c.svg(),
+            Component::Grid(c) => c.svg(),
+            Component::Axis(c) => c.svg().context(ToSVGSnafu)?,
+            Component::Legend(c) => c.svg(),
+            Component::Pie(c) => c.svg(),
+            Component::BezierBand(c) => c.svg(),
+            Component::VisualMap(c) => c.svg().context(ToSVGSnafu)?,
+            Component::Interactive(c, children) => {
+                c.svg(components_svg(&children.borrow())?.join("\n"))
+            }
+            Component::Animation(c, children) => {
+                c.svg(components_svg(&children.borrow())?.join("\n"))
+            }
+        };
+        data.push(value);
+    }
+    Ok(data)
+}
+
 #[cfg(test)]
 mod tests {
     use super::Canvas;
@@ -425,6 +614,7 @@ mod tests {
         let b = c.rect(Rect {
             color: Some((0, 0, 0).into()),
             fill: Some((0, 255, 0).into()),
+            paint: None,
             left: 10.0,
             top: 10.0,
             width: 100.0,
@@ -554,6 +744,7 @@ Hello World!
                 (90.0, 40.0),
             ]),
             bottom: 150.0,
+            ..Default::default()
         });
         assert_eq!("(10,10,90,150)", b.to_string());
         assert_eq!(
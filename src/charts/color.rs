@@ -57,6 +57,17 @@ impl Color {
         c.a = a;
         c
     }
+    /// Mixes the color with other color in OKLab space,
+    /// the ratio 0.0 is self and 1.0 is other.
+    pub fn mix_oklab(&self, other: Color, ratio: f32) -> Color {
+        let ratio = ratio.clamp(0.0, 1.0);
+        let from = to_oklab(self);
+        let to = to_oklab(&other);
+        let mix = |a: f32, b: f32| a + (b - a) * ratio;
+        let mut c = from_oklab((mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2)));
+        c.a = mix(self.a as f32, other.a as f32).round() as u8;
+        c
+    }
     /// Returns ture if the color is light.
     pub fn is_light(&self) -> bool {
         let mut r = self.r as f64;
@@ -115,14 +126,438 @@ impl From<&str> for Color {
     }
 }
 
+fn srgb_to_linear(value: u8) -> f32 {
+    let value = value as f32 / 255.0;
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let value = if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+    (value * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// Converts color to OKLab (l, a, b), the alpha is ignored.
+fn to_oklab(color: &Color) -> (f32, f32, f32) {
+    let r = srgb_to_linear(color.r);
+    let g = srgb_to_linear(color.g);
+    let b = srgb_to_linear(color.b);
+    let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+    let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+    (
+        0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+    )
+}
+
+/// Converts OKLab (l, a, b) to opaque color.
+fn from_oklab(lab: (f32, f32, f32)) -> Color {
+    let l = (lab.0 + 0.39633778 * lab.1 + 0.21580376 * lab.2).powi(3);
+    let m = (lab.0 - 0.105561346 * lab.1 - 0.06385417 * lab.2).powi(3);
+    let s = (lab.0 - 0.08948418 * lab.1 - 1.2914855 * lab.2).powi(3);
+    (
+        linear_to_srgb(4.0767417 * l - 3.3077116 * m + 0.23096994 * s),
+        linear_to_srgb(-1.268438 * l + 2.6097574 * m - 0.34131938 * s),
+        linear_to_srgb(-0.0041960864 * l - 0.7034186 * m + 1.7076147 * s),
+    )
+        .into()
+}
+
 pub(crate) fn get_color(colors: &[Color], index: usize) -> Color {
     let i = index % colors.len();
     *colors.get(i).unwrap_or_else(|| &colors[0])
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct GradientStop {
+    // the offset of stop, 0.0 - 1.0
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl From<(f32, Color)> for GradientStop {
+    fn from(value: (f32, Color)) -> Self {
+        GradientStop {
+            offset: value.0,
+            color: value.1,
+        }
+    }
+}
+
+/// The linear gradient, the points are relative to the bounding box(0.0 - 1.0).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct LinearGradient {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+    pub stops: Vec<GradientStop>,
+}
+
+impl Default for LinearGradient {
+    fn default() -> Self {
+        // from top to bottom
+        LinearGradient {
+            x1: 0.0,
+            y1: 0.0,
+            x2: 0.0,
+            y2: 1.0,
+            stops: vec![],
+        }
+    }
+}
+
+/// The radial gradient, the center and radius are relative to the bounding box(0.0 - 1.0).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RadialGradient {
+    pub cx: f32,
+    pub cy: f32,
+    pub r: f32,
+    pub stops: Vec<GradientStop>,
+}
+
+impl Default for RadialGradient {
+    fn default() -> Self {
+        RadialGradient {
+            cx: 0.5,
+            cy: 0.5,
+            r: 0.5,
+            stops: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum Gradient {
+    Linear(LinearGradient),
+    Radial(RadialGradient),
+}
+
+impl Gradient {
+    /// Creates a linear gradient from top to bottom.
+    pub fn new_linear(stops: Vec<GradientStop>) -> Self {
+        Gradient::Linear(LinearGradient {
+            stops,
+            ..Default::default()
+        })
+    }
+    /// Creates a radial gradient from center.
+    pub fn new_radial(stops: Vec<GradientStop>) -> Self {
+        Gradient::Radial(RadialGradient {
+            stops,
+            ..Default::default()
+        })
+    }
+    /// Gets the stops of gradient.
+    pub fn stops(&self) -> &[GradientStop] {
+        match self {
+            Gradient::Linear(value) => &value.stops,
+            Gradient::Radial(value) => &value.stops,
+        }
+    }
+    /// Gets the id of gradient, it's the hash of gradient,
+    /// so the ids of different gradients are unique in one svg.
+    pub fn id(&self) -> String {
+        format!("charts-rs-gradient-{:08x}", fnv_hash(&format!("{self:?}")))
+    }
+}
+
+/// Gets the fnv-1a hash of value.
+fn fnv_hash(value: &str) -> u32 {
+    let mut hash = 0x811c9dc5_u32;
+    for b in value.bytes() {
+        hash ^= b as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    hash
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum PatternCategory {
+    #[default]
+    DiagonalHatch,
+    Dots,
+    CrossHatch,
+}
+
+impl From<&str> for PatternCategory {
+    fn from(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "dots" => PatternCategory::Dots,
+            "cross_hatch" | "crosshatch" => PatternCategory::CrossHatch,
+            _ => PatternCategory::DiagonalHatch,
+        }
+    }
+}
+
+/// The pattern fill, it's repeated in a tile of size * size.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct Pattern {
+    pub category: PatternCategory,
+    // the color of hatch lines or dots
+    pub color: Color,
+    pub background: Option<Color>,
+    pub size: f32,
+    pub stroke_width: f32,
+}
+
+impl Default for Pattern {
+    fn default() -> Self {
+        Pattern {
+            category: PatternCategory::default(),
+            color: Color::black(),
+            background: None,
+            size: 8.0,
+            stroke_width: 2.0,
+        }
+    }
+}
+
+impl Pattern {
+    /// Creates a pattern of category with the color of series,
+    /// the light tint of color is used as background.
+    pub fn new(category: PatternCategory, color: Color) -> Self {
+        Pattern {
+            category,
+            color,
+            background: Some(color.with_alpha(40)),
+            ..Default::default()
+        }
+    }
+    /// Gets the id of pattern, it's the hash of pattern.
+    pub fn id(&self) -> String {
+        format!("charts-rs-pattern-{:08x}", fnv_hash(&format!("{self:?}")))
+    }
+}
+
+/// The paint of fill, it can be a color, a gradient or a pattern.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Paint {
+    Color(Color),
+    Gradient(Gradient),
+    Pattern(Pattern),
+}
+
+impl Default for Paint {
+    fn default() -> Self {
+        Paint::Color(Color::default())
+    }
+}
+
+impl Paint {
+    /// Returns true if the paint is transparent.
+    pub fn is_transparent(&self) -> bool {
+        match self {
+            Paint::Color(color) => color.is_transparent(),
+            Paint::Gradient(gradient) => gradient
+                .stops()
+                .iter()
+                .all(|item| item.color.is_transparent()),
+            Paint::Pattern(pattern) => {
+                pattern.color.is_transparent()
+                    && pattern.background.unwrap_or_default().is_transparent()
+            }
+        }
+    }
+    /// Gets the color of paint, the color of first stop is used for gradient.
+    pub fn color(&self) -> Color {
+        match self {
+            Paint::Color(color) => *color,
+            Paint::Gradient(gradient) => gradient
+                .stops()
+                .first()
+                .map(|item| item.color)
+                .unwrap_or_default(),
+            Paint::Pattern(pattern) => pattern.color,
+        }
+    }
+    /// Gets the gradient of paint.
+    pub fn gradient(&self) -> Option<&Gradient> {
+        match self {
+            Paint::Gradient(gradient) => Some(gradient),
+            _ => None,
+        }
+    }
+    /// Gets the pattern of paint.
+    pub fn pattern(&self) -> Option<&Pattern> {
+        match self {
+            Paint::Pattern(pattern) => Some(pattern),
+            _ => None,
+        }
+    }
+    /// Returns true if the paint needs a definition in defs of svg.
+    pub fn is_def(&self) -> bool {
+        !matches!(self, Paint::Color(_))
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(value: Color) -> Self {
+        Paint::Color(value)
+    }
+}
+
+impl From<Gradient> for Paint {
+    fn from(value: Gradient) -> Self {
+        Paint::Gradient(value)
+    }
+}
+
+impl From<Pattern> for Paint {
+    fn from(value: Pattern) -> Self {
+        Paint::Pattern(value)
+    }
+}
+
+impl From<(u8, u8, u8)> for Paint {
+    fn from(value: (u8, u8, u8)) -> Self {
+        Paint::Color(value.into())
+    }
+}
+
+impl From<(u8, u8, u8, u8)> for Paint {
+    fn from(value: (u8, u8, u8, u8)) -> Self {
+        Paint::Color(value.into())
+    }
+}
+
+pub static PALETTE_VIRIDIS: &str = "viridis";
+pub static PALETTE_MAGMA: &str = "magma";
+pub static PALETTE_RDBU: &str = "rdbu";
+
+/// Gets the colors of built-in palette, viridis is returned if the palette is not found.
+/// The sequential palettes viridis and magma are from dark to light,
+/// the diverging palette rdbu is from red to blue.
+pub fn get_palette(name: &str) -> Vec<Color> {
+    let colors = match name.to_lowercase().as_str() {
+        "magma" => vec![
+            "#000004", "#180F3D", "#440F76", "#721F81", "#9E2F7F", "#CD4071", "#F1605D", "#FD9668",
+            "#FECA8D", "#FCFDBF",
+        ],
+        "rdbu" => vec![
+            "#67001F", "#B2182B", "#D6604D", "#F4A582", "#FDDBC7", "#F7F7F7", "#D1E5F0", "#92C5DE",
+            "#4393C3", "#2166AC", "#053061",
+        ],
+        _ => vec![
+            "#440154", "#482878", "#3E4989", "#31688E", "#26828E", "#1F9E89", "#35B779", "#6ECE58",
+            "#B5DE2B", "#FDE725",
+        ],
+    };
+    colors.into_iter().map(|item| item.into()).collect()
+}
+
+/// The color scale maps value to color,
+/// the colors are interpolated in OKLab space.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ColorScale {
+    // the colors from min to max, they are evenly distributed
+    pub colors: Vec<Color>,
+    // the domain of scale, the domain of data is used if min is not less than max
+    pub min: f32,
+    pub max: f32,
+    // the midpoint of diverging scale, the middle color is mapped to it
+    pub mid: Option<f32>,
+    // the count of quantized buckets, the scale is continuous if it is none
+    pub steps: Option<usize>,
+}
+
+impl ColorScale {
+    /// Creates a color scale of colors.
+    pub fn new(colors: Vec<Color>, min: f32, max: f32) -> ColorScale {
+        ColorScale {
+            colors,
+            min,
+            max,
+            ..Default::default()
+        }
+    }
+    /// Creates a color scale of built-in palette.
+    pub fn new_palette(name: &str, min: f32, max: f32) -> ColorScale {
+        ColorScale::new(get_palette(name), min, max)
+    }
+    /// Returns true if the domain of scale is empty.
+    pub fn is_domain_empty(&self) -> bool {
+        self.min >= self.max
+    }
+    /// Gets the color of percent(0.0 - 1.0), the step is applied.
+    pub fn get_percent_color(&self, percent: f32) -> Color {
+        if self.colors.is_empty() {
+            return Color::transparent();
+        }
+        let mut percent = if percent.is_nan() {
+            0.0
+        } else {
+            percent.clamp(0.0, 1.0)
+        };
+        if let Some(steps) = self.steps {
+            percent = if steps > 1 {
+                let bucket = (percent * steps as f32).floor().min(steps as f32 - 1.0);
+                bucket / (steps - 1) as f32
+            } else {
+                0.0
+            };
+        }
+        let offset = percent * (self.colors.len() - 1) as f32;
+        let index = offset.floor() as usize;
+        if index + 1 >= self.colors.len() {
+            return self.colors[self.colors.len() - 1];
+        }
+        self.colors[index].mix_oklab(self.colors[index + 1], offset - index as f32)
+    }
+    /// Gets the value of percent(0.0 - 1.0) with custom domain,
+    /// it is the inverse of the mapping from value to percent.
+    pub fn get_percent_value_with_domain(&self, percent: f32, min: f32, max: f32) -> f32 {
+        match self.mid {
+            Some(mid) if mid > min && mid < max => {
+                if percent < 0.5 {
+                    min + (mid - min) * percent * 2.0
+                } else {
+                    mid + (max - mid) * (percent - 0.5) * 2.0
+                }
+            }
+            _ => min + (max - min) * percent,
+        }
+    }
+    /// Gets the value of percent(0.0 - 1.0).
+    pub fn get_percent_value(&self, percent: f32) -> f32 {
+        self.get_percent_value_with_domain(percent, self.min, self.max)
+    }
+    /// Gets the color of value with custom domain.
+    pub fn get_color_with_domain(&self, value: f32, min: f32, max: f32) -> Color {
+        if min >= max {
+            return self.get_percent_color(0.0);
+        }
+        let percent = match self.mid {
+            // the lower and upper half are scaled separately
+            Some(mid) if mid > min && mid < max => {
+                if value < mid {
+                    (value - min) / (mid - min) / 2.0
+                } else {
+                    0.5 + (value - mid) / (max - mid) / 2.0
+                }
+            }
+            _ => (value - min) / (max - min),
+        };
+        self.get_percent_color(percent)
+    }
+    /// Gets the color of value.
+    pub fn get_color(&self, value: f32) -> Color {
+        self.get_color_with_domain(value, self.min, self.max)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Color;
+    use super::{get_palette, Color, ColorScale, PALETTE_MAGMA, PALETTE_RDBU};
     use pretty_assertions::assert_eq;
     #[test]
     fn color_hex() {
@@ -176,4 +611,53 @@ mod tests {
         c = c.with_alpha(51);
         assert_eq!("rgba(255,255,255,0.2)", c.rgba());
     }
+
+    #[test]
+    fn color_mix_oklab() {
+        let c: Color = (255, 0, 0).into();
+        assert_eq!("#FF0000", c.mix_oklab(Color::white(), 0.0).hex());
+        assert_eq!("#FFFFFF", c.mix_oklab(Color::white(), 1.0).hex());
+        // the midpoint is brighter than the raw rgb average
+        assert_eq!("#FFA191", c.mix_oklab(Color::white(), 0.5).hex());
+        assert_eq!(
+            "rgba(0,0,0,0.5)",
+            Color::black()
+                .mix_oklab(Color::black().with_alpha(0), 0.5)
+                .rgba()
+        );
+    }
+
+    #[test]
+    fn color_scale() {
+        let scale = ColorScale::new(vec!["#000000".into(), "#FFFFFF".into()], 0.0, 10.0);
+        assert_eq!("#000000", scale.get_color(-5.0).hex());
+        assert_eq!("#FFFFFF", scale.get_color(20.0).hex());
+        assert_eq!("#636363", scale.get_color(5.0).hex());
+
+        let mut scale = ColorScale::new_palette(PALETTE_RDBU, -10.0, 40.0);
+        scale.mid = Some(0.0);
+        assert_eq!("#F7F7F7", scale.get_color(0.0).hex());
+        assert_eq!("#67001F", scale.get_color(-10.0).hex());
+        assert_eq!("#053061", scale.get_color(40.0).hex());
+
+        let mut scale = ColorScale::new_palette(PALETTE_MAGMA, 0.0, 100.0);
+        scale.steps = Some(3);
+        let colors = get_palette(PALETTE_MAGMA);
+        assert_eq!(colors[0], scale.get_color(10.0));
+        assert_eq!(colors[0], scale.get_color(33.0));
+        assert_eq!(colors[9], scale.get_color(70.0));
+        assert_eq!(colors[9], scale.get_color(100.0));
+        assert_eq!(scale.get_color(34.0), scale.get_color(66.0));
+
+        let mut scale = ColorScale::new_palette(PALETTE_RDBU, -10.0, 40.0);
+        assert_eq!(15.0, scale.get_percent_value(0.5));
+        scale.mid = Some(0.0);
+        assert_eq!(-10.0, scale.get_percent_value(0.0));
+        assert_eq!(-5.0, scale.get_percent_value(0.25));
+        assert_eq!(0.0, scale.get_percent_value(0.5));
+        assert_eq!(20.0, scale.get_percent_value(0.75));
+
+        assert!(ColorScale::default().is_domain_empty());
+        assert_eq!(Color::transparent(), ColorScale::default().get_color(1.0));
+    }
 }
@@ -2,9 +2,16 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::{Box, Color};
-use crate::Point;
+use super::canvas;
+use super::component::{SmoothLine, StraightLine, Text, VisualMap, VISUAL_MAP_THICKNESS};
+use super::util::format_float;
+use super::{Box, Canvas, Color, Gradient, Paint, Pattern, PatternCategory};
+use crate::{
+    format_timestamp, get_time_axis_values, Accessibility, Point, TimeAxisValueParams,
+    TimeAxisValues, NIL_VALUE,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
 pub enum Position {
@@ -61,6 +68,25 @@ pub struct MarkPoint {
     pub category: MarkPointCategory,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub enum TrendLineCategory {
+    #[default]
+    Linear,
+    // polynomial regression of degree n
+    Polynomial(usize),
+    // exponential regression, y = a * e^(b * x)
+    Exponential,
+    // moving average of period n
+    MovingAverage(usize),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct TrendLine {
+    pub category: TrendLineCategory,
+    // whether to display the equation and R² of trend line
+    pub label_show: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
 pub struct Series {
     // name of series
@@ -85,6 +111,16 @@ pub struct Series {
     pub category: Option<SeriesCategory>,
     // stroke dash array for series
     pub stroke_dash_array: Option<String>,
+    // stack name of series, the series with the same stack will be stacked
+    pub stack: Option<String>,
+    // epoch timestamps(seconds) of the data, it's used for time x axis
+    pub timestamps: Option<Vec<i64>>,
+    // gradient fill of series, it's used for bar, area and pie sector
+    pub gradient: Option<Gradient>,
+    // pattern fill of series, it takes precedence over the patterns of chart
+    pub pattern: Option<PatternCategory>,
+    // trend lines of series(line and scatter chart)
+    pub trend_lines: Vec<TrendLine>,
 }
 
 #[derive(Clone, PartialEq, Debug, Default)]
@@ -109,6 +145,253 @@ impl From<(&str, Vec<f32>)> for Series {
     }
 }
 
+/// Gets the stack base values of each series, the base value is the sum
+/// of the previous series in the same stack(same stack name, y axis index and category).
+/// The positive and negative values are stacked separately.
+pub(crate) fn get_series_stack_bases(series_list: &[&Series]) -> Vec<Vec<f32>> {
+    let mut positive_sums: HashMap<(String, usize, bool), Vec<f32>> = HashMap::new();
+    let mut negative_sums: HashMap<(String, usize, bool), Vec<f32>> = HashMap::new();
+    let mut bases_list = vec![];
+    for series in series_list.iter() {
+        let mut bases = vec![0.0; series.data.len()];
+        if let Some(ref stack) = series.stack {
+            let is_line = series.category == Some(SeriesCategory::Line);
+            let key = (stack.clone(), series.y_axis_index, is_line);
+            let size = series.start_index + series.data.len();
+            let positive_sum = positive_sums.entry(key.clone()).or_default();
+            if positive_sum.len() < size {
+                positive_sum.resize(size, 0.0);
+            }
+            let negative_sum = negative_sums.entry(key).or_default();
+            if negative_sum.len() < size {
+                negative_sum.resize(size, 0.0);
+            }
+            for (i, value) in series.data.iter().enumerate() {
+                let value = *value;
+                if value == NIL_VALUE {
+                    continue;
+                }
+                let index = i + series.start_index;
+                if value >= 0.0 {
+                    bases[i] = positive_sum[index];
+                    positive_sum[index] += value;
+                } else {
+                    bases[i] = negative_sum[index];
+                    negative_sum[index] += value;
+                }
+            }
+        }
+        bases_list.push(bases);
+    }
+    bases_list
+}
+
+/// Gets the pattern of series, the pattern of series takes precedence
+/// over the pattern list of chart, which is chosen by series index.
+pub(crate) fn get_series_pattern(
+    series: &Series,
+    index: usize,
+    series_patterns: &Option<Vec<PatternCategory>>,
+    color: Color,
+) -> Option<Pattern> {
+    let category = series.pattern.or_else(|| {
+        series_patterns
+            .as_ref()
+            .filter(|item| !item.is_empty())
+            .map(|item| item[index % item.len()])
+    })?;
+    Some(Pattern::new(category, color))
+}
+
+/// Gets the paint of series fill, the gradient takes precedence over the pattern,
+/// it returns none if the series should be filled with color.
+pub(crate) fn get_series_paint(
+    series: &Series,
+    index: usize,
+    series_patterns: &Option<Vec<PatternCategory>>,
+    color: Color,
+) -> Option<Paint> {
+    if let Some(gradient) = &series.gradient {
+        return Some(gradient.clone().into());
+    }
+    get_series_pattern(series, index, series_patterns, color).map(Paint::from)
+}
+
+/// Gets the category of series value, the timestamp is formatted
+/// as category if it's time axis.
+pub(crate) fn get_series_category(
+    series: &Series,
+    index: usize,
+    x_axis_data: &[String],
+    time_axis: bool,
+) -> Option<String> {
+    if time_axis {
+        return series
+            .timestamps
+            .as_ref()
+            .and_then(|item| item.get(index))
+            .map(|timestamp| format_timestamp(*timestamp, "%Y-%m-%d %H:%M:%S"));
+    }
+    x_axis_data.get(index + series.start_index).cloned()
+}
+
+/// Gets the accessible metadata of chart, the desc lists the series names
+/// and the data table summarises each series. The data of series is split
+/// into points by dimension, e.g. 2 for the x, y values of scatter chart,
+/// and the min and max are the y values of points.
+/// It returns none if neither accessible nor table is enabled.
+pub(crate) fn get_series_accessibility(
+    title: &str,
+    sub_title: &str,
+    series_list: &[Series],
+    dimension: usize,
+    accessible: Option<bool>,
+    table: Option<bool>,
+) -> Option<Accessibility> {
+    let table = table.unwrap_or_default();
+    if !accessible.unwrap_or_default() && !table {
+        return None;
+    }
+    let names: Vec<&str> = series_list
+        .iter()
+        .map(|item| item.name.as_str())
+        .filter(|name| !name.is_empty())
+        .collect();
+    let mut desc = vec![];
+    if !sub_title.is_empty() {
+        desc.push(sub_title.to_string());
+    }
+    if !names.is_empty() {
+        desc.push(format!("Series: {}", names.join(", ")));
+    }
+    let mut accessibility = Accessibility {
+        title: title.to_string(),
+        desc: desc.join(". "),
+        table: None,
+    };
+    if !table {
+        return Some(accessibility);
+    }
+    let dimension = dimension.max(1);
+    let value_index = dimension.min(2) - 1;
+    let format_value = |value: f32| {
+        if value == NIL_VALUE {
+            "-".to_string()
+        } else {
+            format_float(value)
+        }
+    };
+    let mut rows = vec![["Series", "Count", "Min", "Max", "Values"]
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>()];
+    for series in series_list.iter() {
+        let points: Vec<&[f32]> = series
+            .data
+            .chunks(dimension)
+            .filter(|chunk| chunk.len() == dimension)
+            .collect();
+        let values: Vec<f32> = points
+            .iter()
+            .map(|point| point[value_index])
+            .filter(|value| *value != NIL_VALUE)
+            .collect();
+        let format_option = |value: Option<f32>| value.map(format_float).unwrap_or("-".to_string());
+        let min = values.iter().copied().reduce(f32::min);
+        let max = values.iter().copied().reduce(f32::max);
+        let data: Vec<String> = points
+            .iter()
+            .map(|point| {
+                if dimension == 1 {
+                    format_value(point[0])
+                } else {
+                    let items: Vec<String> =
+                        point.iter().map(|value| format_value(*value)).collect();
+                    format!("({})", items.join(", "))
+                }
+            })
+            .collect();
+        rows.push(vec![
+            series.name.clone(),
+            values.len().to_string(),
+            format_option(min),
+            format_option(max),
+            data.join(", "),
+        ]);
+    }
+    accessibility.table = Some(rows);
+    Some(accessibility)
+}
+
+/// Gets the accessible metadata of chart whose points have several named values,
+/// e.g. open, close, lowest and highest of candlestick. The data table lists
+/// one row per point with the category and the labelled values.
+pub(crate) fn get_series_point_accessibility(
+    title: &str,
+    sub_title: &str,
+    series_list: &[Series],
+    x_axis_data: &[String],
+    columns: &[&str],
+    accessible: Option<bool>,
+    table: Option<bool>,
+) -> Option<Accessibility> {
+    if !accessible.unwrap_or_default() && !table.unwrap_or_default() {
+        return None;
+    }
+    let mut accessibility =
+        get_series_accessibility(title, sub_title, series_list, 1, Some(true), None)?;
+    if !table.unwrap_or_default() {
+        return Some(accessibility);
+    }
+    let dimension = columns.len().max(1);
+    let mut header = vec!["Series".to_string(), "Category".to_string()];
+    header.extend(columns.iter().map(|item| item.to_string()));
+    let mut rows = vec![header];
+    for series in series_list.iter() {
+        for (index, point) in series.data.chunks(dimension).enumerate() {
+            if point.len() != dimension {
+                continue;
+            }
+            let category = x_axis_data
+                .get(index + series.start_index)
+                .cloned()
+                .unwrap_or_default();
+            let mut row = vec![series.name.clone(), category];
+            row.extend(point.iter().map(|value| {
+                if *value == NIL_VALUE {
+                    "-".to_string()
+                } else {
+                    format_float(*value)
+                }
+            }));
+            rows.push(row);
+        }
+    }
+    accessibility.table = Some(rows);
+    Some(accessibility)
+}
+
+/// Gets the time axis values of the series list's timestamps,
+/// the domain is padded by half of the min interval if boundary gap is true.
+pub(crate) fn get_series_time_axis_values(
+    series_list: &[Series],
+    formatter: Option<String>,
+    boundary_gap: bool,
+) -> TimeAxisValues {
+    let mut data_list = vec![];
+    for series in series_list.iter() {
+        if let Some(ref timestamps) = series.timestamps {
+            data_list.extend(timestamps.iter());
+        }
+    }
+    get_time_axis_values(TimeAxisValueParams {
+        data_list,
+        formatter,
+        boundary_gap,
+        ..Default::default()
+    })
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct YAxisConfig {
     pub axis_font_size: f32,
@@ -123,4 +406,625 @@ pub struct YAxisConfig {
     pub axis_formatter: Option<String>,
     pub axis_min: Option<f32>,
     pub axis_max: Option<f32>,
+    // the base of logarithmic axis(bar, line, candlestick and scatter chart),
+    // it is linear axis if it is none, horizontal bar, box plot and histogram chart
+    // return error if it is set
+    pub axis_log_base: Option<f32>,
+}
+
+/// The option of visual map, which explains the color of value.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct VisualMapOption {
+    // the side of chart, left(default) and right are vertical,
+    // top and bottom are horizontal
+    pub position: Position,
+    // the align along the side, left is top for vertical visual map
+    pub align: Align,
+    // the formatter of tick labels, e.g. {c}ms
+    pub formatter: Option<String>,
+    // the split number of continuous bar, default is 4
+    pub split_number: usize,
+    // the length of bar, it is relative to the side if it is 0
+    pub length: f32,
+}
+
+/// Takes the area of width and height at the side of canvas, the canvas is shrunk
+/// to the rest area and the left top of the area is returned(relative to canvas).
+pub(crate) fn take_canvas_side(
+    c: &mut Canvas,
+    position: &Position,
+    align: &Align,
+    width: f32,
+    height: f32,
+) -> (f32, f32) {
+    let gap = 10.0;
+    let get_offset = |side_length: f32, length: f32| match align {
+        Align::Left => 0.0,
+        Align::Center => (side_length - length) / 2.0,
+        Align::Right => side_length - length,
+    };
+    match position {
+        Position::Top => {
+            let left = get_offset(c.width(), width);
+            c.margin.top += height + gap;
+            (left, 0.0)
+        }
+        Position::Bottom => {
+            let left_top = (get_offset(c.width(), width), c.height() - height);
+            c.margin.bottom += height + gap;
+            left_top
+        }
+        Position::Right => {
+            let left_top = (c.width() - width, get_offset(c.height(), height));
+            c.margin.right += width + gap;
+            left_top
+        }
+        _ => {
+            let top = get_offset(c.height(), height);
+            c.margin.left += width + gap;
+            (0.0, top)
+        }
+    }
+}
+
+/// Renders the visual map at the side of canvas, and the canvas is shrunk to the rest area.
+/// The scale and font of visual map should be set,
+/// and its formatter is used if the formatter of option is none.
+pub(crate) fn render_visual_map(
+    c: &mut Canvas,
+    option: &VisualMapOption,
+    visual_map: VisualMap,
+) -> canvas::Result<()> {
+    let mut visual_map = visual_map;
+    let vertical = !matches!(option.position, Position::Top | Position::Bottom);
+    let side_length = if vertical { c.height() } else { c.width() };
+    visual_map.vertical = vertical;
+    // the formatter of visual map is the fallback, e.g. the axis formatter of chart
+    if let Some(formatter) = &option.formatter {
+        visual_map.formatter = formatter.clone();
+    }
+    visual_map.split_number = if option.split_number == 0 {
+        4
+    } else {
+        option.split_number
+    };
+    visual_map.thickness = VISUAL_MAP_THICKNESS;
+    visual_map.length = if option.length > 0.0 {
+        option.length.min(side_length)
+    } else if vertical {
+        side_length * 0.6
+    } else {
+        side_length * 0.4
+    };
+    let (width, height) = visual_map
+        .size()
+        .map_err(|source| canvas::Error::ToSVG { source })?;
+    // the canvas of visual map is not shrunk
+    let mut visual_map_canvas = c.child(Box::default());
+    let (left, top) = take_canvas_side(c, &option.position, &option.align, width, height);
+    visual_map.left = left;
+    visual_map.top = top;
+    visual_map_canvas.visual_map(visual_map);
+    Ok(())
+}
+
+/// The regression of trend line, the coefficients of polynomial start from
+/// the constant term, and they are (a, b) of a * e^(b * x) for exponential.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TrendRegression {
+    pub exponential: bool,
+    pub coefficients: Vec<f64>,
+    // coefficient of determination
+    pub r_squared: f64,
+}
+
+impl TrendRegression {
+    /// Gets the value of regression at x.
+    pub fn get_value(&self, x: f64) -> f64 {
+        if self.exponential {
+            return self.coefficients[0] * (self.coefficients[1] * x).exp();
+        }
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |value, coefficient| value * x + coefficient)
+    }
+    /// Gets the equation of regression, e.g. y = 0.5x + 1.2
+    pub fn equation(&self) -> String {
+        if self.exponential {
+            return format!(
+                "y = {}e^({}x)",
+                format_coefficient(self.coefficients[0]),
+                format_coefficient(self.coefficients[1])
+            );
+        }
+        let mut terms = vec![];
+        for (degree, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if *coefficient == 0.0 && degree != 0 {
+                continue;
+            }
+            let variable = match degree {
+                0 => "".to_string(),
+                1 => "x".to_string(),
+                2 => "x²".to_string(),
+                3 => "x³".to_string(),
+                _ => format!("x^{degree}"),
+            };
+            let value = format_coefficient(coefficient.abs());
+            if terms.is_empty() {
+                let sign = if *coefficient < 0.0 { "-" } else { "" };
+                terms.push(format!("{sign}{value}{variable}"));
+            } else if *coefficient == 0.0 {
+                continue;
+            } else {
+                let sign = if *coefficient < 0.0 { "-" } else { "+" };
+                terms.push(format!("{sign} {value}{variable}"));
+            }
+        }
+        format!("y = {}", terms.join(" "))
+    }
+}
+
+/// Formats the coefficient with three significant digits.
+fn format_coefficient(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return "0".to_string();
+    }
+    let digits = (2 - value.abs().log10().floor() as i32).max(0) as usize;
+    let str = format!("{value:.digits$}");
+    if str.contains('.') {
+        str.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        str
+    }
+}
+
+/// Gets the coefficient of determination of the predicted values.
+fn get_r_squared(values: &[(f64, f64)], predict: impl Fn(f64) -> f64) -> f64 {
+    let mean = values.iter().map(|(_, y)| y).sum::<f64>() / values.len() as f64;
+    let mut ss_res = 0.0;
+    let mut ss_tot = 0.0;
+    for (x, y) in values.iter() {
+        ss_res += (y - predict(*x)).powi(2);
+        ss_tot += (y - mean).powi(2);
+    }
+    if ss_tot == 0.0 {
+        return if ss_res == 0.0 { 1.0 } else { 0.0 };
+    }
+    1.0 - ss_res / ss_tot
+}
+
+/// Fits the polynomial of degree by least squares, the x is normalized
+/// before solving the normal equations and the coefficients are converted back.
+fn fit_polynomial(values: &[(f64, f64)], degree: usize) -> Option<Vec<f64>> {
+    if values.len() <= degree {
+        return None;
+    }
+    let count = values.len() as f64;
+    let mean = values.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let scale = values
+        .iter()
+        .map(|(x, _)| (x - mean).abs())
+        .fold(0.0, f64::max);
+    if scale == 0.0 {
+        return None;
+    }
+    let size = degree + 1;
+    // the augmented matrix of normal equations
+    let mut matrix = vec![vec![0.0; size + 1]; size];
+    for (x, y) in values.iter() {
+        let u = (x - mean) / scale;
+        let powers: Vec<f64> = (0..size * 2).map(|i| u.powi(i as i32)).collect();
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, item) in row.iter_mut().take(size).enumerate() {
+                *item += powers[i + j];
+            }
+            row[size] += y * powers[i];
+        }
+    }
+    // gaussian elimination with partial pivoting
+    for i in 0..size {
+        let pivot = (i..size).max_by(|a, b| matrix[*a][i].abs().total_cmp(&matrix[*b][i].abs()))?;
+        if matrix[pivot][i].abs() < 1e-12 {
+            return None;
+        }
+        matrix.swap(i, pivot);
+        let (upper, lower) = matrix.split_at_mut(i + 1);
+        let pivot_row = &upper[i];
+        for row in lower.iter_mut() {
+            let factor = row[i] / pivot_row[i];
+            for (item, value) in row.iter_mut().zip(pivot_row.iter()).skip(i) {
+                *item -= factor * value;
+            }
+        }
+    }
+    let mut normalized = vec![0.0; size];
+    for i in (0..size).rev() {
+        let sum: f64 = ((i + 1)..size).map(|j| matrix[i][j] * normalized[j]).sum();
+        normalized[i] = (matrix[i][size] - sum) / matrix[i][i];
+    }
+    // expand c * ((x - mean) / scale)^k to the coefficients of x
+    let mut coefficients = vec![0.0; size];
+    for (k, c) in normalized.iter().enumerate() {
+        let factor = c / scale.powi(k as i32);
+        let mut binomial = 1.0;
+        for (j, coefficient) in coefficients.iter_mut().take(k + 1).enumerate() {
+            *coefficient += factor * binomial * (-mean).powi((k - j) as i32);
+            binomial = binomial * (k - j) as f64 / (j + 1) as f64;
+        }
+    }
+    Some(coefficients)
+}
+
+impl TrendLine {
+    /// Fits the regression of data(x, y), it returns none if it's moving average
+    /// or the data is not enough to fit.
+    pub fn fit(&self, data: &[(f32, f32)]) -> Option<TrendRegression> {
+        let values: Vec<(f64, f64)> = data
+            .iter()
+            .filter(|(x, y)| *x != NIL_VALUE && *y != NIL_VALUE)
+            .map(|(x, y)| (*x as f64, *y as f64))
+            .collect();
+        match self.category {
+            TrendLineCategory::Linear | TrendLineCategory::Polynomial(_) => {
+                let degree = match self.category {
+                    TrendLineCategory::Polynomial(degree) => degree.max(1),
+                    _ => 1,
+                };
+                let coefficients = fit_polynomial(&values, degree)?;
+                let mut regression = TrendRegression {
+                    coefficients,
+                    ..Default::default()
+                };
+                regression.r_squared = get_r_squared(&values, |x| regression.get_value(x));
+                Some(regression)
+            }
+            TrendLineCategory::Exponential => {
+                // ln(y) = ln(a) + b * x, so only the positive values are fitted
+                let values: Vec<(f64, f64)> =
+                    values.into_iter().filter(|(_, y)| *y > 0.0).collect();
+                let log_values: Vec<(f64, f64)> =
+                    values.iter().map(|(x, y)| (*x, y.ln())).collect();
+                let coefficients = fit_polynomial(&log_values, 1)?;
+                let mut regression = TrendRegression {
+                    exponential: true,
+                    coefficients: vec![coefficients[0].exp(), coefficients[1]],
+                    ..Default::default()
+                };
+                regression.r_squared = get_r_squared(&values, |x| regression.get_value(x));
+                Some(regression)
+            }
+            TrendLineCategory::MovingAverage(_) => None,
+        }
+    }
+    /// Gets the points(x, y) of trend line, the regression is sampled between
+    /// the min and max x of data, and the moving average is sorted by x.
+    pub fn get_points(&self, data: &[(f32, f32)]) -> Vec<(f32, f32)> {
+        let mut values: Vec<(f32, f32)> = data
+            .iter()
+            .copied()
+            .filter(|(x, y)| *x != NIL_VALUE && *y != NIL_VALUE)
+            .collect();
+        if let TrendLineCategory::MovingAverage(period) = self.category {
+            let period = period.max(1);
+            values.sort_by(|a, b| a.0.total_cmp(&b.0));
+            return values
+                .windows(period)
+                .map(|items| {
+                    let sum: f32 = items.iter().map(|(_, y)| y).sum();
+                    (items[period - 1].0, sum / period as f32)
+                })
+                .collect();
+        }
+        let Some(regression) = self.fit(&values) else {
+            return vec![];
+        };
+        let min = values.iter().map(|(x, _)| *x).fold(f32::MAX, f32::min);
+        let max = values.iter().map(|(x, _)| *x).fold(f32::MIN, f32::max);
+        let count = if self.category == TrendLineCategory::Linear {
+            1
+        } else {
+            40
+        };
+        (0..=count)
+            .map(|i| {
+                let x = min + (max - min) * i as f32 / count as f32;
+                (x, regression.get_value(x as f64) as f32)
+            })
+            .collect()
+    }
+    /// Gets the label of trend line, it's the equation and R² of regression.
+    pub fn get_label(&self, data: &[(f32, f32)]) -> Option<String> {
+        if let TrendLineCategory::MovingAverage(period) = self.category {
+            return Some(format!("MA({})", period.max(1)));
+        }
+        self.fit(data).map(|regression| {
+            format!(
+                "{}, R² = {:.3}",
+                regression.equation(),
+                regression.r_squared
+            )
+        })
+    }
+}
+
+/// Clips the polyline to the range of y, the parts out of range are removed,
+/// so the polyline may be split into several parts.
+fn clip_points(points: &[Point], min_y: f32, max_y: f32) -> Vec<Vec<Point>> {
+    let mut points_list = vec![];
+    let mut current: Vec<Point> = vec![];
+    let is_inside = |p: &Point| p.y >= min_y && p.y <= max_y;
+    if points.len() == 1 && is_inside(&points[0]) {
+        return vec![points.to_vec()];
+    }
+    for items in points.windows(2) {
+        let (start, end) = (items[0], items[1]);
+        let dy = end.y - start.y;
+        let (mut t0, mut t1) = (0.0_f32, 1.0_f32);
+        if dy == 0.0 {
+            if !is_inside(&start) {
+                t0 = 1.0;
+                t1 = 0.0;
+            }
+        } else {
+            let a = (min_y - start.y) / dy;
+            let b = (max_y - start.y) / dy;
+            t0 = t0.max(a.min(b));
+            t1 = t1.min(a.max(b));
+        }
+        if t0 > t1 {
+            if current.len() > 1 {
+                points_list.push(current);
+            }
+            current = vec![];
+            continue;
+        }
+        let get_point = |t: f32| Point {
+            x: start.x + (end.x - start.x) * t,
+            y: start.y + dy * t,
+        };
+        if t0 > 0.0 || current.is_empty() {
+            if current.len() > 1 {
+                points_list.push(current);
+            }
+            current = vec![get_point(t0)];
+        }
+        current.push(get_point(t1));
+        if t1 < 1.0 {
+            points_list.push(current);
+            current = vec![];
+        }
+    }
+    if current.len() > 1 {
+        points_list.push(current);
+    }
+    points_list
+}
+
+/// The params of trend lines of a series.
+pub(crate) struct TrendLineParams<'a> {
+    pub trend_lines: &'a [TrendLine],
+    // the data(x, y) of series
+    pub data: Vec<(f32, f32)>,
+    pub color: Color,
+    pub font_family: &'a str,
+    pub font_size: f32,
+    // the height of content area, the trend line out of it is clipped
+    pub height: f32,
+}
+
+/// Renders the dashed trend lines of series, the data is converted to point of canvas
+/// by the function. The labels are rendered at the left top of canvas, one line for each,
+/// and the count of labels rendered is returned.
+pub(crate) fn render_trend_lines(
+    c: &mut Canvas,
+    params: TrendLineParams,
+    label_index: usize,
+    to_point: impl Fn(f32, f32) -> Point,
+) -> usize {
+    let mut label_count = 0;
+    for trend_line in params.trend_lines.iter() {
+        let points: Vec<Point> = trend_line
+            .get_points(&params.data)
+            .into_iter()
+            .map(|(x, y)| to_point(x, y))
+            .collect();
+        let smooth = matches!(
+            trend_line.category,
+            TrendLineCategory::Polynomial(_) | TrendLineCategory::Exponential
+        );
+        for points in clip_points(&points, 0.0, params.height) {
+            if smooth {
+                c.smooth_line(SmoothLine {
+                    color: Some(params.color),
+                    points,
+                    stroke_width: 1.5,
+                    symbol: None,
+                    stroke_dash_array: Some("6,3".to_string()),
+                });
+            } else {
+                c.straight_line(StraightLine {
+                    color: Some(params.color),
+                    points,
+                    stroke_width: 1.5,
+                    symbol: None,
+                    stroke_dash_array: Some("6,3".to_string()),
+                    ..Default::default()
+                });
+            }
+        }
+        if !trend_line.label_show {
+            continue;
+        }
+        if let Some(label) = trend_line.get_label(&params.data) {
+            let line_height = params.font_size + 4.0;
+            c.text(Text {
+                text: label,
+                font_family: Some(params.font_family.to_string()),
+                font_size: Some(params.font_size),
+                font_color: Some(params.color),
+                x: Some(8.0),
+                y: Some(line_height * (label_index + label_count + 1) as f32),
+                ..Default::default()
+            });
+            label_count += 1;
+        }
+    }
+    label_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        clip_points, format_coefficient, get_series_point_accessibility, TrendLine,
+        TrendLineCategory,
+    };
+    use crate::Point;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn trend_line_regression() {
+        let data = vec![(1.0, 3.1), (2.0, 4.9), (3.0, 7.2), (4.0, 8.8), (5.0, 11.1)];
+        let linear = TrendLine::default().fit(&data).unwrap();
+        assert_eq!("y = 1.99x + 1.05", linear.equation());
+        assert_eq!("0.997", format!("{:.3}", linear.r_squared));
+        assert_eq!(
+            "y = 1.99x + 1.05, R² = 0.997",
+            TrendLine {
+                label_show: true,
+                ..Default::default()
+            }
+            .get_label(&data)
+            .unwrap()
+        );
+
+        // y = 2x² - 3x + 1
+        let data: Vec<(f32, f32)> = (0..6)
+            .map(|x| x as f32)
+            .map(|x| (x, 2.0 * x * x - 3.0 * x + 1.0))
+            .collect();
+        let polynomial = TrendLine {
+            category: TrendLineCategory::Polynomial(2),
+            ..Default::default()
+        }
+        .fit(&data)
+        .unwrap();
+        assert_eq!("y = 2x² - 3x + 1", polynomial.equation());
+        assert_eq!("1.000", format!("{:.3}", polynomial.r_squared));
+
+        // y = 1.5 * e^(0.2x), the non positive value is ignored
+        let mut data: Vec<(f32, f32)> = (0..6)
+            .map(|x| x as f32)
+            .map(|x| (x, 1.5 * (0.2 * x).exp()))
+            .collect();
+        data.push((6.0, -1.0));
+        let exponential = TrendLine {
+            category: TrendLineCategory::Exponential,
+            ..Default::default()
+        }
+        .fit(&data)
+        .unwrap();
+        assert_eq!("y = 1.5e^(0.2x)", exponential.equation());
+        assert_eq!("4.08", format!("{:.2}", exponential.get_value(5.0)));
+
+        // not enough data
+        assert_eq!(None, TrendLine::default().fit(&[(1.0, 1.0)]));
+        assert_eq!(None, TrendLine::default().fit(&[(1.0, 1.0), (1.0, 2.0)]));
+    }
+
+    #[test]
+    fn trend_line_points() {
+        let moving_average = TrendLine {
+            category: TrendLineCategory::MovingAverage(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            vec![(3.0, 2.0), (4.0, 3.0), (5.0, 4.0)],
+            moving_average.get_points(&[
+                (5.0, 5.0),
+                (1.0, 1.0),
+                (2.0, 2.0),
+                (4.0, 4.0),
+                (3.0, 3.0)
+            ])
+        );
+        assert_eq!("MA(3)", moving_average.get_label(&[(1.0, 1.0)]).unwrap());
+
+        let linear = TrendLine::default();
+        assert_eq!(
+            vec![(1.0, 2.0), (3.0, 6.0)],
+            linear.get_points(&[(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)])
+        );
+
+        assert_eq!("1.23", format_coefficient(1.2345));
+        assert_eq!("123", format_coefficient(123.45));
+        assert_eq!("12346", format_coefficient(12345.6));
+        assert_eq!("0.00123", format_coefficient(0.0012345));
+        assert_eq!("-0.5", format_coefficient(-0.5));
+    }
+
+    #[test]
+    fn clip_trend_line_points() {
+        let points: Vec<Point> = vec![
+            (0.0, 50.0).into(),
+            (10.0, 150.0).into(),
+            (20.0, 50.0).into(),
+        ];
+        assert_eq!(
+            vec![
+                vec![Point::from((0.0, 50.0)), Point::from((5.0, 100.0))],
+                vec![Point::from((15.0, 100.0)), Point::from((20.0, 50.0))],
+            ],
+            clip_points(&points, 0.0, 100.0)
+        );
+        assert_eq!(vec![points.clone()], clip_points(&points, 0.0, 200.0));
+        assert_eq!(Vec::<Vec<Point>>::new(), clip_points(&points, 200.0, 300.0));
+    }
+
+    #[test]
+    fn series_point_accessibility() {
+        let series_list = vec![(
+            "Stock",
+            vec![20.0, 34.0, 10.0, 38.0, 40.0, 35.0, 30.0, 50.0],
+        )
+            .into()];
+        let x_axis_data = vec!["Mon".to_string(), "Tue".to_string()];
+        let columns = ["Open", "Close", "Lowest", "Highest"];
+        assert_eq!(
+            None,
+            get_series_point_accessibility(
+                "",
+                "",
+                &series_list,
+                &x_axis_data,
+                &columns,
+                None,
+                None
+            )
+        );
+        let accessibility = get_series_point_accessibility(
+            "Price",
+            "",
+            &series_list,
+            &x_axis_data,
+            &columns,
+            None,
+            Some(true),
+        )
+        .unwrap();
+        assert_eq!("Series: Stock", accessibility.desc);
+        assert_eq!(
+            Some(
+                vec![
+                    vec!["Series", "Category", "Open", "Close", "Lowest", "Highest"],
+                    vec!["Stock", "Mon", "20", "34", "10", "38"],
+                    vec!["Stock", "Tue", "40", "35", "30", "50"],
+                ]
+                .into_iter()
+                .map(|row| row.into_iter().map(|item| item.to_string()).collect())
+                .collect::<Vec<Vec<String>>>()
+            ),
+            accessibility.table
+        );
+    }
 }
@@ -4,7 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 use snafu::{ResultExt, Snafu};
+use std::cell::RefCell;
 use std::fmt;
+use std::rc::Rc;
 use std::vec;
 
 use super::color::*;
@@ -23,6 +25,22 @@ static TAG_POLYGON: &str = "polygon";
 static TAG_TEXT: &str = "text";
 static TAG_PATH: &str = "path";
 static TAG_GROUP: &str = "g";
+static TAG_TITLE: &str = "title";
+static TAG_DESC: &str = "desc";
+static TAG_FOREIGN_OBJECT: &str = "foreignObject";
+static TAG_TABLE: &str = "table";
+static TAG_CAPTION: &str = "caption";
+static TAG_TR: &str = "tr";
+static TAG_TH: &str = "th";
+static TAG_TD: &str = "td";
+static TAG_STYLE: &str = "style";
+static TAG_SCRIPT: &str = "script";
+static TAG_DEFS: &str = "defs";
+static TAG_LINEAR_GRADIENT: &str = "linearGradient";
+static TAG_RADIAL_GRADIENT: &str = "radialGradient";
+static TAG_STOP: &str = "stop";
+static TAG_PATTERN: &str = "pattern";
+static TAG_MASK: &str = "mask";
 
 static ATTR_VIEW_BOX: &str = "viewBox";
 static ATTR_XMLNS: &str = "xmlns";
@@ -56,6 +74,63 @@ static ATTR_DX: &str = "dx";
 static ATTR_DY: &str = "dy";
 static ATTR_R: &str = "r";
 static ATTR_D: &str = "d";
+static ATTR_CLASS: &str = "class";
+static ATTR_ROLE: &str = "role";
+static ATTR_ARIA_LABEL: &str = "aria-label";
+static ATTR_OVERFLOW: &str = "overflow";
+static ATTR_STYLE: &str = "style";
+static ATTR_DATA_SERIES: &str = "data-series";
+static ATTR_DATA_CATEGORY: &str = "data-category";
+static ATTR_DATA_VALUE: &str = "data-value";
+static ATTR_ID: &str = "id";
+static ATTR_OFFSET: &str = "offset";
+static ATTR_STOP_COLOR: &str = "stop-color";
+static ATTR_STOP_OPACITY: &str = "stop-opacity";
+static ATTR_PATTERN_UNITS: &str = "patternUnits";
+static ATTR_MASK: &str = "mask";
+
+pub static INTERACTIVE_ITEM_CLASS: &str = "charts-rs-item";
+pub static INTERACTIVE_SERIES_CLASS: &str = "charts-rs-series";
+pub static INTERACTIVE_LEGEND_CLASS: &str = "charts-rs-legend";
+
+static INTERACTIVE_STYLE: &str = ".charts-rs-item,.charts-rs-series{transition:opacity .2s}
+.charts-rs-hover .charts-rs-item:not(.charts-rs-active){opacity:.4}
+.charts-rs-legend{cursor:pointer}
+.charts-rs-legend.charts-rs-disabled{opacity:.4}
+.charts-rs-hidden{display:none}";
+
+static ANIMATION_STYLE: &str = "@keyframes charts-rs-grow-x{from{transform:scaleX(0)}}
+@keyframes charts-rs-grow-y{from{transform:scaleY(0)}}
+@keyframes charts-rs-draw{to{stroke-dashoffset:0}}
+@keyframes charts-rs-sweep{from{stroke-dashoffset:var(--charts-rs-length)}}
+@keyframes charts-rs-fade{from{opacity:0}}
+.charts-rs-grow-x,.charts-rs-grow-y{transform-box:fill-box}
+.charts-rs-grow-x{animation:charts-rs-grow-x 1s ease-out both}
+.charts-rs-grow-y{animation:charts-rs-grow-y 1s ease-out both}
+.charts-rs-draw path{stroke-dasharray:var(--charts-rs-length);stroke-dashoffset:var(--charts-rs-length);animation:charts-rs-draw 1s ease-out forwards}
+.charts-rs-draw circle{animation:charts-rs-fade .3s 1s both}
+.charts-rs-sweep{animation:charts-rs-sweep 1s ease-out both}
+.charts-rs-fade{animation:charts-rs-fade .5s .5s both}
+@media (prefers-reduced-motion:reduce){.charts-rs-grow-x,.charts-rs-grow-y,.charts-rs-sweep,.charts-rs-fade,.charts-rs-draw path,.charts-rs-draw circle{animation:none;stroke-dasharray:none}}";
+
+static INTERACTIVE_SCRIPT: &str = "(function(){
+var script=document.currentScript;
+var svg=script?script.closest('svg'):null;
+if(!svg){return;}
+svg.querySelectorAll('.charts-rs-item').forEach(function(item){
+item.addEventListener('mouseenter',function(){svg.classList.add('charts-rs-hover');item.classList.add('charts-rs-active');});
+item.addEventListener('mouseleave',function(){svg.classList.remove('charts-rs-hover');item.classList.remove('charts-rs-active');});
+});
+svg.querySelectorAll('.charts-rs-legend').forEach(function(legend){
+legend.addEventListener('click',function(){
+var series=legend.getAttribute('data-series');
+var hidden=legend.classList.toggle('charts-rs-disabled');
+svg.querySelectorAll('.charts-rs-item,.charts-rs-series').forEach(function(el){
+if(el.getAttribute('data-series')===series){el.classList.toggle('charts-rs-hidden',hidden);}
+});
+});
+});
+})();";
 
 /// Converts opacity to string value.
 fn convert_opacity(color: &Color) -> String {
@@ -66,6 +141,191 @@ fn convert_opacity(color: &Color) -> String {
     }
 }
 
+/// Escapes the special characters of xml.
+fn escape_xml(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+/// Converts paint to the fill and fill opacity value,
+/// the gradient is referenced by its id.
+fn convert_paint(paint: &Paint) -> (String, String) {
+    match paint {
+        Paint::Color(color) => (color.hex(), convert_opacity(color)),
+        Paint::Gradient(gradient) => (format!("url(#{})", gradient.id()), "".to_string()),
+        Paint::Pattern(pattern) => (format!("url(#{})", pattern.id()), "".to_string()),
+    }
+}
+
+/// Gets the paint of fill, the paint takes precedence over the fill color.
+fn get_fill_paint(fill: Option<Color>, paint: &Option<Paint>) -> Option<Paint> {
+    paint.clone().or(fill.map(Paint::from))
+}
+
+/// Generates the defs of gradients and patterns,
+/// the paint with the same id is only defined once.
+pub fn generate_paint_defs(paints: &[Paint]) -> String {
+    let mut ids = vec![];
+    let mut data = vec![];
+    for paint in paints.iter() {
+        let (id, value) = match paint {
+            Paint::Gradient(gradient) => (gradient.id(), gradient_svg(gradient)),
+            Paint::Pattern(pattern) => (pattern.id(), pattern_svg(pattern)),
+            Paint::Color(_) => continue,
+        };
+        if ids.contains(&id) {
+            continue;
+        }
+        data.push(value);
+        ids.push(id);
+    }
+    if data.is_empty() {
+        return "".to_string();
+    }
+    SVGTag::new(TAG_DEFS, data.join("\n"), vec![]).to_string()
+}
+
+fn gradient_svg(gradient: &Gradient) -> String {
+    let id = gradient.id();
+    let stops = gradient
+        .stops()
+        .iter()
+        .map(|item| {
+            SVGTag {
+                tag: TAG_STOP,
+                attrs: vec![
+                    (ATTR_OFFSET, format_float(item.offset)),
+                    (ATTR_STOP_COLOR, item.color.hex()),
+                    (ATTR_STOP_OPACITY, convert_opacity(&item.color)),
+                ],
+                data: None,
+            }
+            .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let tag = match gradient {
+        Gradient::Linear(value) => SVGTag::new(
+            TAG_LINEAR_GRADIENT,
+            stops,
+            vec![
+                (ATTR_ID, id.clone()),
+                (ATTR_X1, format_float(value.x1)),
+                (ATTR_Y1, format_float(value.y1)),
+                (ATTR_X2, format_float(value.x2)),
+                (ATTR_Y2, format_float(value.y2)),
+            ],
+        ),
+        Gradient::Radial(value) => SVGTag::new(
+            TAG_RADIAL_GRADIENT,
+            stops,
+            vec![
+                (ATTR_ID, id.clone()),
+                (ATTR_CX, format_float(value.cx)),
+                (ATTR_CY, format_float(value.cy)),
+                (ATTR_R, format_float(value.r)),
+            ],
+        ),
+    };
+    tag.to_string()
+}
+
+fn pattern_svg(pattern: &Pattern) -> String {
+    let size = pattern.size;
+    let mut data = vec![];
+    if let Some(background) = pattern.background {
+        data.push(
+            Rect {
+                fill: Some(background),
+                width: size,
+                height: size,
+                ..Default::default()
+            }
+            .svg(),
+        );
+    }
+    // the lines out of tile are drawn to keep the hatch continuous
+    let diagonal = format!(
+        "M0,{} L{},0 M{},{} L{},{} M{},{} L{},{}",
+        format_float(size),
+        format_float(size),
+        format_float(-size / 4.0),
+        format_float(size / 4.0),
+        format_float(size / 4.0),
+        format_float(-size / 4.0),
+        format_float(size * 3.0 / 4.0),
+        format_float(size * 5.0 / 4.0),
+        format_float(size * 5.0 / 4.0),
+        format_float(size * 3.0 / 4.0),
+    );
+    let anti_diagonal = format!(
+        "M0,0 L{},{} M{},{} L{},{} M{},{} L{},{}",
+        format_float(size),
+        format_float(size),
+        format_float(-size / 4.0),
+        format_float(size * 3.0 / 4.0),
+        format_float(size / 4.0),
+        format_float(size * 5.0 / 4.0),
+        format_float(size * 3.0 / 4.0),
+        format_float(-size / 4.0),
+        format_float(size * 5.0 / 4.0),
+        format_float(size / 4.0),
+    );
+    let path = match pattern.category {
+        PatternCategory::DiagonalHatch => Some(diagonal),
+        PatternCategory::CrossHatch => Some(format!("{diagonal} {anti_diagonal}")),
+        PatternCategory::Dots => None,
+    };
+    if let Some(path) = path {
+        data.push(
+            SVGTag {
+                tag: TAG_PATH,
+                attrs: vec![
+                    (ATTR_D, path),
+                    (ATTR_STROKE, pattern.color.hex()),
+                    (ATTR_STROKE_OPACITY, convert_opacity(&pattern.color)),
+                    (ATTR_STROKE_WIDTH, format_float(pattern.stroke_width)),
+                ],
+                data: None,
+            }
+            .to_string(),
+        );
+    } else {
+        data.push(
+            Circle {
+                stroke_width: 0.0,
+                fill: Some(pattern.color),
+                cx: size / 2.0,
+                cy: size / 2.0,
+                r: size / 4.0,
+                ..Default::default()
+            }
+            .svg(),
+        );
+    }
+    SVGTag::new(
+        TAG_PATTERN,
+        data.join("\n"),
+        vec![
+            (ATTR_ID, pattern.id()),
+            (ATTR_PATTERN_UNITS, "userSpaceOnUse".to_string()),
+            (ATTR_WIDTH, format_float(size)),
+            (ATTR_HEIGHT, format_float(size)),
+        ],
+    )
+    .to_string()
+}
+
 fn format_option_float(value: Option<f32>) -> String {
     if let Some(f) = value {
         format_float(f)
@@ -81,7 +341,7 @@ struct SVGTag<'a> {
     data: Option<String>,
 }
 
-pub fn generate_svg(width: f32, height: f32, x: f32, y: f32, data: String) -> String {
+fn get_svg_attrs<'a>(width: f32, height: f32, x: f32, y: f32) -> Vec<(&'a str, String)> {
     let mut attrs = vec![
         (ATTR_WIDTH, format!("{}", width)),
         (ATTR_HEIGHT, format!("{}", height)),
@@ -94,7 +354,44 @@ pub fn generate_svg(width: f32, height: f32, x: f32, y: f32, data: String) -> St
     if y != 0.0 {
         attrs.push((ATTR_Y, format!("{}", y)))
     }
-    SVGTag::new(TAG_SVG, data, attrs).to_string()
+    attrs
+}
+
+pub fn generate_svg(width: f32, height: f32, x: f32, y: f32, data: String) -> String {
+    SVGTag::new(TAG_SVG, data, get_svg_attrs(width, height, x, y)).to_string()
+}
+
+/// Generates svg with the role, aria label, title and desc of accessibility,
+/// the hidden data table is appended if it is set.
+pub fn generate_accessible_svg(
+    width: f32,
+    height: f32,
+    x: f32,
+    y: f32,
+    accessibility: &Accessibility,
+    data: String,
+) -> String {
+    let mut attrs = get_svg_attrs(width, height, x, y);
+    attrs.push((ATTR_ROLE, accessibility.role().to_string()));
+    attrs.push((ATTR_ARIA_LABEL, escape_xml(accessibility.label())));
+    let mut children = vec![];
+    if !accessibility.title.is_empty() {
+        children.push(format!(
+            "<{TAG_TITLE}>{}</{TAG_TITLE}>",
+            escape_xml(&accessibility.title)
+        ));
+    }
+    if !accessibility.desc.is_empty() {
+        children.push(format!(
+            "<{TAG_DESC}>{}</{TAG_DESC}>",
+            escape_xml(&accessibility.desc)
+        ));
+    }
+    children.push(data);
+    if let Some(table) = &accessibility.table {
+        children.push(accessibility.table_svg(table));
+    }
+    SVGTag::new(TAG_SVG, children.join("\n"), attrs).to_string()
 }
 
 impl<'a> SVGTag<'a> {
@@ -164,6 +461,10 @@ pub enum Component {
     Axis(Axis),
     Legend(Legend),
     Pie(Pie),
+    BezierBand(BezierBand),
+    VisualMap(VisualMap),
+    Interactive(Interactive, Rc<RefCell<Vec<Component>>>),
+    Animation(Animation, Rc<RefCell<Vec<Component>>>),
 }
 #[derive(Clone, PartialEq, Debug)]
 
@@ -224,6 +525,8 @@ impl Line {
 pub struct Rect {
     pub color: Option<Color>,
     pub fill: Option<Color>,
+    // the paint of fill(gradient or pattern), it takes precedence over the fill color
+    pub paint: Option<Paint>,
     pub left: f32,
     pub top: f32,
     pub width: f32,
@@ -246,12 +549,13 @@ impl Rect {
             attrs.push((ATTR_STROKE, color.hex()));
             attrs.push((ATTR_STROKE_OPACITY, convert_opacity(&color)));
         }
-        if let Some(color) = self.fill {
-            if color.is_transparent() {
+        if let Some(paint) = get_fill_paint(self.fill, &self.paint) {
+            if paint.is_transparent() {
                 attrs.push((ATTR_FILL, "none".to_string()));
             } else {
-                attrs.push((ATTR_FILL, color.hex()));
-                attrs.push((ATTR_FILL_OPACITY, convert_opacity(&color)));
+                let (fill, opacity) = convert_paint(&paint);
+                attrs.push((ATTR_FILL, fill));
+                attrs.push((ATTR_FILL_OPACITY, opacity));
             }
         }
 
@@ -415,6 +719,8 @@ impl Arrow {
 pub struct Polygon {
     pub color: Option<Color>,
     pub fill: Option<Color>,
+    // the paint of fill(gradient or pattern), it takes precedence over the fill color
+    pub paint: Option<Paint>,
     pub points: Vec<Point>,
 }
 
@@ -433,9 +739,10 @@ impl Polygon {
             attrs.push((ATTR_STROKE, color.hex()));
             attrs.push((ATTR_STROKE_OPACITY, convert_opacity(&color)));
         }
-        if let Some(color) = self.fill {
-            attrs.push((ATTR_FILL, color.hex()));
-            attrs.push((ATTR_FILL_OPACITY, convert_opacity(&color)));
+        if let Some(paint) = get_fill_paint(self.fill, &self.paint) {
+            let (fill, opacity) = convert_paint(&paint);
+            attrs.push((ATTR_FILL, fill));
+            attrs.push((ATTR_FILL_OPACITY, opacity));
         }
         SVGTag {
             tag: TAG_POLYGON,
@@ -539,7 +846,7 @@ impl Text {
             ),
         ];
         if let Some(ref font_family) = self.font_family {
-            attrs.push((ATTR_FONT_FAMILY, font_family.clone()));
+            attrs.push((ATTR_FONT_FAMILY, font::format_font_family(font_family)));
         }
         if let Some(color) = self.font_color {
             attrs.push((ATTR_FILL, color.hex()));
@@ -569,6 +876,8 @@ fn generate_circle_symbol(points: &[Point], c: Circle) -> String {
 #[derive(Clone, PartialEq, Debug)]
 pub struct Pie {
     pub fill: Color,
+    // the paint of fill(gradient or pattern), it takes precedence over the fill color
+    pub paint: Option<Paint>,
     pub stroke_color: Option<Color>,
     pub cx: f32,
     pub cy: f32,
@@ -583,6 +892,7 @@ impl Default for Pie {
     fn default() -> Self {
         Pie {
             fill: (0, 0, 0).into(),
+            paint: None,
             stroke_color: None,
             cx: 0.0,
             cy: 0.0,
@@ -722,10 +1032,11 @@ impl Pie {
 
         path_list.push("Z".to_string());
 
+        let (fill, fill_opacity) = convert_paint(&self.paint.clone().unwrap_or(self.fill.into()));
         let mut attrs = vec![
             (ATTR_D, path_list.join(" ")),
-            (ATTR_FILL, self.fill.hex()),
-            (ATTR_FILL_OPACITY, convert_opacity(&self.fill)),
+            (ATTR_FILL, fill),
+            (ATTR_FILL_OPACITY, fill_opacity),
         ];
         if let Some(color) = self.stroke_color {
             attrs.push((ATTR_STROKE, color.hex()));
@@ -837,6 +1148,43 @@ impl BaseLine {
     }
 }
 
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BezierBand {
+    pub fill: Paint,
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+    pub width: f32,
+}
+
+impl BezierBand {
+    pub fn svg(&self) -> String {
+        if self.width <= 0.0 || self.fill.is_transparent() {
+            return "".to_string();
+        }
+        let path = CubicBezierBand {
+            x1: self.x1,
+            y1: self.y1,
+            x2: self.x2,
+            y2: self.y2,
+            width: self.width,
+        }
+        .to_string();
+        let (fill, fill_opacity) = convert_paint(&self.fill);
+        SVGTag {
+            tag: TAG_PATH,
+            attrs: vec![
+                (ATTR_D, path),
+                (ATTR_FILL, fill),
+                (ATTR_FILL_OPACITY, fill_opacity),
+            ],
+            data: None,
+        }
+        .to_string()
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct SmoothLine {
     pub color: Option<Color>,
@@ -859,6 +1207,14 @@ impl Default for SmoothLine {
 }
 
 impl SmoothLine {
+    /// Gets the approximate length of smooth line.
+    pub fn length(&self) -> f32 {
+        SmoothCurve {
+            points: self.points.clone(),
+            close: false,
+        }
+        .length()
+    }
     pub fn svg(&self) -> String {
         BaseLine {
             color: self.color,
@@ -877,16 +1233,22 @@ impl SmoothLine {
 #[derive(Clone, PartialEq, Debug)]
 pub struct SmoothLineFill {
     pub fill: Color,
+    // the paint of fill(gradient or pattern), it takes precedence over the fill color
+    pub paint: Option<Paint>,
     pub points: Vec<Point>,
     pub bottom: f32,
+    // the bottom points of fill area(stacked area), fill to bottom if it's empty
+    pub base_points: Vec<Point>,
 }
 
 impl Default for SmoothLineFill {
     fn default() -> Self {
         SmoothLineFill {
             fill: (255, 255, 255, 255).into(),
+            paint: None,
             points: vec![],
             bottom: 0.0,
+            base_points: vec![],
         }
     }
 }
@@ -904,19 +1266,37 @@ impl SmoothLineFill {
 
         let last = self.points[self.points.len() - 1];
         let first = self.points[0];
-        let fill_path = [
-            format!("M {} {}", format_float(last.x), format_float(last.y)),
-            format!("L {} {}", format_float(last.x), format_float(self.bottom)),
-            format!("L {} {}", format_float(first.x), format_float(self.bottom)),
-            format!("L {} {}", format_float(first.x), format_float(first.y)),
-        ]
-        .join(" ");
-        path.push_str(&fill_path);
+        if !self.base_points.is_empty() {
+            let mut base_points = self.base_points.clone();
+            base_points.reverse();
+            let base_path = SmoothCurve {
+                points: base_points,
+                ..Default::default()
+            }
+            .to_string();
+            // connect the curve to the reversed base curve
+            path.push_str(&format!(" L{}", base_path.trim_start_matches('M')));
+            path.push_str(&format!(
+                " L {} {}",
+                format_float(first.x),
+                format_float(first.y)
+            ));
+        } else {
+            let fill_path = [
+                format!("M {} {}", format_float(last.x), format_float(last.y)),
+                format!("L {} {}", format_float(last.x), format_float(self.bottom)),
+                format!("L {} {}", format_float(first.x), format_float(self.bottom)),
+                format!("L {} {}", format_float(first.x), format_float(first.y)),
+            ]
+            .join(" ");
+            path.push_str(&fill_path);
+        }
 
+        let (fill, fill_opacity) = convert_paint(&self.paint.clone().unwrap_or(self.fill.into()));
         let attrs = vec![
             (ATTR_D, path),
-            (ATTR_FILL, self.fill.hex()),
-            (ATTR_FILL_OPACITY, convert_opacity(&self.fill)),
+            (ATTR_FILL, fill),
+            (ATTR_FILL_OPACITY, fill_opacity),
         ];
 
         SVGTag {
@@ -954,6 +1334,19 @@ impl Default for StraightLine {
 }
 
 impl StraightLine {
+    /// Gets the length of straight line.
+    pub fn length(&self) -> f32 {
+        let mut points = self.points.clone();
+        if self.close {
+            if let Some(first) = self.points.first() {
+                points.push(*first);
+            }
+        }
+        points
+            .windows(2)
+            .map(|item| ((item[1].x - item[0].x).powi(2) + (item[1].y - item[0].y).powi(2)).sqrt())
+            .sum()
+    }
     pub fn svg(&self) -> String {
         BaseLine {
             color: self.color,
@@ -972,9 +1365,13 @@ impl StraightLine {
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct StraightLineFill {
     pub fill: Color,
+    // the paint of fill(gradient or pattern), it takes precedence over the fill color
+    pub paint: Option<Paint>,
     pub points: Vec<Point>,
     pub bottom: f32,
     pub close: bool,
+    // the bottom points of fill area(stacked area), fill to bottom if it's empty
+    pub base_points: Vec<Point>,
 }
 
 impl StraightLineFill {
@@ -985,8 +1382,12 @@ impl StraightLineFill {
         let mut points = self.points.clone();
         let last = points[self.points.len() - 1];
         let first = points[0];
-        points.push((last.x, self.bottom).into());
-        points.push((first.x, self.bottom).into());
+        if self.base_points.is_empty() {
+            points.push((last.x, self.bottom).into());
+            points.push((first.x, self.bottom).into());
+        } else {
+            points.extend(self.base_points.iter().rev());
+        }
         points.push(first);
         let mut arr = vec![];
         for (index, p) in points.iter().enumerate() {
@@ -1004,10 +1405,11 @@ impl StraightLineFill {
         if self.close {
             arr.push('Z'.to_string());
         }
+        let (fill, fill_opacity) = convert_paint(&self.paint.clone().unwrap_or(self.fill.into()));
         let attrs = vec![
             (ATTR_D, arr.join(" ")),
-            (ATTR_FILL, self.fill.hex()),
-            (ATTR_FILL_OPACITY, convert_opacity(&self.fill)),
+            (ATTR_FILL, fill),
+            (ATTR_FILL_OPACITY, fill_opacity),
         ];
 
         SVGTag {
@@ -1110,6 +1512,8 @@ pub struct Axis {
     pub tick_length: f32,
     pub tick_start: usize,
     pub tick_interval: usize,
+    // the positions(0.0 - 1.0) of ticks and labels, they are split evenly if it's empty
+    pub positions: Vec<f32>,
 }
 impl Default for Axis {
     fn default() -> Self {
@@ -1133,6 +1537,7 @@ impl Default for Axis {
             tick_length: 5.0,
             tick_start: 0,
             tick_interval: 0,
+            positions: vec![],
         }
     }
 }
@@ -1206,8 +1611,9 @@ impl Axis {
                 .map(|item| format_string(item, formatter))
                 .collect();
             if self.position == Position::Top || self.position == Position::Bottom {
-                let f = font::get_font(&self.font_family).context(GetFontSnafu)?;
-                let total_measure = font::measure_text(f, font_size, &text_list.join(" "));
+                let fonts = font::get_fonts(&self.font_family, "", false).context(GetFontSnafu)?;
+                let total_measure =
+                    font::measure_text_fonts(&fonts, font_size, &text_list.join(" "));
                 // 位置不够
                 if total_measure.width() > axis_length {
                     text_unit_count += (total_measure.width() / axis_length).ceil() as usize;
@@ -1220,34 +1626,45 @@ impl Axis {
             split_number = self.data.len();
         }
         if !is_transparent {
-            let unit = axis_length / split_number as f32;
-            let tick_interval = self.tick_interval.max(text_unit_count);
-            let tick_start = self.tick_start;
-            for i in 0..=split_number {
-                if i < tick_start {
-                    continue;
-                }
-                let index = if i > tick_start { i - tick_start } else { i };
-                if i != tick_start && (tick_interval != 0 && index % tick_interval != 0) {
-                    continue;
+            let mut tick_offsets = vec![];
+            if self.positions.is_empty() {
+                let unit = axis_length / split_number as f32;
+                let tick_interval = self.tick_interval.max(text_unit_count);
+                let tick_start = self.tick_start;
+                for i in 0..=split_number {
+                    if i < tick_start {
+                        continue;
+                    }
+                    let index = if i > tick_start { i - tick_start } else { i };
+                    if i != tick_start && (tick_interval != 0 && index % tick_interval != 0) {
+                        continue;
+                    }
+                    tick_offsets.push(unit * i as f32);
                 }
-
+            } else {
+                tick_offsets = self
+                    .positions
+                    .iter()
+                    .map(|item| axis_length * item)
+                    .collect();
+            }
+            for offset in tick_offsets {
                 let values = match self.position {
                     Position::Top => {
-                        let x = left + unit * i as f32;
+                        let x = left + offset;
                         let y = top + height;
                         (x, y - tick_length, x, y)
                     }
                     Position::Right => {
-                        let y = top + unit * i as f32;
+                        let y = top + offset;
                         (left, y, left + tick_length, y)
                     }
                     Position::Bottom => {
-                        let x = left + unit * i as f32;
+                        let x = left + offset;
                         (x, top, x, top + tick_length)
                     }
                     _ => {
-                        let y = top + unit * i as f32;
+                        let y = top + offset;
                         let x = left + width;
                         (x, y, x - tick_length, y)
                     }
@@ -1270,7 +1687,7 @@ impl Axis {
         let name_rotate = self.name_rotate / std::f32::consts::PI * 180.0;
         if !text_list.is_empty() {
             let name_gap = self.name_gap;
-            let f = font::get_font(&self.font_family).context(GetFontSnafu)?;
+            let fonts = font::get_fonts(&self.font_family, "", false).context(GetFontSnafu)?;
             let mut data_len = self.data.len();
             let is_name_align_start = self.name_align == Align::Left;
             if is_name_align_start {
@@ -1282,11 +1699,14 @@ impl Axis {
                 if index % text_unit_count != 0 {
                     continue;
                 }
-                let b = font::measure_text(f, font_size, text);
+                let b = font::measure_text_fonts(&fonts, font_size, text);
                 let mut unit_offset = unit * index as f32 + unit / 2.0;
                 if is_name_align_start {
                     unit_offset -= unit / 2.0;
                 }
+                if let Some(position) = self.positions.get(index) {
+                    unit_offset = axis_length * position;
+                }
                 let text_width = b.width();
 
                 let values = match self.position {
@@ -1411,6 +1831,8 @@ pub struct Legend {
     pub left: f32,
     pub top: f32,
     pub category: LegendCategory,
+    // pattern fill of the rect legend
+    pub pattern: Option<Pattern>,
 }
 impl Legend {
     pub fn svg(&self) -> String {
@@ -1423,6 +1845,7 @@ impl Legend {
                     Rect {
                         color: self.stroke_color,
                         fill: self.stroke_color,
+                        paint: self.pattern.map(Paint::from),
                         left: self.left,
                         top: self.top + (LEGEND_HEIGHT - height) / 2.0,
                         width: LEGEND_WIDTH,
@@ -1438,6 +1861,7 @@ impl Legend {
                     Rect {
                         color: self.stroke_color,
                         fill: self.stroke_color,
+                        paint: self.pattern.map(Paint::from),
                         left: self.left,
                         top: self.top + (LEGEND_HEIGHT - height) / 2.0,
                         width: LEGEND_WIDTH,
@@ -1509,13 +1933,502 @@ impl Legend {
     }
 }
 
+pub(crate) static VISUAL_MAP_THICKNESS: f32 = 12.0;
+pub(crate) static VISUAL_MAP_LABEL_MARGIN: f32 = 4.0;
+static VISUAL_MAP_PIECE_GAP: f32 = 2.0;
+pub(crate) static VISUAL_MAP_GRADIENT_STOPS: usize = 16;
+
+/// The visual map explains the color of value, it is a gradient bar for continuous scale
+/// and pieces for stepped scale. The min value is at the left or bottom.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct VisualMap {
+    // the domain of scale should not be empty
+    pub scale: ColorScale,
+    pub font_family: String,
+    pub font_size: f32,
+    pub font_color: Option<Color>,
+    pub font_weight: Option<String>,
+    // the formatter of tick labels, e.g. {c}ms
+    pub formatter: String,
+    // the colors of gradient stops from min to max, they are evenly distributed,
+    // the colors of scale are sampled if it is empty
+    pub gradient_colors: Vec<Color>,
+    // the split number of continuous bar
+    pub split_number: usize,
+    pub vertical: bool,
+    pub left: f32,
+    pub top: f32,
+    // the length of bar
+    pub length: f32,
+    // the thickness of bar
+    pub thickness: f32,
+}
+
+impl VisualMap {
+    /// Gets the ticks (percent, label) of visual map,
+    /// they are the boundaries of pieces for stepped scale.
+    fn get_ticks(&self) -> Vec<(f32, String)> {
+        let count = match self.scale.steps {
+            Some(steps) if steps > 0 => steps,
+            _ => self.split_number.max(1),
+        };
+        (0..=count)
+            .map(|index| {
+                let percent = index as f32 / count as f32;
+                let value = format_float(self.scale.get_percent_value(percent));
+                (percent, format_string(&value, &self.formatter))
+            })
+            .collect()
+    }
+    /// Gets the gradient of continuous bar, it is none for stepped scale.
+    pub fn get_gradient(&self) -> Option<Gradient> {
+        if self.scale.steps.is_some() || self.scale.colors.is_empty() {
+            return None;
+        }
+        let stops = if self.gradient_colors.len() > 1 {
+            let count = self.gradient_colors.len() - 1;
+            self.gradient_colors
+                .iter()
+                .enumerate()
+                .map(|(index, color)| (index as f32 / count as f32, *color).into())
+                .collect()
+        } else {
+            (0..=VISUAL_MAP_GRADIENT_STOPS)
+                .map(|index| {
+                    let offset = index as f32 / VISUAL_MAP_GRADIENT_STOPS as f32;
+                    (offset, self.scale.get_percent_color(offset)).into()
+                })
+                .collect()
+        };
+        let (x2, y1) = if self.vertical {
+            (0.0, 1.0)
+        } else {
+            (1.0, 0.0)
+        };
+        Some(Gradient::Linear(LinearGradient {
+            x1: 0.0,
+            y1,
+            x2,
+            y2: 0.0,
+            stops,
+        }))
+    }
+    /// Gets the size (width, height) of visual map, include the tick labels.
+    pub fn size(&self) -> Result<(f32, f32)> {
+        if !self.vertical {
+            return Ok((
+                self.length,
+                self.thickness + VISUAL_MAP_LABEL_MARGIN + self.font_size,
+            ));
+        }
+        let mut label_width = 0.0_f32;
+        for (_, text) in self.get_ticks().iter() {
+            let b = measure_text_width_family(&self.font_family, self.font_size, text)
+                .context(GetFontSnafu)?;
+            label_width = label_width.max(b.width());
+        }
+        Ok((
+            self.thickness + VISUAL_MAP_LABEL_MARGIN + label_width,
+            self.length,
+        ))
+    }
+    pub fn svg(&self) -> Result<String> {
+        let mut data = vec![];
+        match self.scale.steps {
+            Some(steps) if steps > 0 => {
+                let piece_length = ((self.length - VISUAL_MAP_PIECE_GAP * (steps - 1) as f32)
+                    / steps as f32)
+                    .max(1.0);
+                for index in 0..steps {
+                    let color = self
+                        .scale
+                        .get_percent_color((index as f32 + 0.5) / steps as f32);
+                    let offset = (piece_length + VISUAL_MAP_PIECE_GAP) * index as f32;
+                    let rect = if self.vertical {
+                        Rect {
+                            left: self.left,
+                            top: self.top + self.length - offset - piece_length,
+                            width: self.thickness,
+                            height: piece_length,
+                            ..Default::default()
+                        }
+                    } else {
+                        Rect {
+                            left: self.left + offset,
+                            top: self.top,
+                            width: piece_length,
+                            height: self.thickness,
+                            ..Default::default()
+                        }
+                    };
+                    data.push(
+                        Rect {
+                            fill: Some(color),
+                            ..rect
+                        }
+                        .svg(),
+                    );
+                }
+            }
+            _ => {
+                if let Some(gradient) = self.get_gradient() {
+                    let (width, height) = if self.vertical {
+                        (self.thickness, self.length)
+                    } else {
+                        (self.length, self.thickness)
+                    };
+                    data.push(
+                        Rect {
+                            paint: Some(gradient.into()),
+                            left: self.left,
+                            top: self.top,
+                            width,
+                            height,
+                            ..Default::default()
+                        }
+                        .svg(),
+                    );
+                }
+            }
+        }
+
+        let ticks = self.get_ticks();
+        let last = ticks.len() - 1;
+        let mut text_widths = vec![];
+        for (_, text) in ticks.iter() {
+            let b = measure_text_width_family(&self.font_family, self.font_size, text)
+                .context(GetFontSnafu)?;
+            text_widths.push(b.width());
+        }
+        // the right of previous label, the label is omitted if it overlaps
+        let gap = VISUAL_MAP_LABEL_MARGIN;
+        let mut prev_right = f32::MIN;
+        for (index, (percent, text)) in ticks.into_iter().enumerate() {
+            let text_width = text_widths[index];
+            // the first and last labels are aligned to the ends of bar
+            let (x, y) = if self.vertical {
+                let position = self.top + self.length * (1.0 - percent);
+                let y = if index == 0 {
+                    position
+                } else if index == last {
+                    position + self.font_size
+                } else {
+                    position + self.font_size / 2.0 - 1.0
+                };
+                (self.left + self.thickness + VISUAL_MAP_LABEL_MARGIN, y)
+            } else {
+                let position = self.left + self.length * percent;
+                let x = if index == 0 {
+                    position
+                } else if index == last {
+                    position - text_width
+                } else {
+                    position - text_width / 2.0
+                };
+                let last_left = self.left + self.length - text_widths[last];
+                if index != last
+                    && (x < prev_right || (index != 0 && x + text_width + gap > last_left))
+                {
+                    continue;
+                }
+                prev_right = x + text_width + gap;
+                (
+                    x,
+                    self.top + self.thickness + VISUAL_MAP_LABEL_MARGIN + self.font_size,
+                )
+            };
+            data.push(
+                Text {
+                    text,
+                    font_family: Some(self.font_family.clone()),
+                    font_color: self.font_color,
+                    font_size: Some(self.font_size),
+                    font_weight: self.font_weight.clone(),
+                    x: Some(x),
+                    y: Some(y),
+                    ..Default::default()
+                }
+                .svg(),
+            );
+        }
+        Ok(SVGTag {
+            tag: TAG_GROUP,
+            data: Some(data.join("\n")),
+            ..Default::default()
+        }
+        .to_string())
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Interactive {
+    // the class of group, e.g. charts-rs-item
+    pub class: String,
+    pub series: String,
+    pub category: Option<String>,
+    pub value: Option<String>,
+    // the tooltip of group
+    pub title: Option<String>,
+}
+
+impl Interactive {
+    /// Creates an interactive item of series,
+    /// the tooltip is generated by series name, category and value text.
+    pub fn new_item(series: &str, category: Option<String>, value: String, text: &str) -> Self {
+        let mut lines = vec![];
+        if !series.is_empty() {
+            lines.push(series.to_string());
+        }
+        if let Some(category) = &category {
+            lines.push(format!("{category}: {text}"));
+        } else {
+            lines.push(text.to_string());
+        }
+        Interactive {
+            class: INTERACTIVE_ITEM_CLASS.to_string(),
+            series: series.to_string(),
+            category,
+            value: Some(value),
+            title: Some(lines.join("\n")),
+        }
+    }
+    /// Creates an interactive group of whole series.
+    pub fn new_series(series: &str) -> Self {
+        Interactive {
+            class: INTERACTIVE_SERIES_CLASS.to_string(),
+            series: series.to_string(),
+            ..Default::default()
+        }
+    }
+    /// Creates an interactive group of legend.
+    pub fn new_legend(series: &str) -> Self {
+        Interactive {
+            class: INTERACTIVE_LEGEND_CLASS.to_string(),
+            series: series.to_string(),
+            ..Default::default()
+        }
+    }
+    /// Converts interactive group to svg, the data is the svg of children.
+    pub fn svg(&self, data: String) -> String {
+        if data.is_empty() {
+            return "".to_string();
+        }
+        let mut children = vec![];
+        if let Some(title) = &self.title {
+            children.push(format!("<{TAG_TITLE}>{}</{TAG_TITLE}>", escape_xml(title)));
+        }
+        children.push(data);
+        let option_value = |value: &Option<String>| -> String {
+            value.as_ref().map(|v| escape_xml(v)).unwrap_or_default()
+        };
+        SVGTag::new(
+            TAG_GROUP,
+            children.join("\n"),
+            vec![
+                (ATTR_CLASS, self.class.clone()),
+                (ATTR_DATA_SERIES, escape_xml(&self.series)),
+                (ATTR_DATA_CATEGORY, option_value(&self.category)),
+                (ATTR_DATA_VALUE, option_value(&self.value)),
+            ],
+        )
+        .to_string()
+    }
+}
+
+/// The entrance animation of widgets.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Animation {
+    // grows from the position, e.g. bottom for the bar of positive value
+    Grow(Position),
+    // draws the line in by stroke-dashoffset, the length should not be less than the line
+    Draw { length: f32 },
+    // sweeps clockwise from the top, the children are revealed by
+    // the mask of circle whose stroke is drawn in
+    Sweep { cx: f32, cy: f32, r: f32 },
+    Fade,
+}
+
+impl Animation {
+    /// Converts animation group to svg, the data is the svg of children.
+    pub fn svg(&self, data: String) -> String {
+        if data.is_empty() {
+            return "".to_string();
+        }
+        let (class, style) = match self {
+            Animation::Grow(position) => match position {
+                Position::Left => (
+                    "charts-rs-grow-x",
+                    "transform-origin:left center".to_string(),
+                ),
+                Position::Right => (
+                    "charts-rs-grow-x",
+                    "transform-origin:right center".to_string(),
+                ),
+                Position::Top => (
+                    "charts-rs-grow-y",
+                    "transform-origin:center top".to_string(),
+                ),
+                _ => (
+                    "charts-rs-grow-y",
+                    "transform-origin:center bottom".to_string(),
+                ),
+            },
+            Animation::Draw { length } => (
+                "charts-rs-draw",
+                format!("--charts-rs-length:{}", format_float(length.ceil())),
+            ),
+            Animation::Sweep { cx, cy, r } => {
+                return sweep_svg(*cx, *cy, *r, data);
+            }
+            Animation::Fade => ("charts-rs-fade", "".to_string()),
+        };
+        SVGTag::new(
+            TAG_GROUP,
+            data,
+            vec![(ATTR_CLASS, class.to_string()), (ATTR_STYLE, style)],
+        )
+        .to_string()
+    }
+}
+
+/// Converts the sweep animation to svg, the stroke of mask circle covers
+/// the pie and its dash offset is animated from the circumference to zero.
+fn sweep_svg(cx: f32, cy: f32, r: f32, data: String) -> String {
+    // the mask is a little larger than the pie for its stroke
+    let r = r + 2.0;
+    let id = format!(
+        "charts-rs-sweep-{}-{}-{}",
+        cx.round() as i32,
+        cy.round() as i32,
+        r.round() as i32
+    );
+    let circumference = (std::f32::consts::PI * r).ceil();
+    let circle = SVGTag {
+        tag: TAG_CIRCLE,
+        attrs: vec![
+            (ATTR_CLASS, "charts-rs-sweep".to_string()),
+            (
+                ATTR_STYLE,
+                format!("--charts-rs-length:{}", format_float(circumference)),
+            ),
+            (ATTR_CX, format_float(cx)),
+            (ATTR_CY, format_float(cy)),
+            (ATTR_R, format_float(r / 2.0)),
+            (ATTR_FILL, "none".to_string()),
+            (ATTR_STROKE, "#FFFFFF".to_string()),
+            (ATTR_STROKE_WIDTH, format_float(r)),
+            (ATTR_STROKE_DASH_ARRAY, format_float(circumference)),
+            (
+                ATTR_TRANSFORM,
+                format!("rotate(-90 {} {})", format_float(cx), format_float(cy)),
+            ),
+        ],
+        data: None,
+    };
+    [
+        SVGTag::new(TAG_MASK, circle.to_string(), vec![(ATTR_ID, id.clone())]).to_string(),
+        SVGTag::new(TAG_GROUP, data, vec![(ATTR_MASK, format!("url(#{id})"))]).to_string(),
+    ]
+    .join("\n")
+}
+
+/// Generates the style of entrance animation.
+pub(crate) fn generate_animation_style() -> String {
+    SVGTag::new(TAG_STYLE, ANIMATION_STYLE.to_string(), vec![]).to_string()
+}
+
+/// Generates the style and script for interactive svg.
+pub(crate) fn generate_interactive_script() -> String {
+    [
+        SVGTag::new(TAG_STYLE, INTERACTIVE_STYLE.to_string(), vec![]).to_string(),
+        SVGTag::new(
+            TAG_SCRIPT,
+            format!("<![CDATA[\n{INTERACTIVE_SCRIPT}\n]]>"),
+            vec![],
+        )
+        .to_string(),
+    ]
+    .join("\n")
+}
+
+/// The accessible metadata of svg, the title and desc are written
+/// as the first children and the data table is visually hidden.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Accessibility {
+    pub title: String,
+    pub desc: String,
+    // the rows of data table, the first row is header
+    pub table: Option<Vec<Vec<String>>>,
+}
+
+impl Accessibility {
+    /// Gets the role of svg, the figure role keeps the data table
+    /// readable, the img role treats the svg as a single image.
+    fn role(&self) -> &str {
+        if self.table.is_some() {
+            "figure"
+        } else {
+            "img"
+        }
+    }
+    /// Gets the aria label of svg, it's the title or desc.
+    fn label(&self) -> &str {
+        if self.title.is_empty() {
+            &self.desc
+        } else {
+            &self.title
+        }
+    }
+    /// Converts the data table to a html table in foreign object,
+    /// the size of foreign object is 1px so it's hidden from view.
+    fn table_svg(&self, table: &[Vec<String>]) -> String {
+        let mut rows = vec![];
+        if !self.title.is_empty() {
+            rows.push(format!(
+                "<{TAG_CAPTION}>{}</{TAG_CAPTION}>",
+                escape_xml(&self.title)
+            ));
+        }
+        for (index, row) in table.iter().enumerate() {
+            let tag = if index == 0 { TAG_TH } else { TAG_TD };
+            let cells: Vec<String> = row
+                .iter()
+                .map(|cell| format!("<{tag}>{}</{tag}>", escape_xml(cell)))
+                .collect();
+            rows.push(format!("<{TAG_TR}>{}</{TAG_TR}>", cells.join("")));
+        }
+        SVGTag::new(
+            TAG_FOREIGN_OBJECT,
+            SVGTag::new(
+                TAG_TABLE,
+                rows.join("\n"),
+                vec![(ATTR_XMLNS, "http://www.w3.org/1999/xhtml".to_string())],
+            )
+            .to_string(),
+            vec![
+                (ATTR_X, "0".to_string()),
+                (ATTR_Y, "0".to_string()),
+                (ATTR_WIDTH, "1".to_string()),
+                (ATTR_HEIGHT, "1".to_string()),
+                (ATTR_OVERFLOW, "hidden".to_string()),
+            ],
+        )
+        .to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        Arrow, Axis, Bubble, Circle, Grid, Legend, LegendCategory, Line, Pie, Polygon, Polyline,
-        Rect, SmoothLine, SmoothLineFill, StraightLine, StraightLineFill, Text,
+        generate_accessible_svg, generate_paint_defs, generate_svg, Accessibility, Arrow, Axis,
+        BezierBand, Bubble, Circle, Grid, Interactive, Legend, LegendCategory, Line, Pie, Polygon,
+        Polyline, Rect, SmoothLine, SmoothLineFill, StraightLine, StraightLineFill, Text,
+        VisualMap,
+    };
+    use crate::{
+        Align, Color, ColorScale, Gradient, Pattern, PatternCategory, Position, Symbol,
+        DEFAULT_FONT_FAMILY,
     };
-    use crate::{Align, Position, Symbol, DEFAULT_FONT_FAMILY};
     use pretty_assertions::assert_eq;
     #[test]
     fn line() {
@@ -1587,6 +2500,7 @@ mod tests {
             Rect {
                 color: Some((0, 0, 0).into()),
                 fill: Some((255, 255, 255).into()),
+                paint: None,
                 left: 0.0,
                 top: 0.0,
                 width: 50.0,
@@ -1602,6 +2516,7 @@ mod tests {
             Rect {
                 color: Some((0, 0, 0, 128).into()),
                 fill: Some((255, 255, 255, 50).into()),
+                paint: None,
                 left: 0.0,
                 top: 0.0,
                 width: 50.0,
@@ -1761,6 +2676,7 @@ mod tests {
             Polygon {
                 color: Some((0, 0, 0).into()),
                 fill: Some((255, 255, 255).into()),
+                paint: None,
                 points: vec![
                     (0.0, 0.0).into(),
                     (10.0, 30.0).into(),
@@ -1775,6 +2691,7 @@ mod tests {
             Polygon {
                 color: Some((0, 0, 0, 128).into()),
                 fill: Some((255, 255, 255, 20).into()),
+                paint: None,
                 points: vec![
                     (0.0, 0.0).into(),
                     (10.0, 30.0).into(),
@@ -1789,6 +2706,7 @@ mod tests {
             Polygon {
                 color: None,
                 fill: None,
+                paint: None,
                 points: vec![
                     (0.0, 0.0).into(),
                     (10.0, 30.0).into(),
@@ -1832,6 +2750,88 @@ Hello World!
             }
             .svg()
         );
+
+        assert_eq!(
+            r###"<text font-size="14" font-family="'Noto Sans CJK', Roboto">
+你好
+</text>"###,
+            Text {
+                text: "你好".to_string(),
+                font_family: Some("Noto Sans CJK".to_string()),
+                font_size: Some(14.0),
+                ..Default::default()
+            }
+            .svg()
+        );
+    }
+
+    #[test]
+    fn bezier_band() {
+        let band = BezierBand {
+            fill: Color::from((84, 112, 198, 100)).into(),
+            x1: 10.0,
+            y1: 20.0,
+            x2: 110.0,
+            y2: 60.0,
+            width: 15.0,
+        };
+        assert_eq!(
+            r###"<path d="M10 20 C60 20, 60 60, 110 60 L110 75 C60 75, 60 35, 10 35 Z" fill="#5470C6" fill-opacity="0.4"/>"###,
+            band.svg()
+        );
+        assert_eq!("", BezierBand { width: 0.0, ..band }.svg());
+    }
+
+    #[test]
+    fn visual_map() {
+        let mut visual_map = VisualMap {
+            scale: ColorScale::new(vec![Color::black(), Color::white()], 0.0, 10.0),
+            font_family: DEFAULT_FONT_FAMILY.to_string(),
+            font_size: 12.0,
+            split_number: 2,
+            left: 10.0,
+            top: 20.0,
+            length: 100.0,
+            thickness: 10.0,
+            ..Default::default()
+        };
+        assert_eq!((100.0, 26.0), visual_map.size().unwrap());
+        assert_eq!(
+            r###"<g>
+<rect x="10" y="20" width="100" height="10" fill="url(#charts-rs-gradient-a0de658e)"/>
+<text font-size="12" x="10" y="46" font-family="Roboto">
+0
+</text>
+<text font-size="12" x="56.5" y="46" font-family="Roboto">
+5
+</text>
+<text font-size="12" x="96" y="46" font-family="Roboto">
+10
+</text>
+</g>"###,
+            visual_map.svg().unwrap()
+        );
+
+        visual_map.scale.steps = Some(2);
+        visual_map.vertical = true;
+        visual_map.formatter = "{c}%".to_string();
+        assert_eq!(None, visual_map.get_gradient());
+        assert_eq!(
+            r###"<g>
+<rect x="10" y="71" width="10" height="49" fill="#000000"/>
+<rect x="10" y="20" width="10" height="49" fill="#FFFFFF"/>
+<text font-size="12" x="24" y="120" font-family="Roboto">
+0%
+</text>
+<text font-size="12" x="24" y="75" font-family="Roboto">
+5%
+</text>
+<text font-size="12" x="24" y="32" font-family="Roboto">
+10%
+</text>
+</g>"###,
+            visual_map.svg().unwrap()
+        );
     }
 
     #[test]
@@ -1854,6 +2854,7 @@ Hello World!
 
         let p = Pie {
             fill: (0, 0, 0, 128).into(),
+            paint: None,
             stroke_color: Some((0, 0, 0).into()),
             cx: 250.0,
             cy: 250.0,
@@ -2021,6 +3022,7 @@ Hello World!
                     (40.0, 50.0).into(),
                 ],
                 bottom: 100.0,
+                ..Default::default()
             }
             .svg()
         );
@@ -2188,4 +3190,184 @@ Line
             .svg()
         );
     }
+
+    #[test]
+    fn interactive() {
+        let rect = r###"<rect x="0" y="0" width="10" height="10"/>"###;
+        assert_eq!(
+            r###"<g class="charts-rs-item" data-series="Email &amp; Ads" data-category="&lt;Mon&gt;" data-value="120">
+<title>Email &amp; Ads
+&lt;Mon&gt;: 120 ml</title>
+<rect x="0" y="0" width="10" height="10"/>
+</g>"###,
+            Interactive::new_item(
+                "Email & Ads",
+                Some("<Mon>".to_string()),
+                "120".to_string(),
+                "120 ml"
+            )
+            .svg(rect.to_string())
+        );
+
+        assert_eq!(
+            r###"<g class="charts-rs-legend" data-series="Email">
+<rect x="0" y="0" width="10" height="10"/>
+</g>"###,
+            Interactive::new_legend("Email").svg(rect.to_string())
+        );
+
+        assert_eq!("", Interactive::new_series("Email").svg("".to_string()));
+    }
+
+    #[test]
+    fn accessibility() {
+        let rect = r###"<rect x="0" y="0" width="10" height="10"/>"###;
+        assert_eq!(
+            r###"<svg width="100" height="50" viewBox="0 0 100 50" xmlns="http://www.w3.org/2000/svg">
+<rect x="0" y="0" width="10" height="10"/>
+</svg>"###,
+            generate_svg(100.0, 50.0, 0.0, 0.0, rect.to_string())
+        );
+
+        assert_eq!(
+            r###"<svg width="100" height="50" viewBox="0 0 100 50" xmlns="http://www.w3.org/2000/svg" role="img" aria-label="Series: Email">
+<desc>Series: Email</desc>
+<rect x="0" y="0" width="10" height="10"/>
+</svg>"###,
+            generate_accessible_svg(
+                100.0,
+                50.0,
+                0.0,
+                0.0,
+                &Accessibility {
+                    desc: "Series: Email".to_string(),
+                    ..Default::default()
+                },
+                rect.to_string()
+            )
+        );
+
+        assert_eq!(
+            r###"<svg width="100" height="50" viewBox="0 0 100 50" xmlns="http://www.w3.org/2000/svg" role="figure" aria-label="Sales &amp; Ads">
+<title>Sales &amp; Ads</title>
+<rect x="0" y="0" width="10" height="10"/>
+<foreignObject x="0" y="0" width="1" height="1" overflow="hidden">
+<table xmlns="http://www.w3.org/1999/xhtml">
+<caption>Sales &amp; Ads</caption>
+<tr><th>Series</th><th>Values</th></tr>
+<tr><td>&lt;Email&gt;</td><td>120, 132</td></tr>
+</table>
+</foreignObject>
+</svg>"###,
+            generate_accessible_svg(
+                100.0,
+                50.0,
+                0.0,
+                0.0,
+                &Accessibility {
+                    title: "Sales & Ads".to_string(),
+                    table: Some(vec![
+                        vec!["Series".to_string(), "Values".to_string()],
+                        vec!["<Email>".to_string(), "120, 132".to_string()],
+                    ]),
+                    ..Default::default()
+                },
+                rect.to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn gradient() {
+        let linear = Gradient::new_linear(vec![
+            (0.0, Color::from("#5470c6")).into(),
+            (1.0, Color::from("#5470c6").with_alpha(0)).into(),
+        ]);
+        let radial = Gradient::new_radial(vec![
+            (0.0, Color::white()).into(),
+            (1.0, Color::from("#91cc75")).into(),
+        ]);
+        assert_ne!(linear.id(), radial.id());
+        assert_eq!(linear.id(), linear.clone().id());
+
+        assert_eq!(
+            r###"<rect x="10" y="10" width="30" height="20" fill="url(#charts-rs-gradient-3377cdb5)"/>"###,
+            Rect {
+                paint: Some(linear.clone().into()),
+                left: 10.0,
+                top: 10.0,
+                width: 30.0,
+                height: 20.0,
+                ..Default::default()
+            }
+            .svg()
+        );
+
+        assert_eq!(
+            r###"<defs>
+<linearGradient id="charts-rs-gradient-3377cdb5" x1="0" y1="0" x2="0" y2="1">
+<stop offset="0" stop-color="#5470C6"/>
+<stop offset="1" stop-color="#5470C6" stop-opacity="0"/>
+</linearGradient>
+<radialGradient id="charts-rs-gradient-bc10a455" cx="0.5" cy="0.5" r="0.5">
+<stop offset="0" stop-color="#FFFFFF"/>
+<stop offset="1" stop-color="#91CC75"/>
+</radialGradient>
+</defs>"###,
+            generate_paint_defs(&[linear.clone().into(), radial.into(), linear.into()])
+        );
+        assert_eq!("", generate_paint_defs(&[]));
+    }
+
+    #[test]
+    fn pattern() {
+        let hatch = Pattern::new(PatternCategory::DiagonalHatch, Color::black());
+        let dots = Pattern {
+            category: PatternCategory::Dots,
+            ..Default::default()
+        };
+        let cross_hatch = Pattern {
+            category: PatternCategory::CrossHatch,
+            size: 10.0,
+            stroke_width: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            r###"<defs>
+<pattern id="charts-rs-pattern-2c262ade" patternUnits="userSpaceOnUse" width="8" height="8">
+<rect x="0" y="0" width="8" height="8" fill="#000000" fill-opacity="0.2"/>
+<path d="M0,8 L8,0 M-2,2 L2,-2 M6,10 L10,6" stroke="#000000" stroke-width="2"/>
+</pattern>
+<pattern id="charts-rs-pattern-b0e197ed" patternUnits="userSpaceOnUse" width="8" height="8">
+<circle cx="4" cy="4" r="2" stroke-width="0" fill="#000000"/>
+</pattern>
+<pattern id="charts-rs-pattern-f1afe7b3" patternUnits="userSpaceOnUse" width="10" height="10">
+<path d="M0,10 L10,0 M-2.5,2.5 L2.5,-2.5 M7.5,12.5 L12.5,7.5 M0,0 L10,10 M-2.5,7.5 L2.5,12.5 M7.5,-2.5 L12.5,2.5" stroke="#000000" stroke-width="1"/>
+</pattern>
+</defs>"###,
+            generate_paint_defs(&[hatch.into(), dots.into(), cross_hatch.into(), hatch.into()])
+        );
+
+        assert_eq!(
+            r###"<g>
+<rect x="10" y="35" width="25" height="10" stroke="#000000" fill="url(#charts-rs-pattern-2c262ade)"/>
+<text font-size="14" x="38" y="44" font-family="Roboto" fill="#000000">
+Line
+</text>
+</g>"###,
+            Legend {
+                text: "Line".to_string(),
+                font_size: 14.0,
+                font_family: DEFAULT_FONT_FAMILY.to_string(),
+                font_color: Some((0, 0, 0).into()),
+                stroke_color: Some((0, 0, 0).into()),
+                left: 10.0,
+                top: 30.0,
+                category: LegendCategory::Rect,
+                pattern: Some(hatch),
+                ..Default::default()
+            }
+            .svg()
+        );
+    }
 }
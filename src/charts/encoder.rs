@@ -3,12 +3,11 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use image::ImageFormat;
-use once_cell::sync::OnceCell;
 use resvg::{tiny_skia, usvg};
 use snafu::{ResultExt, Snafu};
 use std::io::Cursor;
-use std::sync::Arc;
-use usvg::fontdb;
+
+use super::font::get_or_init_image_fontdb;
 
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -28,25 +27,8 @@ pub enum Error {
 }
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
-pub(crate) fn get_or_init_fontdb(fonts: Option<Vec<&[u8]>>) -> Arc<fontdb::Database> {
-    static GLOBAL_FONT_DB: OnceCell<Arc<fontdb::Database>> = OnceCell::new();
-    GLOBAL_FONT_DB
-        .get_or_init(|| {
-            let mut fontdb = fontdb::Database::new();
-            if let Some(value) = fonts {
-                for item in value.iter() {
-                    fontdb.load_font_data((*item).to_vec());
-                }
-            } else {
-                fontdb.load_system_fonts();
-            }
-            Arc::new(fontdb)
-        })
-        .clone()
-}
-
 fn save_image(svg: &str, format: image::ImageFormat) -> Result<Vec<u8>> {
-    let fontdb = get_or_init_fontdb(None);
+    let fontdb = get_or_init_image_fontdb();
     let tree = usvg::Tree::from_str(
         svg,
         &usvg::Options {
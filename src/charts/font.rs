@@ -3,11 +3,15 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use super::util::*;
+use arc_swap::ArcSwap;
+use fontdb::{Database, Family, Query, Source, Style, Weight, ID};
 use fontdue::layout::{CoordinateSystem, Layout, TextStyle};
 use fontdue::Font;
-use once_cell::sync::OnceCell;
+use once_cell::sync::{Lazy, OnceCell};
 use snafu::Snafu;
+use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -15,6 +19,10 @@ pub enum Error {
     FontNotFound { name: String },
     #[snafu(display("Error parse font: {message}"))]
     ParseFont { message: String },
+    #[snafu(display("Error font: {family} exists"))]
+    FontExists { family: String },
+    #[snafu(display("Error font: default font {family} can not be removed"))]
+    RemoveDefaultFont { family: String },
 }
 
 impl From<&str> for Error {
@@ -30,49 +38,399 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 pub static DEFAULT_FONT_FAMILY: &str = "Roboto";
 pub static DEFAULT_FONT_DATA: &[u8] = include_bytes!("../Roboto.ttf");
 
-fn get_family_from_font(font: &fontdue::Font) -> String {
-    if let Ok(re) = regex::Regex::new(r#"name:( ?)Some\("(?P<family>[\S ]+)"\)"#) {
-        let desc = format!("{:?}", font);
-        if let Some(caps) = re.captures(&desc) {
-            let mut family = caps["family"].to_string();
-            // https://developer.mozilla.org/en-US/docs/Web/CSS/font-weight
-            // replace some font weight
-            if let Ok(weight) = regex::Regex::new(r#"Thin|Light|Regular|Medium|Bold|Black$"#) {
-                family = weight.replace_all(&family, "").to_string();
+/// The font registry of charts, the font data is loaded once and shared by
+/// text measurement(fontdue) and svg rendering(fontdb).
+#[derive(Default)]
+pub struct FontRegistry {
+    db: Arc<Database>,
+    fonts: HashMap<ID, Arc<Font>>,
+    // the regular font of each family, it is built on demand
+    regular_fonts: OnceCell<Arc<HashMap<String, Font>>>,
+}
+
+/// Converts css font weight to number, e.g. bold -> 700.
+pub(crate) fn parse_font_weight(font_weight: &str) -> u16 {
+    match font_weight.trim() {
+        "" | "normal" => Weight::NORMAL.0,
+        "bold" | "bolder" => Weight::BOLD.0,
+        "lighter" => Weight::LIGHT.0,
+        value => value
+            .parse::<u16>()
+            .unwrap_or(Weight::NORMAL.0)
+            .clamp(1, 1000),
+    }
+}
+
+fn get_face_family(face: &fontdb::FaceInfo) -> String {
+    face.families
+        .first()
+        .map(|(family, _)| family.clone())
+        .unwrap_or_default()
+}
+
+impl FontRegistry {
+    /// Creates a new registry with the font source, the face with the same
+    /// family, weight and style as an existing face returns an error.
+    fn with_font(&self, source: Source) -> Result<(FontRegistry, Vec<String>)> {
+        let mut db = (*self.db).clone();
+        let ids = db.load_font_source(source);
+        if ids.is_empty() {
+            return Err("font data is invalid".into());
+        }
+        let mut fonts = self.fonts.clone();
+        let mut families = vec![];
+        for id in ids.iter() {
+            let Some(face) = db.face(*id) else {
+                continue;
+            };
+            let family = get_face_family(face);
+            if self.db.faces().any(|item| {
+                get_face_family(item) == family
+                    && item.weight == face.weight
+                    && item.style == face.style
+            }) {
+                return FontExistsSnafu { family }.fail();
+            }
+            let font = db
+                .with_face_data(*id, |data, index| {
+                    Font::from_bytes(
+                        data,
+                        fontdue::FontSettings {
+                            collection_index: index,
+                            ..Default::default()
+                        },
+                    )
+                })
+                .ok_or(Error::FontNotFound {
+                    name: family.clone(),
+                })??;
+            fonts.insert(*id, Arc::new(font));
+            if !families.contains(&family) {
+                families.push(family);
+            }
+        }
+        Ok((
+            FontRegistry {
+                db: Arc::new(db),
+                fonts,
+                ..Default::default()
+            },
+            families,
+        ))
+    }
+    /// Creates a new registry with the system fonts, the face with the same
+    /// family, weight and style as an existing face is skipped.
+    #[cfg(feature = "system-fonts")]
+    fn with_system_fonts(&self) -> (FontRegistry, Vec<String>) {
+        let mut db = (*self.db).clone();
+        db.load_system_fonts();
+        let mut fonts = self.fonts.clone();
+        let mut families = vec![];
+        let ids: Vec<ID> = db
+            .faces()
+            .filter(|face| !self.fonts.contains_key(&face.id))
+            .map(|face| face.id)
+            .collect();
+        for id in ids.into_iter() {
+            let Some(face) = db.face(id) else {
+                continue;
+            };
+            let family = get_face_family(face);
+            let exists = db.faces().any(|item| {
+                item.id != id
+                    && (self.fonts.contains_key(&item.id) || fonts.contains_key(&item.id))
+                    && get_face_family(item) == family
+                    && item.weight == face.weight
+                    && item.style == face.style
+            });
+            let font = if exists {
+                None
+            } else {
+                db.with_face_data(id, |data, index| {
+                    Font::from_bytes(
+                        data,
+                        fontdue::FontSettings {
+                            collection_index: index,
+                            ..Default::default()
+                        },
+                    )
+                    .ok()
+                })
+                .flatten()
+            };
+            let Some(font) = font else {
+                db.remove_face(id);
+                continue;
+            };
+            fonts.insert(id, Arc::new(font));
+            if !families.contains(&family) {
+                families.push(family);
+            }
+        }
+        (
+            FontRegistry {
+                db: Arc::new(db),
+                fonts,
+                ..Default::default()
+            },
+            families,
+        )
+    }
+    /// Creates a new registry without the faces of font family.
+    fn without_family(&self, family: &str) -> Result<FontRegistry> {
+        if family == DEFAULT_FONT_FAMILY {
+            return RemoveDefaultFontSnafu { family }.fail();
+        }
+        let ids: Vec<ID> = self
+            .db
+            .faces()
+            .filter(|face| get_face_family(face) == family)
+            .map(|face| face.id)
+            .collect();
+        if ids.is_empty() {
+            return FontNotFoundSnafu { name: family }.fail();
+        }
+        let mut db = (*self.db).clone();
+        let mut fonts = self.fonts.clone();
+        for id in ids.iter() {
+            db.remove_face(*id);
+            fonts.remove(id);
+        }
+        Ok(FontRegistry {
+            db: Arc::new(db),
+            fonts,
+            ..Default::default()
+        })
+    }
+    /// Gets the font database, it's used for rendering svg.
+    pub fn database(&self) -> Arc<Database> {
+        self.db.clone()
+    }
+    /// Gets all font families.
+    pub fn families(&self) -> Vec<String> {
+        let mut families = vec![];
+        for face in self.db.faces() {
+            let family = get_face_family(face);
+            if !families.contains(&family) {
+                families.push(family);
             }
-            return family.trim().to_string();
-        }
-    }
-    "".to_string()
-}
-
-pub fn get_or_try_init_fonts(fonts: Option<Vec<&[u8]>>) -> Result<&'static HashMap<String, Font>> {
-    static GLOBAL_FONTS: OnceCell<HashMap<String, Font>> = OnceCell::new();
-    GLOBAL_FONTS.get_or_try_init(|| {
-        let mut m = HashMap::new();
-        // init fonts, will returns an error if load font fails.
-        let font = fontdue::Font::from_bytes(DEFAULT_FONT_DATA, fontdue::FontSettings::default())?;
-        m.insert(DEFAULT_FONT_FAMILY.to_string(), font);
-        let mut font_datas = vec![DEFAULT_FONT_DATA];
-        if let Some(value) = fonts {
-            for data in value.iter() {
-                let font = fontdue::Font::from_bytes(*data, fontdue::FontSettings::default())?;
-                let family = get_family_from_font(&font);
-                if !family.is_empty() {
-                    m.insert(family, font);
-                    font_datas.push(*data);
+        }
+        families
+    }
+    /// Gets the regular font of each family.
+    pub fn get_regular_fonts(&self) -> Arc<HashMap<String, Font>> {
+        self.regular_fonts
+            .get_or_init(|| {
+                let mut m = HashMap::new();
+                for family in self.families() {
+                    if let Some(font) = self.get(&family, Weight::NORMAL.0, false) {
+                        m.insert(family, font.as_ref().clone());
+                    }
                 }
+                Arc::new(m)
+            })
+            .clone()
+    }
+    /// Gets the font which matches the family, weight and style best.
+    pub fn get(&self, family: &str, weight: u16, italic: bool) -> Option<Arc<Font>> {
+        let style = if italic { Style::Italic } else { Style::Normal };
+        let id = self.db.query(&Query {
+            families: &[Family::Name(family)],
+            weight: Weight(weight),
+            style,
+            ..Default::default()
+        })?;
+        self.fonts.get(&id).cloned()
+    }
+}
+
+static FONT_REGISTRY: Lazy<ArcSwap<FontRegistry>> = Lazy::new(|| {
+    let registry = FontRegistry::default()
+        .with_font(Source::Binary(Arc::new(DEFAULT_FONT_DATA)))
+        .map(|(registry, _)| registry)
+        .unwrap_or_default();
+    ArcSwap::from_pointee(registry)
+});
+// the registry is copied on write, so the writes should be serial
+static FONT_REGISTRY_WRITE: Mutex<()> = Mutex::new(());
+
+/// Adds font at runtime, the families of font are returned.
+/// It returns an error if the family with the same weight and style exists.
+pub fn add_font(data: &[u8]) -> Result<Vec<String>> {
+    let _guard = FONT_REGISTRY_WRITE
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let (registry, families) = FONT_REGISTRY
+        .load()
+        .with_font(Source::Binary(Arc::new(data.to_vec())))?;
+    FONT_REGISTRY.store(Arc::new(registry));
+    Ok(families)
+}
+
+/// Removes all faces of the font family at runtime,
+/// the default font can not be removed.
+pub fn remove_font(family: &str) -> Result<()> {
+    let _guard = FONT_REGISTRY_WRITE
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let registry = FONT_REGISTRY.load().without_family(family)?;
+    FONT_REGISTRY.store(Arc::new(registry));
+    Ok(())
+}
+
+/// Gets the font registry, the fonts are added if they are not loaded.
+/// Use `add_font` to get the error of duplicate font.
+pub fn get_or_try_init_font_registry(fonts: Option<Vec<&[u8]>>) -> Result<Arc<FontRegistry>> {
+    for data in fonts.unwrap_or_default().into_iter() {
+        match add_font(data) {
+            Err(Error::FontExists { .. }) => {}
+            result => {
+                result?;
             }
         }
-        #[cfg(feature = "image-encoder")]
-        crate::get_or_init_fontdb(Some(font_datas));
-        Ok(m)
-    })
+    }
+    Ok(FONT_REGISTRY.load_full())
 }
-/// Gets font by font family.
-pub fn get_font(name: &str) -> Result<&Font> {
-    let fonts = get_or_try_init_fonts(None)?;
-    if let Some(font) = fonts.get(name).or_else(|| fonts.get(DEFAULT_FONT_FAMILY)) {
+
+/// Gets the regular font of each family, the fonts are added if they are not loaded.
+/// It's a wrapper of `get_or_try_init_font_registry`, the map is shared by
+/// the registry and it is released after the fonts of registry are changed.
+pub fn get_or_try_init_fonts(fonts: Option<Vec<&[u8]>>) -> Result<Arc<HashMap<String, Font>>> {
+    let registry = get_or_try_init_font_registry(fonts)?;
+    Ok(registry.get_regular_fonts())
+}
+
+/// Loads the system fonts at runtime, the families of new fonts are returned.
+/// The faces which exist or can not be parsed are skipped.
+#[cfg(feature = "system-fonts")]
+pub fn load_system_fonts() -> Vec<String> {
+    let _guard = FONT_REGISTRY_WRITE
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let (registry, families) = FONT_REGISTRY.load().with_system_fonts();
+    FONT_REGISTRY.store(Arc::new(registry));
+    families
+}
+
+/// Gets the font database of pdf encoder, it's the same as the font registry.
+#[cfg(feature = "pdf-encoder")]
+pub(crate) fn get_or_init_fontdb() -> Arc<Database> {
+    FONT_REGISTRY.load().database()
+}
+
+/// Gets the font database of image encoder, the system fonts are appended
+/// to the fonts of registry as the fallback of rendering.
+#[cfg(feature = "image-encoder")]
+pub(crate) fn get_or_init_image_fontdb() -> Arc<Database> {
+    static SYSTEM_FONTDB: Lazy<Database> = Lazy::new(|| {
+        let mut db = Database::new();
+        db.load_system_fonts();
+        db
+    });
+    type ImageFontdb = (Arc<FontRegistry>, Arc<Database>);
+    static IMAGE_FONTDB: Mutex<Option<ImageFontdb>> = Mutex::new(None);
+    let registry = FONT_REGISTRY.load_full();
+    let mut image_fontdb = IMAGE_FONTDB.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some((current, db)) = image_fontdb.as_ref() {
+        if Arc::ptr_eq(current, &registry) {
+            return db.clone();
+        }
+    }
+    let mut db = (*registry.database()).clone();
+    for face in SYSTEM_FONTDB.faces() {
+        db.push_face_info(face.clone());
+    }
+    let db = Arc::new(db);
+    *image_fontdb = Some((registry, db.clone()));
+    db
+}
+
+/// Splits the font family list, e.g. "Roboto, 'Noto Sans CJK'".
+pub(crate) fn get_font_family_list(font_family: &str) -> Vec<String> {
+    font_family
+        .split(',')
+        .map(|item| item.trim().trim_matches(|c| c == '"' || c == '\'').trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.to_string())
+        .collect()
+}
+
+// the generic font families of css
+static GENERIC_FONT_FAMILIES: [&str; 13] = [
+    "serif",
+    "sans-serif",
+    "monospace",
+    "cursive",
+    "fantasy",
+    "system-ui",
+    "ui-serif",
+    "ui-sans-serif",
+    "ui-monospace",
+    "ui-rounded",
+    "emoji",
+    "math",
+    "fangsong",
+];
+
+/// Formats the font family list of svg, the default font family is
+/// appended as the last fallback, which is the same as measurement.
+/// It is not appended if the list contains it or a generic font family.
+pub(crate) fn format_font_family(font_family: &str) -> String {
+    let mut families = get_font_family_list(font_family);
+    if !families.iter().any(|item| {
+        item.eq_ignore_ascii_case(DEFAULT_FONT_FAMILY)
+            || GENERIC_FONT_FAMILIES
+                .iter()
+                .any(|generic| item.eq_ignore_ascii_case(generic))
+    }) {
+        families.push(DEFAULT_FONT_FAMILY.to_string());
+    }
+    families
+        .iter()
+        .map(|item| {
+            if item.contains(' ') {
+                format!("'{item}'")
+            } else {
+                item.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Gets the fonts of font family list, e.g. "Roboto, Noto Sans CJK",
+/// the default font is appended as the last fallback.
+pub fn get_fonts(font_family: &str, font_weight: &str, italic: bool) -> Result<Vec<Arc<Font>>> {
+    let registry = FONT_REGISTRY.load();
+    let weight = parse_font_weight(font_weight);
+    let mut fonts: Vec<Arc<Font>> = vec![];
+    let mut families = get_font_family_list(font_family);
+    families.push(DEFAULT_FONT_FAMILY.to_string());
+    for family in families.iter() {
+        if let Some(font) = registry.get(family, weight, italic) {
+            if !fonts.iter().any(|item| Arc::ptr_eq(item, &font)) {
+                fonts.push(font);
+            }
+        }
+    }
+    if fonts.is_empty() {
+        return FontNotFoundSnafu {
+            name: font_family.to_string(),
+        }
+        .fail();
+    }
+    Ok(fonts)
+}
+
+/// Gets font by font family and font weight, the default font will be
+/// returned if the font family is not found.
+pub fn get_font_with_weight(name: &str, font_weight: &str, italic: bool) -> Result<Arc<Font>> {
+    let fonts = FONT_REGISTRY.load();
+    let weight = parse_font_weight(font_weight);
+    if let Some(font) = fonts
+        .get(name, weight, italic)
+        .or_else(|| fonts.get(DEFAULT_FONT_FAMILY, weight, italic))
+    {
         Ok(font)
     } else {
         FontNotFoundSnafu {
@@ -81,20 +439,67 @@ pub fn get_font(name: &str) -> Result<&Font> {
         .fail()
     }
 }
+/// Gets font by font family, the font is kept alive after it is removed,
+/// use `get_font_arc` if the fonts are changed at runtime.
+pub fn get_font(name: &str) -> Result<&Font> {
+    // the fonts which have been returned, each font is kept once
+    static FONT_REFS: Mutex<Vec<&'static Arc<Font>>> = Mutex::new(Vec::new());
+    let font = get_font_arc(name)?;
+    let mut font_refs = FONT_REFS.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(item) = font_refs.iter().find(|item| Arc::ptr_eq(item, &font)) {
+        return Ok(item);
+    }
+    let item: &'static Arc<Font> = std::boxed::Box::leak(std::boxed::Box::new(font));
+    font_refs.push(item);
+    Ok(item)
+}
+/// Gets font by font family, the default font will be returned
+/// if the font family is not found.
+pub fn get_font_arc(name: &str) -> Result<Arc<Font>> {
+    get_font_with_weight(name, "", false)
+}
 /// Gets all supported font family
 pub fn get_font_families() -> Result<Vec<String>> {
-    let fonts = get_or_try_init_fonts(None)?;
-    let mut families = vec![];
-    for (name, _) in fonts.iter() {
-        families.push(name.to_string());
-    }
-    Ok(families)
+    Ok(FONT_REGISTRY.load().families())
 }
 
 /// Measures the display area of text of a specified font size.
 pub fn measure_text(font: &Font, font_size: f32, text: &str) -> Box {
+    measure_text_fonts(&[font], font_size, text)
+}
+
+/// Measures the display area of text with font fallback, each char uses
+/// the first font which contains its glyph.
+pub fn measure_text_fonts<F: Borrow<Font>>(fonts: &[F], font_size: f32, text: &str) -> Box {
     let mut layout = Layout::new(CoordinateSystem::PositiveYDown);
-    layout.append(&[font], &TextStyle::new(text, font_size, 0));
+    if fonts.len() <= 1 {
+        layout.append(fonts, &TextStyle::new(text, font_size, 0));
+    } else {
+        let get_font_index = |c: char| {
+            fonts
+                .iter()
+                .position(|font| font.borrow().lookup_glyph_index(c) != 0)
+                .unwrap_or_default()
+        };
+        let mut start = 0;
+        let mut current = None;
+        for (index, c) in text.char_indices() {
+            let font_index = get_font_index(c);
+            if let Some(value) = current {
+                if value != font_index {
+                    layout.append(
+                        fonts,
+                        &TextStyle::new(&text[start..index], font_size, value),
+                    );
+                    start = index;
+                }
+            }
+            current = Some(font_index);
+        }
+        if let Some(value) = current {
+            layout.append(fonts, &TextStyle::new(&text[start..], font_size, value));
+        }
+    }
 
     let mut right = 0.0_f32;
     let mut bottom = 0.0_f32;
@@ -117,8 +522,19 @@ pub fn measure_text(font: &Font, font_size: f32, text: &str) -> Box {
 
 /// Measures the display area of text of a specified font size and font family.
 pub fn measure_text_width_family(font_family: &str, font_size: f32, text: &str) -> Result<Box> {
-    let font = get_font(font_family)?;
-    Ok(measure_text(font, font_size, text))
+    let fonts = get_fonts(font_family, "", false)?;
+    Ok(measure_text_fonts(&fonts, font_size, text))
+}
+
+/// Measures the display area of text of a specified font size, font family and font weight.
+pub fn measure_text_width_family_weight(
+    font_family: &str,
+    font_weight: &str,
+    font_size: f32,
+    text: &str,
+) -> Result<Box> {
+    let fonts = get_fonts(font_family, font_weight, false)?;
+    Ok(measure_text_fonts(&fonts, font_size, text))
 }
 
 /// Gets the max width of multi text.
@@ -127,10 +543,10 @@ pub fn measure_max_text_width_family(
     font_size: f32,
     texts: Vec<&str>,
 ) -> Result<Box> {
-    let font = get_font(font_family)?;
+    let fonts = get_fonts(font_family, "", false)?;
     let mut result = Box::default();
     for item in texts.iter() {
-        let b = measure_text(font, font_size, item);
+        let b = measure_text_fonts(&fonts, font_size, item);
         if b.width() > result.width() {
             result = b;
         }
@@ -145,8 +561,8 @@ pub fn text_wrap_fit(
     text: &str,
     width: f32,
 ) -> Result<Vec<String>> {
-    let font = get_font(font_family)?;
-    let b = measure_text(font, font_size, text);
+    let fonts = get_fonts(font_family, "", false)?;
+    let b = measure_text_fonts(&fonts, font_size, text);
     if b.width() <= width {
         return Ok(vec![text.to_string()]);
     }
@@ -155,7 +571,7 @@ pub fn text_wrap_fit(
     let mut result = vec![];
     for item in text.chars() {
         let new_str = current.clone() + &item.to_string();
-        let b = measure_text(font, font_size, &new_str);
+        let b = measure_text_fonts(&fonts, font_size, &new_str);
         if b.width() > width {
             result.push(current);
             current = item.to_string();
@@ -171,7 +587,11 @@ pub fn text_wrap_fit(
 
 #[cfg(test)]
 mod tests {
-    use super::{get_font, get_font_families, measure_text_width_family, text_wrap_fit};
+    use super::{
+        format_font_family, get_font, get_font_arc, get_font_families, get_font_family_list,
+        get_font_with_weight, get_fonts, get_or_try_init_font_registry, measure_text_width_family,
+        parse_font_weight, text_wrap_fit,
+    };
     use pretty_assertions::assert_eq;
     #[test]
     fn measure_text() {
@@ -203,4 +623,63 @@ mod tests {
             result
         );
     }
+    #[test]
+    fn font_weight() {
+        assert_eq!(400, parse_font_weight(""));
+        assert_eq!(400, parse_font_weight("normal"));
+        assert_eq!(700, parse_font_weight("bold"));
+        assert_eq!(300, parse_font_weight("lighter"));
+        assert_eq!(500, parse_font_weight("500"));
+        assert_eq!(1000, parse_font_weight("1200"));
+        assert_eq!(400, parse_font_weight("unknown"));
+
+        let registry = get_or_try_init_font_registry(None).unwrap();
+        assert_eq!(true, registry.get("Roboto", 700, false).is_some());
+        assert_eq!(true, registry.get("Unknown", 400, false).is_none());
+
+        // only regular weight of roboto is registered, so bold uses it
+        let font = get_font_with_weight("Roboto", "bold", false).unwrap();
+        assert_eq!(get_font("Roboto").unwrap().file_hash(), font.file_hash());
+        // the font reference is kept once
+        assert_eq!(
+            true,
+            std::ptr::eq(get_font("Roboto").unwrap(), get_font("Roboto").unwrap())
+        );
+        assert_eq!(
+            get_font_arc("Roboto").unwrap().file_hash(),
+            font.file_hash()
+        );
+        // fallback to the default font
+        let font = get_font_with_weight("Unknown", "bold", true).unwrap();
+        assert_eq!(get_font("Roboto").unwrap().file_hash(), font.file_hash());
+    }
+    #[test]
+    fn font_family_list() {
+        assert_eq!(
+            vec!["Roboto", "Noto Sans CJK", "Noto Color Emoji"],
+            get_font_family_list(r#"Roboto, 'Noto Sans CJK',"Noto Color Emoji","#)
+        );
+        assert_eq!("Roboto", format_font_family("Roboto"));
+        assert_eq!(
+            "Roboto, 'Noto Sans CJK'",
+            format_font_family("Roboto,Noto Sans CJK")
+        );
+        assert_eq!(
+            "'Noto Sans CJK', Roboto",
+            format_font_family("Noto Sans CJK")
+        );
+        assert_eq!(
+            "'Noto Sans CJK', roboto",
+            format_font_family("Noto Sans CJK, roboto")
+        );
+        assert_eq!(
+            "'Noto Sans CJK', sans-serif",
+            format_font_family("'Noto Sans CJK', sans-serif")
+        );
+        assert_eq!("monospace", format_font_family("monospace"));
+
+        // the unknown families are ignored and the default font is used
+        assert_eq!(1, get_fonts("Unknown, Roboto", "", false).unwrap().len());
+        assert_eq!(1, get_fonts("", "", false).unwrap().len());
+    }
 }
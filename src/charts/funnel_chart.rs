@@ -0,0 +1,383 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::canvas;
+use super::color::*;
+use super::common::*;
+use super::component::*;
+use super::params::*;
+use super::theme::{get_default_theme_name, get_theme, Theme, DEFAULT_Y_AXIS_WIDTH};
+use super::util::*;
+use super::Canvas;
+use crate::charts::measure_text_width_family;
+use charts_rs_derive::Chart;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The sort of funnel stages.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum FunnelSort {
+    // the largest stage is on the top
+    #[default]
+    Descending,
+    // the smallest stage is on the top, like a pyramid
+    Ascending,
+    // keep the order of series
+    None,
+}
+
+impl From<&str> for FunnelSort {
+    fn from(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "ascending" => FunnelSort::Ascending,
+            "none" => FunnelSort::None,
+            _ => FunnelSort::Descending,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Chart)]
+pub struct FunnelChart {
+    pub width: f32,
+    pub height: f32,
+    pub x: f32,
+    pub y: f32,
+    pub margin: Box,
+    // each series is a stage of funnel
+    pub series_list: Vec<Series>,
+    pub font_family: String,
+    pub background_color: Color,
+    pub is_light: bool,
+
+    // title
+    pub title_text: String,
+    pub title_font_size: f32,
+    pub title_font_color: Color,
+    pub title_font_weight: Option<String>,
+    pub title_margin: Option<Box>,
+    pub title_align: Align,
+    pub title_height: f32,
+
+    // sub title
+    pub sub_title_text: String,
+    pub sub_title_font_size: f32,
+    pub sub_title_font_color: Color,
+    pub sub_title_font_weight: Option<String>,
+    pub sub_title_margin: Option<Box>,
+    pub sub_title_align: Align,
+    pub sub_title_height: f32,
+
+    // legend
+    pub legend_font_size: f32,
+    pub legend_font_color: Color,
+    pub legend_font_weight: Option<String>,
+    pub legend_align: Align,
+    pub legend_margin: Option<Box>,
+    pub legend_category: LegendCategory,
+    pub legend_show: Option<bool>,
+
+    // the sort of stages
+    pub sort: FunnelSort,
+    // the gap between stages
+    pub gap: f32,
+
+    // x axis
+    pub x_axis_data: Vec<String>,
+    pub x_axis_height: f32,
+    pub x_axis_stroke_color: Color,
+    pub x_axis_font_size: f32,
+    pub x_axis_font_color: Color,
+    pub x_axis_font_weight: Option<String>,
+    pub x_axis_name_gap: f32,
+    pub x_axis_name_rotate: f32,
+    pub x_axis_margin: Option<Box>,
+    pub x_boundary_gap: Option<bool>,
+
+    // y axis
+    pub y_axis_configs: Vec<YAxisConfig>,
+
+    // grid
+    pub grid_stroke_color: Color,
+    pub grid_stroke_width: f32,
+
+    // series
+    pub series_stroke_width: f32,
+    pub series_label_font_color: Color,
+    pub series_label_font_size: f32,
+    pub series_label_font_weight: Option<String>,
+    pub series_label_formatter: String,
+    pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
+    pub series_symbol: Option<Symbol>,
+    pub series_smooth: bool,
+    pub series_fill: bool,
+    // tooltip of the value and percentage of each stage
+    pub interactive: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the value of each stage, it implies accessible
+    pub accessible_table: Option<bool>,
+}
+
+impl FunnelChart {
+    fn fill_default(&mut self) {
+        self.gap = 2.0;
+        self.legend_category = LegendCategory::Rect;
+    }
+    /// Creates a funnel chart from json.
+    pub fn from_json(data: &str) -> canvas::Result<FunnelChart> {
+        let mut f = FunnelChart {
+            ..Default::default()
+        };
+        f.fill_default();
+        let value = f.fill_option(data)?;
+        if let Some(sort) = get_string_from_value(&value, "sort") {
+            f.sort = sort.as_str().into();
+        }
+        if let Some(gap) = get_f32_from_value(&value, "gap") {
+            f.gap = gap;
+        }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            f.interactive = Some(interactive);
+        }
+        Ok(f)
+    }
+    /// Creates a funnel chart with custom theme.
+    pub fn new_with_theme(mut series_list: Vec<Series>, theme: &str) -> FunnelChart {
+        // set the index of series, keep the color after sorting
+        series_list
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, item)| {
+                item.index = Some(index);
+            });
+        let mut f = FunnelChart {
+            series_list,
+            ..Default::default()
+        };
+        f.fill_default();
+        f.fill_theme(get_theme(theme));
+        f
+    }
+    /// Creates a funnel chart with default theme.
+    pub fn new(series_list: Vec<Series>) -> FunnelChart {
+        FunnelChart::new_with_theme(series_list, &get_default_theme_name())
+    }
+    /// Converts funnel chart to svg.
+    pub fn svg(&self) -> canvas::Result<String> {
+        let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
+
+        self.render_background(c.child(Box::default()));
+        c.margin = self.margin.clone();
+
+        let title_height = self.render_title(c.child(Box::default()));
+
+        let legend_height = self.render_legend(c.child(Box::default()));
+        // get the max height of title and legend
+        let axis_top = if legend_height > title_height {
+            legend_height
+        } else {
+            title_height
+        };
+        if axis_top > 0.0 {
+            c = c.child(Box {
+                top: axis_top,
+                ..Default::default()
+            });
+        }
+        if self.series_list.is_empty() {
+            return c.svg();
+        }
+
+        let values: Vec<f32> = self
+            .series_list
+            .iter()
+            .map(|item| item.data.iter().sum())
+            .collect();
+        let max = values.iter().fold(0.0_f32, |acc, item| acc.max(*item));
+
+        let mut stages: Vec<usize> = (0..values.len()).collect();
+        match self.sort {
+            FunnelSort::Descending => stages.sort_by(|a, b| values[*b].total_cmp(&values[*a])),
+            FunnelSort::Ascending => stages.sort_by(|a, b| values[*a].total_cmp(&values[*b])),
+            FunnelSort::None => {}
+        }
+        // the percentage is relative to the first stage of funnel,
+        // it's the largest stage if the stages are sorted
+        let first = if self.sort == FunnelSort::None {
+            values[stages[0]]
+        } else {
+            max
+        };
+
+        let mut series_label_formatter = self.series_label_formatter.clone();
+        if series_label_formatter.is_empty() {
+            series_label_formatter = "{a}: {d}".to_string();
+        }
+        let funnel_width = c.width() * 0.8;
+        let left = (c.width() - funnel_width) / 2.0;
+        let count = stages.len() as f32;
+        let stage_height = ((c.height() - self.gap * (count - 1.0)) / count).max(1.0);
+        let get_width = |value: f32| -> f32 {
+            if max <= 0.0 {
+                return 0.0;
+            }
+            funnel_width * value / max
+        };
+        let is_ascending = self.sort == FunnelSort::Ascending;
+
+        for (i, index) in stages.iter().enumerate() {
+            let series = &self.series_list[*index];
+            let value = values[*index];
+            let color = get_color(&self.series_colors, series.index.unwrap_or(*index));
+            // the trapezoid narrows to the next stage,
+            // or widens from the previous stage for ascending
+            let (top_width, bottom_width) = if is_ascending {
+                let prev = if i == 0 {
+                    0.0
+                } else {
+                    get_width(values[stages[i - 1]])
+                };
+                (prev, get_width(value))
+            } else {
+                let next = if let Some(next) = stages.get(i + 1) {
+                    get_width(values[*next])
+                } else {
+                    0.0
+                };
+                (get_width(value), next)
+            };
+            let top = (stage_height + self.gap) * i as f32;
+            let bottom = top + stage_height;
+            let cx = left + funnel_width / 2.0;
+            let polygon = Polygon {
+                fill: Some(color),
+                paint: get_series_paint(
+                    series,
+                    series.index.unwrap_or(*index),
+                    &self.series_patterns,
+                    color,
+                ),
+                points: vec![
+                    (cx - top_width / 2.0, top).into(),
+                    (cx + top_width / 2.0, top).into(),
+                    (cx + bottom_width / 2.0, bottom).into(),
+                    (cx - bottom_width / 2.0, bottom).into(),
+                ],
+                ..Default::default()
+            };
+            let percentage = if first != 0.0 { value / first } else { 0.0 };
+            let category_name = self.x_axis_data.get(*index).cloned().unwrap_or_default();
+            if c.interactive {
+                let text = LabelOption {
+                    value,
+                    percentage,
+                    formatter: "{c} ({d})".to_string(),
+                    ..Default::default()
+                }
+                .format();
+                c.interactive_child(Interactive::new_item(
+                    &series.name,
+                    Some(category_name.clone()),
+                    format_float(value),
+                    &text,
+                ))
+                .polygon(polygon);
+            } else {
+                c.polygon(polygon);
+            }
+
+            let label_text = LabelOption {
+                series_name: series.name.clone(),
+                category_name,
+                value,
+                percentage,
+                formatter: series_label_formatter.clone(),
+            }
+            .format();
+            let label_width = if let Ok(b) = measure_text_width_family(
+                &self.font_family,
+                self.series_label_font_size,
+                &label_text,
+            ) {
+                b.width()
+            } else {
+                0.0
+            };
+            c.child(Box {
+                left: cx - label_width / 2.0,
+                top: top + (stage_height + self.series_label_font_size) / 2.0 - 2.0,
+                ..Default::default()
+            })
+            .text(Text {
+                text: label_text,
+                font_family: Some(self.font_family.clone()),
+                font_size: Some(self.series_label_font_size),
+                font_color: Some(self.series_label_font_color),
+                font_weight: self.series_label_font_weight.clone(),
+                ..Default::default()
+            });
+        }
+
+        c.svg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FunnelChart, FunnelSort};
+    use crate::Align;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn funnel_basic() {
+        let mut funnel_chart = FunnelChart::new(vec![
+            ("Visit", vec![120.0]).into(),
+            ("Order", vec![20.0]).into(),
+            ("Inquiry", vec![60.0]).into(),
+            ("Click", vec![80.0]).into(),
+            ("Show", vec![100.0]).into(),
+        ]);
+        funnel_chart.title_text = "Funnel".to_string();
+        funnel_chart.title_align = Align::Left;
+        funnel_chart.legend_align = Align::Right;
+        funnel_chart.series_label_formatter = "{a} {c} ({d})".to_string();
+        assert_eq!(
+            include_str!("../../asset/funnel_chart/basic.svg"),
+            funnel_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn funnel_ascending() {
+        let mut funnel_chart = FunnelChart::new_with_theme(
+            vec![
+                ("Visit", vec![120.0]).into(),
+                ("Show", vec![100.0]).into(),
+                ("Click", vec![80.0]).into(),
+                ("Inquiry", vec![60.0]).into(),
+                ("Order", vec![20.0]).into(),
+            ],
+            "dark",
+        );
+        funnel_chart.title_text = "Pyramid".to_string();
+        funnel_chart.title_align = Align::Left;
+        funnel_chart.legend_align = Align::Right;
+        funnel_chart.sort = FunnelSort::Ascending;
+        funnel_chart.gap = 6.0;
+        assert_eq!(
+            include_str!("../../asset/funnel_chart/ascending.svg"),
+            funnel_chart.svg().unwrap()
+        );
+    }
+}
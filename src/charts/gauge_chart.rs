@@ -0,0 +1,513 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::canvas;
+use super::color::*;
+use super::common::*;
+use super::component::*;
+use super::params::*;
+use super::theme::{get_default_theme_name, get_theme, Theme, DEFAULT_Y_AXIS_WIDTH};
+use super::util::*;
+use super::Canvas;
+use crate::charts::measure_text_width_family;
+use charts_rs_derive::Chart;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The threshold band of gauge, it ends at the value.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct GaugeBand {
+    pub value: f32,
+    pub color: Color,
+}
+
+impl From<(f32, Color)> for GaugeBand {
+    fn from(value: (f32, Color)) -> Self {
+        GaugeBand {
+            value: value.0,
+            color: value.1,
+        }
+    }
+}
+
+/// Gets the threshold bands of gauge from serde json.
+fn get_gauge_bands_from_value(value: &serde_json::Value, key: &str) -> Option<Vec<GaugeBand>> {
+    let bands = value.get(key)?.as_array()?;
+    Some(
+        bands
+            .iter()
+            .map(|item| GaugeBand {
+                value: get_f32_from_value(item, "value").unwrap_or_default(),
+                color: get_color_from_value(item, "color").unwrap_or_default(),
+            })
+            .collect(),
+    )
+}
+
+#[derive(Clone, Debug, Default, Chart)]
+pub struct GaugeChart {
+    pub width: f32,
+    pub height: f32,
+    pub x: f32,
+    pub y: f32,
+    pub margin: Box,
+    // the first value of first series is the value of gauge
+    pub series_list: Vec<Series>,
+    pub font_family: String,
+    pub background_color: Color,
+    pub is_light: bool,
+
+    // title
+    pub title_text: String,
+    pub title_font_size: f32,
+    pub title_font_color: Color,
+    pub title_font_weight: Option<String>,
+    pub title_margin: Option<Box>,
+    pub title_align: Align,
+    pub title_height: f32,
+
+    // sub title
+    pub sub_title_text: String,
+    pub sub_title_font_size: f32,
+    pub sub_title_font_color: Color,
+    pub sub_title_font_weight: Option<String>,
+    pub sub_title_margin: Option<Box>,
+    pub sub_title_align: Align,
+    pub sub_title_height: f32,
+
+    // legend
+    pub legend_font_size: f32,
+    pub legend_font_color: Color,
+    pub legend_font_weight: Option<String>,
+    pub legend_align: Align,
+    pub legend_margin: Option<Box>,
+    pub legend_category: LegendCategory,
+    pub legend_show: Option<bool>,
+
+    // the angle is clockwise from the top, the same as pie
+    pub start_angle: f32,
+    pub end_angle: f32,
+    pub min: f32,
+    pub max: f32,
+    // auto radius if it is 0
+    pub radius: f32,
+    pub band_width: f32,
+    // the threshold bands, the value and progress are shown if it is empty
+    pub bands: Vec<GaugeBand>,
+    // the split number of major ticks
+    pub split_number: usize,
+    // the split number of minor ticks between major ticks
+    pub minor_split_number: usize,
+    pub value_font_size: f32,
+
+    // x axis
+    pub x_axis_data: Vec<String>,
+    pub x_axis_height: f32,
+    pub x_axis_stroke_color: Color,
+    pub x_axis_font_size: f32,
+    pub x_axis_font_color: Color,
+    pub x_axis_font_weight: Option<String>,
+    pub x_axis_name_gap: f32,
+    pub x_axis_name_rotate: f32,
+    pub x_axis_margin: Option<Box>,
+    pub x_boundary_gap: Option<bool>,
+
+    // y axis
+    pub y_axis_configs: Vec<YAxisConfig>,
+
+    // grid
+    pub grid_stroke_color: Color,
+    pub grid_stroke_width: f32,
+
+    // series
+    pub series_stroke_width: f32,
+    pub series_label_font_color: Color,
+    pub series_label_font_size: f32,
+    pub series_label_font_weight: Option<String>,
+    pub series_label_formatter: String,
+    pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
+    pub series_symbol: Option<Symbol>,
+    pub series_smooth: bool,
+    pub series_fill: bool,
+    // tooltip of the value of pointer
+    pub interactive: Option<bool>,
+    // title and desc of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the value, it implies accessible
+    pub accessible_table: Option<bool>,
+}
+
+impl GaugeChart {
+    fn fill_default(&mut self) {
+        self.start_angle = -135.0;
+        self.end_angle = 135.0;
+        self.min = 0.0;
+        self.max = 100.0;
+        self.band_width = 15.0;
+        self.split_number = 10;
+        self.minor_split_number = 5;
+        self.value_font_size = 28.0;
+        self.legend_show = Some(false);
+    }
+    /// Creates a gauge chart from json.
+    pub fn from_json(data: &str) -> canvas::Result<GaugeChart> {
+        let mut g = GaugeChart {
+            ..Default::default()
+        };
+        g.fill_default();
+        let value = g.fill_option(data)?;
+        if let Some(start_angle) = get_f32_from_value(&value, "start_angle") {
+            g.start_angle = start_angle;
+        }
+        if let Some(end_angle) = get_f32_from_value(&value, "end_angle") {
+            g.end_angle = end_angle;
+        }
+        if let Some(min) = get_f32_from_value(&value, "min") {
+            g.min = min;
+        }
+        if let Some(max) = get_f32_from_value(&value, "max") {
+            g.max = max;
+        }
+        if let Some(radius) = get_f32_from_value(&value, "radius") {
+            g.radius = radius;
+        }
+        if let Some(band_width) = get_f32_from_value(&value, "band_width") {
+            g.band_width = band_width;
+        }
+        if let Some(bands) = get_gauge_bands_from_value(&value, "bands") {
+            g.bands = bands;
+        }
+        if let Some(split_number) = get_usize_from_value(&value, "split_number") {
+            g.split_number = split_number;
+        }
+        if let Some(minor_split_number) = get_usize_from_value(&value, "minor_split_number") {
+            g.minor_split_number = minor_split_number;
+        }
+        if let Some(value_font_size) = get_f32_from_value(&value, "value_font_size") {
+            g.value_font_size = value_font_size;
+        }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            g.interactive = Some(interactive);
+        }
+        Ok(g)
+    }
+    /// Creates a gauge chart with custom theme.
+    pub fn new_with_theme(series_list: Vec<Series>, theme: &str) -> GaugeChart {
+        let mut g = GaugeChart {
+            series_list,
+            ..Default::default()
+        };
+        g.fill_default();
+        g.fill_theme(get_theme(theme));
+        g
+    }
+    /// Creates a gauge chart with default theme.
+    pub fn new(series_list: Vec<Series>) -> GaugeChart {
+        GaugeChart::new_with_theme(series_list, &get_default_theme_name())
+    }
+    /// Gets the angle of value, the value is limited to min and max.
+    fn get_angle(&self, value: f32) -> f32 {
+        let range = self.max - self.min;
+        if range <= 0.0 {
+            return self.start_angle;
+        }
+        let percent = ((value - self.min) / range).clamp(0.0, 1.0);
+        self.start_angle + (self.end_angle - self.start_angle) * percent
+    }
+    /// Converts gauge chart to svg.
+    pub fn svg(&self) -> canvas::Result<String> {
+        let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
+
+        self.render_background(c.child(Box::default()));
+        c.margin = self.margin.clone();
+
+        let title_height = self.render_title(c.child(Box::default()));
+
+        let legend_height = self.render_legend(c.child(Box::default()));
+        // get the max height of title and legend
+        let axis_top = if legend_height > title_height {
+            legend_height
+        } else {
+            title_height
+        };
+        if axis_top > 0.0 {
+            c = c.child(Box {
+                top: axis_top,
+                ..Default::default()
+            });
+        }
+
+        let mut r = c.width().min(c.height()) * 0.45;
+        if self.radius > 0.0 && self.radius < r {
+            r = self.radius;
+        }
+        let cx = c.width() / 2.0;
+        let cy = c.height() / 2.0 + r * 0.1;
+        let ir = r - self.band_width;
+
+        let (name, value) = if let Some(series) = self.series_list.first() {
+            (
+                series.name.clone(),
+                series.data.first().copied().unwrap_or(self.min),
+            )
+        } else {
+            ("".to_string(), self.min)
+        };
+        let color = get_color(&self.series_colors, 0);
+        let value_angle = self.get_angle(value);
+        // the gauge sweeps counterclockwise if the end angle is less than the start angle
+        let direction = if self.end_angle < self.start_angle {
+            -1.0
+        } else {
+            1.0
+        };
+        let band_pie = |start_angle: f32, end_angle: f32, fill: Paint| Pie {
+            fill: fill.color(),
+            paint: Some(fill),
+            cx,
+            cy,
+            r,
+            ir,
+            start_angle: start_angle.min(end_angle),
+            delta: (end_angle - start_angle).abs(),
+            border_radius: 0.0,
+            ..Default::default()
+        };
+
+        if self.bands.is_empty() {
+            // the track and progress of value
+            c.pie(band_pie(
+                self.start_angle,
+                self.end_angle,
+                self.grid_stroke_color.into(),
+            ));
+            if (value_angle - self.start_angle) * direction > 0.0 {
+                c.pie(band_pie(self.start_angle, value_angle, color.into()));
+            }
+        } else {
+            let mut bands = self.bands.clone();
+            bands.sort_by(|a, b| a.value.total_cmp(&b.value));
+            let mut prev_angle = self.start_angle;
+            for band in bands.iter() {
+                let angle = self.get_angle(band.value);
+                if (angle - prev_angle) * direction > 0.0 {
+                    c.pie(band_pie(prev_angle, angle, band.color.into()));
+                }
+                prev_angle = angle;
+            }
+            // the rest of gauge
+            if (self.end_angle - prev_angle) * direction > 0.0 {
+                c.pie(band_pie(
+                    prev_angle,
+                    self.end_angle,
+                    self.grid_stroke_color.into(),
+                ));
+            }
+        }
+
+        // ticks and labels
+        let y_axis_config = self.get_y_axis_config(0);
+        let axis_formatter = y_axis_config.axis_formatter.unwrap_or_default();
+        let split_number = self.split_number.max(1);
+        let minor_split_number = self.minor_split_number.max(1);
+        let tick_count = split_number * minor_split_number;
+        let unit = (self.end_angle - self.start_angle) / tick_count as f32;
+        let tick_r = ir - 3.0;
+        for i in 0..=tick_count {
+            let angle = self.start_angle + unit * i as f32;
+            let is_major = i % minor_split_number == 0;
+            let length = if is_major { 10.0 } else { 5.0 };
+            let start = get_pie_point(cx, cy, tick_r, angle);
+            let end = get_pie_point(cx, cy, tick_r - length, angle);
+            c.line(Line {
+                color: Some(self.x_axis_stroke_color),
+                stroke_width: if is_major { 2.0 } else { 1.0 },
+                left: start.x,
+                top: start.y,
+                right: end.x,
+                bottom: end.y,
+                ..Default::default()
+            });
+            if !is_major {
+                continue;
+            }
+            let tick_value = self.min + (self.max - self.min) * i as f32 / tick_count as f32;
+            let text = format_string(&format_float(tick_value), &axis_formatter);
+            let label_width = if let Ok(b) =
+                measure_text_width_family(&self.font_family, y_axis_config.axis_font_size, &text)
+            {
+                b.width()
+            } else {
+                0.0
+            };
+            let point = get_pie_point(
+                cx,
+                cy,
+                tick_r - length - 6.0 - y_axis_config.axis_font_size,
+                angle,
+            );
+            c.child(Box {
+                left: point.x - label_width / 2.0,
+                top: point.y + y_axis_config.axis_font_size / 2.0 - 2.0,
+                ..Default::default()
+            })
+            .text(Text {
+                text,
+                font_family: Some(self.font_family.clone()),
+                font_size: Some(y_axis_config.axis_font_size),
+                font_color: Some(y_axis_config.axis_font_color),
+                font_weight: y_axis_config.axis_font_weight.clone(),
+                ..Default::default()
+            });
+        }
+
+        // pointer
+        let pointer = Polygon {
+            fill: Some(color),
+            points: vec![
+                get_pie_point(cx, cy, ir - 10.0, value_angle),
+                get_pie_point(cx, cy, 5.0, value_angle + 90.0),
+                get_pie_point(cx, cy, 4.0, value_angle + 180.0),
+                get_pie_point(cx, cy, 5.0, value_angle - 90.0),
+            ],
+            ..Default::default()
+        };
+        let percentage = if self.max > self.min {
+            (value - self.min) / (self.max - self.min)
+        } else {
+            0.0
+        };
+        let mut series_label_formatter = self.series_label_formatter.clone();
+        if series_label_formatter.is_empty() {
+            series_label_formatter = "{c}".to_string();
+        }
+        let value_text = LabelOption {
+            series_name: name.clone(),
+            value,
+            percentage,
+            formatter: series_label_formatter,
+            ..Default::default()
+        }
+        .format();
+        let mut pointer_canvas = c.clone();
+        if c.interactive {
+            pointer_canvas = pointer_canvas.interactive_child(Interactive::new_item(
+                &name,
+                None,
+                format_float(value),
+                &value_text,
+            ));
+        }
+        pointer_canvas.polygon(pointer);
+        pointer_canvas.circle(Circle {
+            fill: Some(color),
+            stroke_color: Some(self.background_color),
+            stroke_width: 2.0,
+            cx,
+            cy,
+            r: 7.0,
+        });
+
+        // value and name under the center
+        let mut text_top = cy + r * 0.4;
+        for (text, font_size, font_weight) in [
+            (value_text, self.value_font_size, Some("bold".to_string())),
+            (
+                name,
+                self.series_label_font_size,
+                self.series_label_font_weight.clone(),
+            ),
+        ] {
+            let text_width =
+                if let Ok(b) = measure_text_width_family(&self.font_family, font_size, &text) {
+                    b.width()
+                } else {
+                    0.0
+                };
+            c.child(Box {
+                left: cx - text_width / 2.0,
+                top: text_top,
+                ..Default::default()
+            })
+            .text(Text {
+                text,
+                font_family: Some(self.font_family.clone()),
+                font_size: Some(font_size),
+                font_color: Some(self.series_label_font_color),
+                font_weight,
+                ..Default::default()
+            });
+            text_top += font_size + 4.0;
+        }
+
+        c.svg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GaugeChart;
+    use crate::Color;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn gauge_basic() {
+        let mut gauge_chart = GaugeChart::new(vec![("Availability", vec![72.5]).into()]);
+        gauge_chart.title_text = "SLO".to_string();
+        gauge_chart.series_label_formatter = "{c}%".to_string();
+        assert_eq!(
+            include_str!("../../asset/gauge_chart/basic.svg"),
+            gauge_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn gauge_bands() {
+        let mut gauge_chart =
+            GaugeChart::new_with_theme(vec![("Attainment", vec![99.2]).into()], "dark");
+        gauge_chart.title_text = "SLO Attainment".to_string();
+        gauge_chart.min = 95.0;
+        gauge_chart.max = 100.0;
+        gauge_chart.start_angle = -90.0;
+        gauge_chart.end_angle = 90.0;
+        gauge_chart.split_number = 5;
+        gauge_chart.bands = vec![
+            (99.0, Color::from("#EE6666")).into(),
+            (99.5, Color::from("#FAC858")).into(),
+            (100.0, Color::from("#91CC75")).into(),
+        ];
+        gauge_chart.series_label_formatter = "{c}%".to_string();
+        assert_eq!(
+            include_str!("../../asset/gauge_chart/bands.svg"),
+            gauge_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn gauge_reversed_angle() {
+        let mut gauge_chart = GaugeChart::new(vec![("Availability", vec![72.5]).into()]);
+        gauge_chart.title_text = "SLO".to_string();
+        gauge_chart.start_angle = 135.0;
+        gauge_chart.end_angle = -135.0;
+        gauge_chart.bands = vec![
+            (50.0, Color::from("#EE6666")).into(),
+            (80.0, Color::from("#FAC858")).into(),
+        ];
+        gauge_chart.series_label_formatter = "{c}%".to_string();
+        assert_eq!(
+            include_str!("../../asset/gauge_chart/reversed_angle.svg"),
+            gauge_chart.svg().unwrap()
+        );
+    }
+}
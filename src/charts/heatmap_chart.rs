@@ -13,9 +13,10 @@ use super::util::*;
 use super::Canvas;
 use crate::charts::measure_text_width_family;
 use charts_rs_derive::Chart;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct HeatmapData {
     pub index: usize,
     pub value: f32,
@@ -30,7 +31,7 @@ impl From<(usize, f32)> for HeatmapData {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct HeatmapSeries {
     pub data: Vec<HeatmapData>,
     pub min: f32,
@@ -39,10 +40,19 @@ pub struct HeatmapSeries {
     pub max_color: Color,
     pub min_font_color: Color,
     pub max_font_color: Color,
+    // the color scale is used instead of min and max color if it is set,
+    // the min and max of series are used if the domain of scale is empty
+    pub color_scale: Option<ColorScale>,
 }
 
 impl HeatmapSeries {
     fn get_color(&self, value: f32) -> Color {
+        if let Some(scale) = &self.color_scale {
+            if scale.is_domain_empty() {
+                return scale.get_color_with_domain(value, self.min, self.max);
+            }
+            return scale.get_color(value);
+        }
         if value < self.min {
             return self.min_color;
         }
@@ -68,7 +78,66 @@ impl HeatmapSeries {
     }
 }
 
-#[derive(Clone, Debug, Default, Chart)]
+/// Gets the weekday of the days since 1970-01-01, 0 is sunday.
+fn get_weekday(days: i64) -> usize {
+    // 1970-01-01 is thursday
+    (days + 4).rem_euclid(7) as usize
+}
+
+/// Parses the date of format YYYY-MM-DD to the days since 1970-01-01.
+fn parse_date(value: &str) -> Option<i64> {
+    let arr: Vec<&str> = value.trim().split('-').collect();
+    if arr.len() != 3 {
+        return None;
+    }
+    let year = arr[0].parse::<i64>().ok()?;
+    let month = arr[1].parse::<i64>().ok()?;
+    let day = arr[2].parse::<i64>().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    // the day is out of month, e.g. 2023-02-30
+    if civil_from_days(days) != (year, month, day) {
+        return None;
+    }
+    Some(days)
+}
+
+/// The calendar layout of heatmap, each cell is a day
+/// and the index of heatmap data is the offset days from start.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct HeatmapCalendar {
+    // the first day, format: YYYY-MM-DD
+    pub start: String,
+    // the last day, format: YYYY-MM-DD
+    pub end: String,
+    // the gap between day cells
+    pub cell_gap: f32,
+}
+
+impl HeatmapCalendar {
+    /// Creates a calendar of the date range.
+    pub fn new(start: &str, end: &str) -> HeatmapCalendar {
+        HeatmapCalendar {
+            start: start.to_string(),
+            end: end.to_string(),
+            cell_gap: 2.0,
+        }
+    }
+    /// Gets the index of date, it is none if the date is invalid or out of range.
+    pub fn get_index(&self, date: &str) -> Option<usize> {
+        let start = parse_date(&self.start)?;
+        let end = parse_date(&self.end)?;
+        let days = parse_date(date)?;
+        if days < start || days > end {
+            return None;
+        }
+        Some((days - start) as usize)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Chart)]
 pub struct HeatmapChart {
     pub width: f32,
     pub height: f32,
@@ -78,6 +147,10 @@ pub struct HeatmapChart {
     // no use, but for derive chart
     series_list: Vec<Series>,
     pub series: HeatmapSeries,
+    // calendar layout, the cells are days of the range
+    pub calendar: Option<HeatmapCalendar>,
+    // visual map of the colors of series
+    pub visual_map: Option<VisualMapOption>,
     pub font_family: String,
     pub background_color: Color,
     pub is_light: bool,
@@ -138,9 +211,14 @@ pub struct HeatmapChart {
     pub series_label_font_weight: Option<String>,
     pub series_label_formatter: String,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
+    // title and desc of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the value of each cell, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl HeatmapChart {
@@ -162,6 +240,17 @@ impl HeatmapChart {
         if self.series.max_font_color.is_zero() {
             self.series.max_font_color = (238, 238, 238).into();
         }
+        if self.calendar.is_some() {
+            if self.x_axis_data.is_empty() {
+                self.x_axis_data = MONTH_NAMES.iter().map(|item| item.to_string()).collect();
+            }
+            if self.y_axis_data.is_empty() {
+                self.y_axis_data = ["", "Mon", "", "Wed", "", "Fri", ""]
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect();
+            }
+        }
         if self.series.max == 0.0 {
             let mut max = 0.0;
             for item in self.series.data.iter() {
@@ -181,6 +270,19 @@ impl HeatmapChart {
         if let Some(y_axis_data) = get_string_slice_from_value(&value, "y_axis_data") {
             h.y_axis_data = y_axis_data;
         }
+        if let Some(visual_map) = get_visual_map_option_from_value(&value, "visual_map") {
+            h.visual_map = Some(visual_map);
+        }
+        if let Some(value) = value.get("calendar") {
+            let mut calendar = HeatmapCalendar::new(
+                &get_string_from_value(value, "start").unwrap_or_default(),
+                &get_string_from_value(value, "end").unwrap_or_default(),
+            );
+            if let Some(cell_gap) = get_f32_from_value(value, "cell_gap") {
+                calendar.cell_gap = cell_gap;
+            }
+            h.calendar = Some(calendar);
+        }
         if let Some(value) = value.get("series") {
             if let Some(min) = get_f32_from_value(value, "min") {
                 h.series.min = min;
@@ -200,6 +302,9 @@ impl HeatmapChart {
             if let Some(max_font_color) = get_color_from_value(value, "max_font_color") {
                 h.series.max_font_color = max_font_color;
             }
+            if let Some(color_scale) = get_color_scale_from_value(value, "color_scale") {
+                h.series.color_scale = Some(color_scale);
+            }
             if let Some(data) = value.get("data") {
                 let mut values = vec![];
                 if let Some(arr) = data.as_array() {
@@ -208,8 +313,21 @@ impl HeatmapChart {
                             if arr.len() != 2 {
                                 continue;
                             }
+                            // the date of calendar is converted to index
+                            let index = if let Some(date) = arr[0].as_str() {
+                                let Some(index) = h
+                                    .calendar
+                                    .as_ref()
+                                    .and_then(|calendar| calendar.get_index(date))
+                                else {
+                                    continue;
+                                };
+                                index
+                            } else {
+                                arr[0].as_i64().unwrap_or_default() as usize
+                            };
                             values.push(HeatmapData {
-                                index: arr[0].as_i64().unwrap_or_default() as usize,
+                                index,
                                 value: arr[1].as_f64().unwrap_or_default() as f32,
                             });
                         }
@@ -266,9 +384,311 @@ impl HeatmapChart {
         h.fill_default();
         h
     }
+    /// Creates a calendar heatmap chart with default theme,
+    /// the dates of data are YYYY-MM-DD in the range of start and end.
+    pub fn new_calendar(series_data: Vec<(&str, f32)>, start: &str, end: &str) -> HeatmapChart {
+        HeatmapChart::new_calendar_with_theme(series_data, start, end, &get_default_theme_name())
+    }
+    /// Creates a calendar heatmap chart with custom theme.
+    pub fn new_calendar_with_theme(
+        series_data: Vec<(&str, f32)>,
+        start: &str,
+        end: &str,
+        theme: &str,
+    ) -> HeatmapChart {
+        let calendar = HeatmapCalendar::new(start, end);
+        let mut h = HeatmapChart {
+            ..Default::default()
+        };
+        // the date out of range is ignored
+        h.series.data = series_data
+            .iter()
+            .filter_map(|(date, value)| {
+                calendar.get_index(date).map(|index| (index, *value).into())
+            })
+            .collect();
+        h.calendar = Some(calendar);
+        h.fill_theme(get_theme(theme));
+        h.fill_default();
+        h
+    }
+    /// Renders the visual map of series at the side of canvas.
+    fn render_visual_map(&self, c: &mut Canvas) -> canvas::Result<()> {
+        let Some(option) = &self.visual_map else {
+            return Ok(());
+        };
+        let mut scale = self.series.color_scale.clone().unwrap_or_else(|| {
+            ColorScale::new(vec![self.series.min_color, self.series.max_color], 0.0, 0.0)
+        });
+        if scale.is_domain_empty() {
+            scale.min = self.series.min;
+            scale.max = self.series.max;
+        }
+        // the gradient is sampled from the colors of cells,
+        // they are interpolated in rgb space if the color scale is none
+        let gradient_colors = if self.series.color_scale.is_none() {
+            (0..=VISUAL_MAP_GRADIENT_STOPS)
+                .map(|index| {
+                    let percent = index as f32 / VISUAL_MAP_GRADIENT_STOPS as f32;
+                    self.series
+                        .get_color(scale.min + (scale.max - scale.min) * percent)
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        render_visual_map(
+            c,
+            option,
+            VisualMap {
+                scale,
+                font_family: self.font_family.clone(),
+                font_size: self.x_axis_font_size,
+                font_color: Some(self.x_axis_font_color),
+                font_weight: self.x_axis_font_weight.clone(),
+                formatter: self.y_axis_configs[0]
+                    .axis_formatter
+                    .clone()
+                    .unwrap_or_default(),
+                gradient_colors,
+                ..Default::default()
+            },
+        )
+    }
+    /// Gets the color of cell without value.
+    fn get_blank_color(&self) -> Color {
+        let mut color = self.background_color;
+        let offset = 20;
+        if color.is_light() {
+            color.r -= offset;
+            color.g -= offset;
+            color.b -= offset;
+        } else {
+            color.r += offset;
+            color.g += offset;
+            color.b += offset;
+        }
+        color
+    }
+    /// Gets the accessible metadata of calendar heatmap chart,
+    /// each month is a row of data table.
+    fn get_calendar_accessibility(&self, start: i64, end: i64) -> Option<Accessibility> {
+        let mut data = vec![NIL_VALUE; (end - start + 1) as usize];
+        for item in self.series.data.iter() {
+            if item.index < data.len() {
+                data[item.index] = item.value;
+            }
+        }
+        let mut series_list: Vec<Series> = vec![];
+        for (offset, value) in data.iter().enumerate() {
+            let (year, month, day) = civil_from_days(start + offset as i64);
+            if offset == 0 || day == 1 {
+                series_list.push((format!("{year}-{month:02}").as_str(), vec![]).into());
+            }
+            if let Some(series) = series_list.last_mut() {
+                series.data.push(*value);
+            }
+        }
+        get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        )
+    }
+    /// Converts calendar heatmap chart to svg,
+    /// the weeks are columns and the weekdays are rows.
+    fn calendar_svg(&self, calendar: &HeatmapCalendar) -> canvas::Result<String> {
+        let (Some(start), Some(end)) = (parse_date(&calendar.start), parse_date(&calendar.end))
+        else {
+            return Err(canvas::Error::Params {
+                message: "calendar start or end is invalid".to_string(),
+            });
+        };
+        if end < start {
+            return Err(canvas::Error::Params {
+                message: "calendar end can not be before start".to_string(),
+            });
+        }
+        let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.accessibility = self.get_calendar_accessibility(start, end);
+
+        self.render_background(c.child(Box::default()));
+        c.margin = self.margin.clone();
+
+        let title_height = self.render_title(c.child(Box::default()));
+
+        let legend_height = self.render_legend(c.child(Box::default()));
+        // get the max height of title and legend
+        let axis_top = if legend_height > title_height {
+            legend_height
+        } else {
+            title_height
+        };
+        if axis_top > 0.0 {
+            c = c.child(Box {
+                top: axis_top,
+                ..Default::default()
+            });
+        }
+        self.render_visual_map(&mut c)?;
+
+        let label_gap = 5.0;
+        let month_height = if self.x_axis_hidden {
+            0.0
+        } else {
+            self.x_axis_font_size + label_gap
+        };
+        let y_axis_font_size = self.y_axis_configs[0].axis_font_size;
+        let weekday_width = if self.y_axis_hidden {
+            0.0
+        } else {
+            measure_max_text_width_family(
+                &self.font_family,
+                y_axis_font_size,
+                self.y_axis_data.iter().map(|item| item.as_str()).collect(),
+            )?
+            .width()
+                + label_gap
+        };
+
+        let count = (end - start + 1) as usize;
+        let first_weekday = get_weekday(start);
+        let weeks = (count + first_weekday - 1) / 7 + 1;
+        // the cells are square
+        let unit = ((c.width() - weekday_width) / weeks as f32)
+            .min((c.height() - month_height) / 7.0)
+            .max(1.0);
+        let cell_size = (unit - calendar.cell_gap).max(1.0);
+
+        let mut data = vec![None; count];
+        for item in self.series.data.iter() {
+            if item.index < data.len() {
+                data[item.index] = Some(item.value);
+            }
+        }
+        let blank_color = self.get_blank_color();
+        let mut months = vec![];
+        for (offset, value) in data.iter().enumerate() {
+            let position = offset + first_weekday;
+            let column = position / 7;
+            let row = position % 7;
+            let (_, month, day) = civil_from_days(start + offset as i64);
+            if offset == 0 || day == 1 {
+                months.push((column, month));
+            }
+            let color = if let Some(value) = value {
+                self.series.get_color(*value)
+            } else {
+                blank_color
+            };
+            c.rect(Rect {
+                color: Some(color),
+                fill: Some(color),
+                left: weekday_width + unit * column as f32,
+                top: month_height + unit * row as f32,
+                width: cell_size,
+                height: cell_size,
+                ..Default::default()
+            });
+        }
+
+        if !self.x_axis_hidden {
+            // the later month is kept if labels overlap,
+            // so the partial month of start is omitted
+            let mut next_left = f32::MAX;
+            for (column, month) in months.iter().rev() {
+                let text = self
+                    .x_axis_data
+                    .get(*month as usize - 1)
+                    .cloned()
+                    .unwrap_or(month.to_string());
+                let left = weekday_width + unit * *column as f32;
+                let text_width =
+                    measure_text_width_family(&self.font_family, self.x_axis_font_size, &text)?
+                        .width();
+                if left + text_width > next_left {
+                    continue;
+                }
+                next_left = left;
+                c.child(Box {
+                    left,
+                    top: self.x_axis_font_size,
+                    ..Default::default()
+                })
+                .text(Text {
+                    text,
+                    font_family: Some(self.font_family.clone()),
+                    font_size: Some(self.x_axis_font_size),
+                    font_color: Some(self.x_axis_font_color),
+                    font_weight: self.x_axis_font_weight.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+        if !self.y_axis_hidden {
+            for (row, text) in self.y_axis_data.iter().take(7).enumerate() {
+                if text.is_empty() {
+                    continue;
+                }
+                c.child(Box {
+                    top: month_height + unit * row as f32 + (cell_size + y_axis_font_size) / 2.0
+                        - 2.0,
+                    ..Default::default()
+                })
+                .text(Text {
+                    text: text.clone(),
+                    font_family: Some(self.font_family.clone()),
+                    font_size: Some(y_axis_font_size),
+                    font_color: Some(self.y_axis_configs[0].axis_font_color),
+                    font_weight: self.y_axis_configs[0].axis_font_weight.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        c.svg()
+    }
+    /// Gets the accessible metadata of heatmap chart,
+    /// each y axis category is a row of data table.
+    fn get_accessibility(&self) -> Option<Accessibility> {
+        let x_axis_count = self.x_axis_data.len();
+        let mut data = vec![NIL_VALUE; x_axis_count * self.y_axis_data.len()];
+        for item in self.series.data.iter() {
+            if item.index < data.len() {
+                data[item.index] = item.value;
+            }
+        }
+        let series_list: Vec<Series> = self
+            .y_axis_data
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let start = i * self.y_axis_data.len();
+                let values = (0..x_axis_count)
+                    .map(|j| data.get(start + j).copied().unwrap_or(NIL_VALUE))
+                    .collect();
+                (name.as_str(), values).into()
+            })
+            .collect();
+        get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        )
+    }
     /// Converts heatmap chart to svg.
     pub fn svg(&self) -> canvas::Result<String> {
+        if let Some(calendar) = &self.calendar {
+            return self.calendar_svg(calendar);
+        }
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.accessibility = self.get_accessibility();
 
         if self.x_axis_data.is_empty() || self.y_axis_data.is_empty() {
             return Err(canvas::Error::Params {
@@ -293,8 +713,6 @@ impl HeatmapChart {
         } else {
             title_height
         };
-        let axis_height = c.height() - x_axis_height - axis_top;
-
         // minus the height of top text area
         if axis_top > 0.0 {
             c = c.child(Box {
@@ -302,6 +720,8 @@ impl HeatmapChart {
                 ..Default::default()
             });
         }
+        self.render_visual_map(&mut c)?;
+        let axis_height = c.height() - x_axis_height;
         let mut y_axis_width = 0.0;
         if !self.y_axis_hidden {
             let max_text_width_box = measure_max_text_width_family(
@@ -357,29 +777,25 @@ impl HeatmapChart {
                 let mut text = "".to_string();
                 let mut font_color = self.series.min_font_color;
                 let color = if let Some(value) = data[index] {
+                    let color = self.series.get_color(value);
                     let percent = (value - self.series.min) / (self.series.max - self.series.min);
-                    if percent >= 0.8 {
+                    // the font color of scale depends on the lightness of cell
+                    if self.series.color_scale.is_some() {
+                        if !color.is_light() {
+                            font_color = self.series.max_font_color;
+                        }
+                    } else if percent >= 0.8 {
                         font_color = self.series.max_font_color;
                     }
 
                     text = format_series_value(value, &self.series_label_formatter);
-                    self.series.get_color(value)
+                    color
                 } else {
                     let mut color_index = j;
                     if i % 2 != 0 {
                         color_index += 1;
                     }
-                    let mut color = self.background_color;
-                    let offset = 20;
-                    if color.is_light() {
-                        color.r -= offset;
-                        color.g -= offset;
-                        color.b -= offset;
-                    } else {
-                        color.r += offset;
-                        color.g += offset;
-                        color.b += offset;
-                    }
+                    let mut color = self.get_blank_color();
                     if color_index % 2 != 0 {
                         color = color.with_alpha(100);
                     }
@@ -425,11 +841,57 @@ impl HeatmapChart {
 
 #[cfg(test)]
 mod tests {
-    use crate::THEME_DARK;
+    use crate::{ColorScale, Position, VisualMapOption, PALETTE_RDBU, THEME_DARK};
 
-    use super::HeatmapChart;
+    use super::{civil_from_days, get_weekday, parse_date, HeatmapCalendar, HeatmapChart};
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn heatmap_calendar_date() {
+        // 2024-03-03 is sunday
+        assert_eq!(0, get_weekday(parse_date("2024-03-03").unwrap()));
+        assert_eq!(6, get_weekday(parse_date("2024-03-09").unwrap()));
+
+        assert_eq!(None, parse_date("2023-02-29"));
+        assert_eq!(None, parse_date("2023-13-01"));
+        assert_eq!(None, parse_date("2023/01/01"));
+
+        let calendar = HeatmapCalendar::new("2023-12-30", "2024-01-31");
+        assert_eq!(Some(0), calendar.get_index("2023-12-30"));
+        assert_eq!(Some(3), calendar.get_index("2024-01-02"));
+        assert_eq!(Some(32), calendar.get_index("2024-01-31"));
+        assert_eq!(None, calendar.get_index("2024-02-01"));
+        assert_eq!(None, calendar.get_index("2023-12-29"));
+    }
+
+    #[test]
+    fn heatmap_chart_calendar() {
+        let start = parse_date("2024-01-01").unwrap();
+        let dates: Vec<String> = (0..366)
+            .map(|offset| {
+                let (year, month, day) = civil_from_days(start + offset);
+                format!("{year}-{month:02}-{day:02}")
+            })
+            .collect();
+        let data = dates
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| index % 5 != 0)
+            .map(|(index, date)| (date.as_str(), (index * 7 % 11) as f32))
+            .collect();
+        let mut heatmap_chart = HeatmapChart::new_calendar(data, "2024-01-01", "2024-12-31");
+        heatmap_chart.title_text = "Contributions".to_string();
+        heatmap_chart.width = 800.0;
+        heatmap_chart.height = 200.0;
+        heatmap_chart.series.min_color = (235, 237, 240).into();
+        heatmap_chart.series.max_color = (33, 110, 57).into();
+
+        assert_eq!(
+            include_str!("../../asset/heatmap_chart/calendar.svg"),
+            heatmap_chart.svg().unwrap()
+        );
+    }
+
     #[test]
     fn heatmap_chart_basic() {
         let x_axis_data = vec![
@@ -567,4 +1029,178 @@ mod tests {
             heatmap_chart.svg().unwrap()
         );
     }
+
+    #[test]
+    fn heatmap_chart_color_scale() {
+        let x_axis_data = vec![
+            "12a", "1a", "2a", "3a", "4a", "5a", "6a", "7a", "8a", "9a", "10a", "11a", "12p", "1p",
+            "2p", "3p", "4p", "5p", "6p", "7p", "8p", "9p", "10p", "11p",
+        ]
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+        let y_axis_data = [
+            "Saturday",
+            "Friday",
+            "Thursday",
+            "Wednesday",
+            "Tuesday",
+            "Monday",
+            "Sunday",
+        ]
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+        let mut heatmap_chart = HeatmapChart::new(
+            vec![
+                (0, 9.0),
+                (1, 3.0),
+                (7, 3.0),
+                (12, 3.0),
+                (24, 12.0),
+                (28, 10.0),
+                (31, 8.0),
+                (50, 4.0),
+                (63, 2.0),
+            ],
+            x_axis_data,
+            y_axis_data,
+        );
+        heatmap_chart.width = 800.0;
+        heatmap_chart.series.max = 10.0;
+        let mut color_scale = ColorScale::new_palette(PALETTE_RDBU, 0.0, 12.0);
+        color_scale.colors.reverse();
+        color_scale.mid = Some(3.0);
+        heatmap_chart.series.color_scale = Some(color_scale);
+
+        assert_eq!(
+            include_str!("../../asset/heatmap_chart/color_scale.svg"),
+            heatmap_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn heatmap_chart_visual_map() {
+        let x_axis_data = vec![
+            "12a", "1a", "2a", "3a", "4a", "5a", "6a", "7a", "8a", "9a", "10a", "11a", "12p", "1p",
+            "2p", "3p", "4p", "5p", "6p", "7p", "8p", "9p", "10p", "11p",
+        ]
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+        let y_axis_data = [
+            "Saturday",
+            "Friday",
+            "Thursday",
+            "Wednesday",
+            "Tuesday",
+            "Monday",
+            "Sunday",
+        ]
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+        let mut heatmap_chart = HeatmapChart::new(
+            vec![
+                (0, 9.0),
+                (1, 3.0),
+                (7, 3.0),
+                (12, 3.0),
+                (24, 12.0),
+                (28, 10.0),
+                (31, 8.0),
+                (50, 4.0),
+                (63, 2.0),
+            ],
+            x_axis_data,
+            y_axis_data,
+        );
+        heatmap_chart.width = 800.0;
+        heatmap_chart.series.max = 10.0;
+        heatmap_chart.title_text = "Punch Card".to_string();
+        heatmap_chart.visual_map = Some(VisualMapOption {
+            position: Position::Right,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            include_str!("../../asset/heatmap_chart/visual_map.svg"),
+            heatmap_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn heatmap_chart_visual_map_axis_formatter() {
+        let mut heatmap_chart = HeatmapChart::new(
+            vec![(0, 0.0), (1, 10.0)],
+            vec!["a".to_string(), "b".to_string()],
+            vec!["c".to_string()],
+        );
+        heatmap_chart.series.max = 10.0;
+        heatmap_chart.y_axis_configs[0].axis_formatter = Some("{c}%".to_string());
+        heatmap_chart.visual_map = Some(VisualMapOption {
+            position: Position::Right,
+            ..Default::default()
+        });
+        let svg = heatmap_chart.svg().unwrap();
+        assert!(svg.contains("\n0%\n</text>"));
+        assert!(svg.contains("\n10%\n</text>"));
+
+        // the formatter of visual map option takes precedence
+        heatmap_chart.visual_map = Some(VisualMapOption {
+            position: Position::Right,
+            formatter: Some("{c}ms".to_string()),
+            ..Default::default()
+        });
+        let svg = heatmap_chart.svg().unwrap();
+        assert!(svg.contains("\n10ms\n</text>"));
+        assert!(!svg.contains("\n10%\n</text>"));
+    }
+
+    #[test]
+    fn heatmap_chart_serde() {
+        let x_axis_data = vec![
+            "12a", "1a", "2a", "3a", "4a", "5a", "6a", "7a", "8a", "9a", "10a", "11a", "12p", "1p",
+            "2p", "3p", "4p", "5p", "6p", "7p", "8p", "9p", "10p", "11p",
+        ]
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+        let y_axis_data = [
+            "Saturday",
+            "Friday",
+            "Thursday",
+            "Wednesday",
+            "Tuesday",
+            "Monday",
+            "Sunday",
+        ]
+        .iter()
+        .map(|item| item.to_string())
+        .collect();
+        let mut heatmap_chart = HeatmapChart::new(
+            vec![
+                (0, 9.0),
+                (1, 3.0),
+                (7, 3.0),
+                (12, 3.0),
+                (24, 12.0),
+                (28, 10.0),
+                (31, 8.0),
+                (50, 4.0),
+                (63, 2.0),
+            ],
+            x_axis_data,
+            y_axis_data,
+        );
+        heatmap_chart.width = 800.0;
+        heatmap_chart.series.max = 10.0;
+
+        let data = serde_json::to_string(&heatmap_chart).unwrap();
+        let heatmap_chart: HeatmapChart = serde_json::from_str(&data).unwrap();
+        assert_eq!(
+            include_str!("../../asset/heatmap_chart/basic.svg"),
+            heatmap_chart.svg().unwrap()
+        );
+    }
 }
@@ -0,0 +1,559 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::canvas;
+use super::color::*;
+use super::common::*;
+use super::component::*;
+use super::params::*;
+use super::theme::{get_default_theme_name, get_theme, Theme, DEFAULT_Y_AXIS_WIDTH};
+use super::util::*;
+use super::Canvas;
+use crate::charts::measure_text_width_family;
+use charts_rs_derive::Chart;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+// the max count of bins, avoid too many bars for bad width
+static MAX_BIN_COUNT: usize = 1000;
+
+/// The method of histogram binning.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum HistogramBin {
+    // ceil(log2(n)) + 1 bins
+    #[default]
+    Sturges,
+    // the bin width is 2 * iqr / cbrt(n)
+    FreedmanDiaconis,
+    Count(usize),
+    Width(f32),
+}
+
+/// Gets the edges of bins for samples, the count of bins is edges.len() - 1.
+pub fn get_histogram_edges(samples: &[f32], bin: HistogramBin) -> Vec<f32> {
+    let mut values: Vec<f32> = samples
+        .iter()
+        .filter(|item| **item != NIL_VALUE && item.is_finite())
+        .copied()
+        .collect();
+    if values.is_empty() {
+        return vec![];
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let min = values[0];
+    let max = values[values.len() - 1];
+    let range = max - min;
+    if range <= 0.0 {
+        return vec![min - 0.5, min + 0.5];
+    }
+    let count = values.len() as f32;
+    let sturges_count = count.log2().ceil() as usize + 1;
+    let (start, width, bin_count) = match bin {
+        HistogramBin::Sturges => (min, range / sturges_count as f32, sturges_count),
+        HistogramBin::FreedmanDiaconis => {
+            let iqr = get_quantile(&values, 0.75) - get_quantile(&values, 0.25);
+            let width = 2.0 * iqr / count.cbrt();
+            if width > 0.0 {
+                (min, width, (range / width).ceil() as usize)
+            } else {
+                (min, range / sturges_count as f32, sturges_count)
+            }
+        }
+        HistogramBin::Count(count) => {
+            let count = count.max(1);
+            (min, range / count as f32, count)
+        }
+        // align the edges to the multiple of width
+        HistogramBin::Width(width) if width > 0.0 => {
+            let start = (min / width).floor() * width;
+            let count = ((max - start) / width).ceil() as usize;
+            // the max value is on the edge, it belongs to the last bin
+            (start, width, count)
+        }
+        HistogramBin::Width(_) => (min, range / sturges_count as f32, sturges_count),
+    };
+    // the range is split evenly if there are too many bins
+    let (width, bin_count) = if bin_count > MAX_BIN_COUNT {
+        ((max - start) / MAX_BIN_COUNT as f32, MAX_BIN_COUNT)
+    } else {
+        (width, bin_count.max(1))
+    };
+    let mut edges: Vec<f32> = (0..=bin_count)
+        .map(|index| start + width * index as f32)
+        .collect();
+    // avoid the float error of last edge
+    if edges[bin_count] < max {
+        edges[bin_count] = max;
+    }
+    edges
+}
+
+/// Counts the samples of each bin, the bin is [start, end)
+/// except the last one, which includes the end.
+pub fn get_histogram_counts(samples: &[f32], edges: &[f32]) -> Vec<usize> {
+    if edges.len() < 2 {
+        return vec![];
+    }
+    let mut counts = vec![0; edges.len() - 1];
+    let last = counts.len() - 1;
+    for value in samples.iter() {
+        if *value == NIL_VALUE || !value.is_finite() {
+            continue;
+        }
+        if *value < edges[0] || *value > edges[edges.len() - 1] {
+            continue;
+        }
+        let index = edges
+            .partition_point(|edge| edge <= value)
+            .saturating_sub(1);
+        counts[index.min(last)] += 1;
+    }
+    counts
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Chart)]
+pub struct HistogramChart {
+    pub width: f32,
+    pub height: f32,
+    pub x: f32,
+    pub y: f32,
+    pub margin: Box,
+    // the raw samples of series
+    pub series_list: Vec<Series>,
+    pub font_family: String,
+    pub background_color: Color,
+    pub is_light: bool,
+
+    // title
+    pub title_text: String,
+    pub title_font_size: f32,
+    pub title_font_color: Color,
+    pub title_font_weight: Option<String>,
+    pub title_margin: Option<Box>,
+    pub title_align: Align,
+    pub title_height: f32,
+
+    // sub title
+    pub sub_title_text: String,
+    pub sub_title_font_size: f32,
+    pub sub_title_font_color: Color,
+    pub sub_title_font_weight: Option<String>,
+    pub sub_title_margin: Option<Box>,
+    pub sub_title_align: Align,
+    pub sub_title_height: f32,
+
+    // legend
+    pub legend_font_size: f32,
+    pub legend_font_color: Color,
+    pub legend_font_weight: Option<String>,
+    pub legend_align: Align,
+    pub legend_margin: Option<Box>,
+    pub legend_category: LegendCategory,
+    pub legend_show: Option<bool>,
+
+    // x axis
+    pub x_axis_data: Vec<String>,
+    pub x_axis_height: f32,
+    pub x_axis_stroke_color: Color,
+    pub x_axis_font_size: f32,
+    pub x_axis_font_color: Color,
+    pub x_axis_font_weight: Option<String>,
+    pub x_axis_name_gap: f32,
+    pub x_axis_name_rotate: f32,
+    pub x_axis_margin: Option<Box>,
+    pub x_axis_config: YAxisConfig,
+    pub x_axis_hidden: bool,
+    pub x_boundary_gap: Option<bool>,
+
+    // y axis
+    pub y_axis_hidden: bool,
+    pub y_axis_configs: Vec<YAxisConfig>,
+
+    // grid
+    pub grid_stroke_color: Color,
+    pub grid_stroke_width: f32,
+
+    // series
+    pub series_stroke_width: f32,
+    pub series_label_font_color: Color,
+    pub series_label_font_size: f32,
+    pub series_label_font_weight: Option<String>,
+    pub series_label_formatter: String,
+    pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
+    pub series_symbol: Option<Symbol>,
+    pub series_smooth: bool,
+    pub series_fill: bool,
+
+    // the bins are shared by all series
+    pub bin: HistogramBin,
+    // tooltip of the range and count of each bin
+    pub interactive: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the samples of each series, it implies accessible
+    pub accessible_table: Option<bool>,
+}
+
+impl HistogramChart {
+    /// Creates a histogram chart from json, the bin is set by
+    /// `bin_count`, `bin_width` or `bin_method`(sturges, freedman_diaconis).
+    pub fn from_json(data: &str) -> canvas::Result<HistogramChart> {
+        let mut h = HistogramChart {
+            ..Default::default()
+        };
+        let value = h.fill_option(data)?;
+        h.fill_default();
+
+        if let Some(bin_method) = get_string_from_value(&value, "bin_method") {
+            if bin_method.to_lowercase() == "freedman_diaconis" {
+                h.bin = HistogramBin::FreedmanDiaconis;
+            }
+        }
+        if let Some(bin_count) = get_usize_from_value(&value, "bin_count") {
+            h.bin = HistogramBin::Count(bin_count);
+        }
+        if let Some(bin_width) = get_f32_from_value(&value, "bin_width") {
+            h.bin = HistogramBin::Width(bin_width);
+        }
+        if let Some(x_axis_hidden) = get_bool_from_value(&value, "x_axis_hidden") {
+            h.x_axis_hidden = x_axis_hidden;
+        }
+        if let Some(y_axis_hidden) = get_bool_from_value(&value, "y_axis_hidden") {
+            h.y_axis_hidden = y_axis_hidden;
+        }
+        let theme = get_string_from_value(&value, "theme").unwrap_or_default();
+        if let Some(x_axis_config) = value.get("x_axis_config") {
+            h.x_axis_config = get_y_axis_config_from_value(get_theme(&theme), x_axis_config);
+        }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            h.interactive = Some(interactive);
+        }
+        Ok(h)
+    }
+    /// Creates a histogram chart with theme, the data of series is the raw samples.
+    pub fn new_with_theme(mut series_list: Vec<Series>, theme: &str) -> HistogramChart {
+        // set the index of series
+        series_list
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, item)| {
+                item.index = Some(index);
+            });
+        let mut h = HistogramChart {
+            series_list,
+            ..Default::default()
+        };
+        let theme = get_theme(theme);
+        h.fill_theme(theme);
+        h.fill_default();
+        h
+    }
+    fn fill_default(&mut self) {
+        if self.x_axis_config.axis_split_number == 0 {
+            self.x_axis_config = self.get_y_axis_config(0);
+        }
+        // the labels of x axis are placed at the ticks
+        self.x_boundary_gap = Some(false);
+    }
+    /// Creates a histogram chart with default theme.
+    pub fn new(series_list: Vec<Series>) -> HistogramChart {
+        HistogramChart::new_with_theme(series_list, &get_default_theme_name())
+    }
+    /// Gets the edges of bins and the counts of each series.
+    pub fn get_bins(&self) -> (Vec<f32>, Vec<Vec<usize>>) {
+        let samples: Vec<f32> = self
+            .series_list
+            .iter()
+            .flat_map(|item| item.data.iter().copied())
+            .collect();
+        let edges = get_histogram_edges(&samples, self.bin);
+        let counts_list = self
+            .series_list
+            .iter()
+            .map(|item| get_histogram_counts(&item.data, &edges))
+            .collect();
+        (edges, counts_list)
+    }
+    /// Converts histogram chart to svg.
+    pub fn svg(&self) -> canvas::Result<String> {
+        // the bins and counts are drawn on linear axis
+        if let Some(log_base) = self
+            .get_y_axis_config(0)
+            .axis_log_base
+            .or(self.x_axis_config.axis_log_base)
+        {
+            return Err(canvas::Error::Params {
+                message: format!("histogram chart does not support log axis, got {log_base}"),
+            });
+        }
+        let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
+
+        self.render_background(c.child(Box::default()));
+        let mut x_axis_height = self.x_axis_height;
+        if self.x_axis_hidden {
+            x_axis_height = 0.0;
+        }
+        c.margin = self.margin.clone();
+
+        let title_height = self.render_title(c.child(Box::default()));
+
+        let legend_height = self.render_legend(c.child(Box::default()));
+        // get the max height of title and legend
+        let axis_top = if legend_height > title_height {
+            legend_height
+        } else {
+            title_height
+        };
+
+        let (edges, counts_list) = self.get_bins();
+        let y_axis_config = self.get_y_axis_config(0);
+        // the count starts from zero
+        let mut y_axis_data_list = vec![0.0];
+        for counts in counts_list.iter() {
+            y_axis_data_list.extend(counts.iter().map(|item| *item as f32));
+        }
+        let y_axis_values = get_axis_values(AxisValueParams {
+            data_list: y_axis_data_list,
+            split_number: y_axis_config.axis_split_number,
+            reverse: Some(true),
+            min: y_axis_config.axis_min,
+            max: y_axis_config.axis_max,
+            thousands_format: false,
+            log_base: None,
+        });
+        let y_axis_width = if self.y_axis_hidden {
+            0.0
+        } else if let Some(value) = y_axis_config.axis_width {
+            value
+        } else {
+            let y_axis_formatter = &y_axis_config.axis_formatter.clone().unwrap_or_default();
+            let mut longest_item = "";
+            for item in y_axis_values.data.iter() {
+                if item.chars().count() > longest_item.chars().count() {
+                    longest_item = item;
+                }
+            }
+            let str = format_string(longest_item, y_axis_formatter);
+            if let Ok(b) =
+                measure_text_width_family(&self.font_family, y_axis_config.axis_font_size, &str)
+            {
+                b.width() + 5.0
+            } else {
+                DEFAULT_Y_AXIS_WIDTH
+            }
+        };
+
+        let axis_height = c.height() - x_axis_height - axis_top;
+        let axis_width = c.width() - y_axis_width;
+        // minus the height of top text area
+        if axis_top > 0.0 {
+            c = c.child(Box {
+                top: axis_top,
+                ..Default::default()
+            });
+        }
+
+        self.render_grid(
+            c.child(Box {
+                left: y_axis_width,
+                ..Default::default()
+            }),
+            axis_width,
+            axis_height,
+        );
+
+        // y axis
+        if !self.y_axis_hidden {
+            self.render_y_axis(
+                c.child(Box::default()),
+                y_axis_values.data.clone(),
+                axis_height,
+                y_axis_width,
+                0,
+            );
+        }
+
+        // x axis is a numeric axis of the edges
+        let x_axis_values = get_axis_values(AxisValueParams {
+            data_list: edges.clone(),
+            split_number: self.x_axis_config.axis_split_number,
+            min: self.x_axis_config.axis_min,
+            max: self.x_axis_config.axis_max,
+            ..Default::default()
+        });
+        if !self.x_axis_hidden {
+            let x_axis_formatter = &self
+                .x_axis_config
+                .axis_formatter
+                .clone()
+                .unwrap_or_default();
+            self.render_x_axis(
+                c.child(Box {
+                    top: c.height() - x_axis_height,
+                    left: y_axis_width,
+                    ..Default::default()
+                }),
+                x_axis_values
+                    .data
+                    .iter()
+                    .map(|item| format_string(item, x_axis_formatter))
+                    .collect(),
+                axis_width,
+            );
+        }
+
+        let mut content_canvas = c.child(Box {
+            left: y_axis_width,
+            ..Default::default()
+        });
+        let get_x = |value: f32| axis_width - x_axis_values.get_offset_height(value, axis_width);
+        let bottom = y_axis_values.get_offset_height(0.0, axis_height);
+        for (index, series) in self.series_list.iter().enumerate() {
+            let mut color = get_color(&self.series_colors, series.index.unwrap_or(index));
+            // the bars of series are overlapped
+            if self.series_list.len() > 1 {
+                color = color.with_alpha(160);
+            }
+            let fill = get_series_paint(
+                series,
+                series.index.unwrap_or(index),
+                &self.series_patterns,
+                color,
+            )
+            .unwrap_or(color.into());
+            for (i, count) in counts_list[index].iter().enumerate() {
+                if *count == 0 {
+                    continue;
+                }
+                let left = get_x(edges[i]);
+                let top = y_axis_values.get_offset_height(*count as f32, axis_height);
+                // the border of background color separates the bars
+                let rect = Rect {
+                    color: Some(self.background_color),
+                    paint: Some(fill.clone()),
+                    left,
+                    top,
+                    width: get_x(edges[i + 1]) - left,
+                    height: bottom - top,
+                    ..Default::default()
+                };
+                if content_canvas.interactive {
+                    let end = if i == counts_list[index].len() - 1 {
+                        "]"
+                    } else {
+                        ")"
+                    };
+                    let category = format!(
+                        "[{}, {}{end}",
+                        format_float(edges[i]),
+                        format_float(edges[i + 1])
+                    );
+                    content_canvas
+                        .interactive_child(Interactive::new_item(
+                            &series.name,
+                            Some(category),
+                            count.to_string(),
+                            &count.to_string(),
+                        ))
+                        .rect(rect);
+                } else {
+                    content_canvas.rect(rect);
+                }
+            }
+        }
+
+        c.svg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_histogram_counts, get_histogram_edges, HistogramBin, HistogramChart};
+    use crate::Align;
+    use pretty_assertions::assert_eq;
+
+    fn round(values: Vec<f32>) -> Vec<f32> {
+        values
+            .iter()
+            .map(|item| (item * 100.0).round() / 100.0)
+            .collect()
+    }
+    #[test]
+    fn histogram_bins() {
+        let samples = vec![1.0, 2.0, 2.5, 3.0, 4.0, 4.5, 5.0, 7.0, 8.0, 10.0];
+        // ceil(log2(10)) + 1 = 5
+        let edges = get_histogram_edges(&samples, HistogramBin::Sturges);
+        assert_eq!(vec![1.0, 2.8, 4.6, 6.4, 8.2, 10.0], round(edges.clone()));
+        assert_eq!(vec![3, 3, 1, 2, 1], get_histogram_counts(&samples, &edges));
+
+        let edges = get_histogram_edges(&samples, HistogramBin::Width(4.0));
+        assert_eq!(vec![0.0, 4.0, 8.0, 12.0], edges);
+        assert_eq!(vec![4, 4, 2], get_histogram_counts(&samples, &edges));
+
+        let edges = get_histogram_edges(&samples, HistogramBin::Count(3));
+        assert_eq!(vec![1.0, 4.0, 7.0, 10.0], edges);
+        assert_eq!(vec![4, 3, 3], get_histogram_counts(&samples, &edges));
+
+        // iqr = 6.5 - 2.625 = 3.875, width = 7.75 / cbrt(10)
+        let edges = get_histogram_edges(&samples, HistogramBin::FreedmanDiaconis);
+        assert_eq!(vec![1.0, 4.6, 8.19, 11.79], round(edges.clone()));
+        assert_eq!(vec![6, 3, 1], get_histogram_counts(&samples, &edges));
+
+        // the count of bins is limited and the range is split evenly
+        let samples = vec![0.0, 50.0, 100.0];
+        let edges = get_histogram_edges(&samples, HistogramBin::Width(0.001));
+        assert_eq!(1001, edges.len());
+        assert_eq!(0.1, (edges[1] * 1000.0).round() / 1000.0);
+        assert_eq!(100.0, edges[1000]);
+        assert_eq!(
+            true,
+            edges
+                .windows(2)
+                .all(|item| (item[1] - item[0] - 0.1).abs() < 0.001)
+        );
+
+        assert_eq!(
+            vec![4.5, 5.5],
+            get_histogram_edges(&[5.0, 5.0], HistogramBin::Sturges)
+        );
+        assert_eq!(
+            Vec::<f32>::new(),
+            get_histogram_edges(&[], HistogramBin::Sturges)
+        );
+    }
+
+    #[test]
+    fn histogram_chart_basic() {
+        let mut histogram_chart = HistogramChart::new(vec![(
+            "Latency",
+            vec![
+                23.0, 45.0, 31.0, 28.0, 52.0, 37.0, 41.0, 29.0, 35.0, 48.0, 33.0, 39.0, 27.0, 44.0,
+                36.0, 61.0, 30.0, 42.0, 38.0, 34.0, 47.0, 26.0, 40.0, 55.0, 32.0, 36.0, 43.0, 39.0,
+                35.0, 71.0,
+            ],
+        )
+            .into()]);
+        histogram_chart.title_text = "Latency Distribution".to_string();
+        histogram_chart.bin = HistogramBin::Width(5.0);
+        histogram_chart.x_axis_config.axis_formatter = Some("{c}ms".to_string());
+        histogram_chart.x_axis_config.axis_min = Some(15.0);
+        histogram_chart.x_axis_config.axis_split_number = 4;
+        histogram_chart.title_align = Align::Left;
+        histogram_chart.legend_align = Align::Right;
+        assert_eq!(
+            include_str!("../../asset/histogram_chart/basic.svg"),
+            histogram_chart.svg().unwrap()
+        );
+    }
+}
@@ -80,9 +80,18 @@ pub struct HorizontalBarChart {
     pub series_label_formatter: String,
     pub series_label_position: Option<Position>,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
+    // tooltip of each bar, the legend toggles its series
+    pub interactive: Option<bool>,
+    // bars grow from the axis
+    pub animation: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the values of each series, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl HorizontalBarChart {
@@ -97,6 +106,12 @@ impl HorizontalBarChart {
         {
             h.series_label_position = Some(series_label_position);
         }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            h.interactive = Some(interactive);
+        }
+        if let Some(animation) = get_bool_from_value(&value, "animation") {
+            h.animation = Some(animation);
+        }
         Ok(h)
     }
     /// Creates a horizontal bar with custom theme.
@@ -120,7 +135,23 @@ impl HorizontalBarChart {
     }
     /// Converts horizontal bar chart to svg.
     pub fn svg(&self) -> canvas::Result<String> {
+        // the value axis of horizontal bar chart is linear
+        if let Some(log_base) = self.get_y_axis_config(0).axis_log_base {
+            return Err(canvas::Error::Params {
+                message: format!("horizontal bar chart does not support log axis, got {log_base}"),
+            });
+        }
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.animation = self.animation.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
 
         self.render_background(c.child(Box::default()));
         c.margin = self.margin.clone();
@@ -241,6 +272,13 @@ impl HorizontalBarChart {
             let mut series_labels_list = vec![];
             for (index, series) in self.series_list.iter().enumerate() {
                 let color = get_color(&self.series_colors, series.index.unwrap_or(index));
+                let fill = get_series_paint(
+                    series,
+                    series.index.unwrap_or(index),
+                    &self.series_patterns,
+                    color,
+                )
+                .unwrap_or(color.into());
 
                 let mut series_labels = vec![];
                 let series_data_count = series.data.len();
@@ -254,16 +292,36 @@ impl HorizontalBarChart {
                     top += (bar_height + bar_chart_gap) * index as f32;
 
                     let x = max_width - x_axis_values.get_offset_height(value, max_width);
-                    c1.rect(Rect {
-                        fill: Some(color),
+                    let rect = Rect {
+                        paint: Some(fill.clone()),
                         top,
                         width: x,
                         height: bar_height,
                         ..Default::default()
-                    });
+                    };
+                    let text = format_series_value(value, &self.series_label_formatter);
+                    let mut rect_canvas = c1.clone();
+                    if c1.interactive {
+                        rect_canvas = rect_canvas.interactive_child(Interactive::new_item(
+                            &series.name,
+                            self.x_axis_data.get(i).cloned(),
+                            format_float(value),
+                            &text,
+                        ));
+                    }
+                    if c1.animation {
+                        // the bar of negative value grows from right
+                        let position = if value < 0.0 {
+                            Position::Right
+                        } else {
+                            Position::Left
+                        };
+                        rect_canvas = rect_canvas.animation_child(Animation::Grow(position));
+                    }
+                    rect_canvas.rect(rect);
                     series_labels.push(SeriesLabel {
                         point: (x, top + half_bar_height).into(),
-                        text: format_series_value(value, &self.series_label_formatter),
+                        text,
                     })
                 }
                 if series.label_show {
@@ -427,4 +485,26 @@ mod tests {
             horizontal_bar_chart.svg().unwrap()
         );
     }
+
+    #[test]
+    fn horizontal_bar_chart_animation() {
+        let mut horizontal_bar_chart = HorizontalBarChart::new(
+            vec![
+                ("2011", vec![18203.0, -23489.0, 29034.0]).into(),
+                ("2012", vec![19325.0, 23438.0, -31000.0]).into(),
+            ],
+            vec![
+                "Brazil".to_string(),
+                "Indonesia".to_string(),
+                "USA".to_string(),
+            ],
+        );
+        horizontal_bar_chart.title_text = "World Population".to_string();
+        horizontal_bar_chart.margin.right = 15.0;
+        horizontal_bar_chart.animation = Some(true);
+        assert_eq!(
+            include_str!("../../asset/horizontal_bar_chart/animation.svg"),
+            horizontal_bar_chart.svg().unwrap()
+        );
+    }
 }
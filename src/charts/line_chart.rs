@@ -14,6 +14,8 @@ use crate::charts::measure_text_width_family;
 use charts_rs_derive::Chart;
 use std::sync::Arc;
 
+static SECONDS_OF_DAY: f32 = 86400.0;
+
 #[derive(Clone, Debug, Default, Chart)]
 pub struct LineChart {
     pub width: f32,
@@ -65,6 +67,9 @@ pub struct LineChart {
     pub x_axis_margin: Option<Box>,
     pub x_axis_hidden: bool,
     pub x_boundary_gap: Option<bool>,
+    // time x axis, the timestamps of series are used instead of x axis data
+    pub x_axis_time: Option<bool>,
+    pub x_axis_time_format: Option<String>,
 
     // y axis
     pub y_axis_hidden: bool,
@@ -81,9 +86,18 @@ pub struct LineChart {
     pub series_label_font_weight: Option<String>,
     pub series_label_formatter: String,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
+    // tooltip of each point, the legend toggles its line
+    pub interactive: Option<bool>,
+    // lines draw in and the areas fade in
+    pub animation: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the values of each line, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl LineChart {
@@ -96,9 +110,21 @@ impl LineChart {
         if let Some(x_axis_hidden) = get_bool_from_value(&value, "x_axis_hidden") {
             l.x_axis_hidden = x_axis_hidden;
         }
+        if let Some(x_axis_time) = get_bool_from_value(&value, "x_axis_time") {
+            l.x_axis_time = Some(x_axis_time);
+        }
+        if let Some(x_axis_time_format) = get_string_from_value(&value, "x_axis_time_format") {
+            l.x_axis_time_format = Some(x_axis_time_format);
+        }
         if let Some(y_axis_hidden) = get_bool_from_value(&value, "y_axis_hidden") {
             l.y_axis_hidden = y_axis_hidden;
         }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            l.interactive = Some(interactive);
+        }
+        if let Some(animation) = get_bool_from_value(&value, "animation") {
+            l.animation = Some(animation);
+        }
         Ok(l)
     }
     /// Creates a line chart with custom theme.
@@ -128,6 +154,8 @@ impl LineChart {
         max_height: f32,
     ) {
         let mut c = c;
+        let stack_series_list: Vec<&Series> = series_list.iter().collect();
+        let stack_bases_list = get_series_stack_bases(&stack_series_list);
         for (index, series) in series_list.iter().enumerate() {
             if series.mark_lines.is_empty() {
                 continue;
@@ -138,11 +166,13 @@ impl LineChart {
                 y_axis_values_list[series.y_axis_index]
             };
             let color = get_color(&self.series_colors, series.index.unwrap_or(index));
+            // the stacked series use the stacked value
             let values: Vec<_> = series
                 .data
                 .iter()
-                .filter(|x| *x.to_owned() != NIL_VALUE)
-                .map(|x| x.to_owned())
+                .enumerate()
+                .filter(|(_, x)| *x.to_owned() != NIL_VALUE)
+                .map(|(i, x)| x.to_owned() + stack_bases_list[index][i])
                 .collect();
             let mut sum = 0.0;
             let mut min = f32::MAX;
@@ -206,9 +236,99 @@ impl LineChart {
             }
         }
     }
+    /// Renders the trend lines of series, the x of trend line is the index of data,
+    /// or the days from the first timestamp if it's time axis.
+    fn render_trend_line(
+        &self,
+        c: Canvas,
+        y_axis_values_list: &[&AxisValues],
+        max_height: f32,
+        time_axis_values: Option<&TimeAxisValues>,
+    ) {
+        let mut c = c;
+        let stack_series_list: Vec<&Series> = self.series_list.iter().collect();
+        let stack_bases_list = get_series_stack_bases(&stack_series_list);
+        let x_boundary_gap = self.x_boundary_gap.unwrap_or(true);
+        let split_unit_count = if x_boundary_gap {
+            self.x_axis_data.len() as f32
+        } else {
+            self.x_axis_data.len() as f32 - 1.0
+        };
+        let unit_width = c.width() / split_unit_count;
+        let width = c.width();
+        let mut label_index = 0;
+        for (index, series) in self.series_list.iter().enumerate() {
+            if series.trend_lines.is_empty() {
+                continue;
+            }
+            let y_axis_values = if series.y_axis_index >= y_axis_values_list.len() {
+                y_axis_values_list[0]
+            } else {
+                y_axis_values_list[series.y_axis_index]
+            };
+            let first_timestamp = series
+                .timestamps
+                .as_ref()
+                .and_then(|item| item.first())
+                .copied()
+                .unwrap_or_default();
+            let mut data = vec![];
+            for (i, value) in series.data.iter().enumerate() {
+                if *value == NIL_VALUE {
+                    continue;
+                }
+                let x = if time_axis_values.is_some() {
+                    match series.timestamps.as_ref().and_then(|item| item.get(i)) {
+                        Some(timestamp) => (timestamp - first_timestamp) as f32 / SECONDS_OF_DAY,
+                        None => continue,
+                    }
+                } else {
+                    (i + series.start_index) as f32
+                };
+                data.push((x, value + stack_bases_list[index][i]));
+            }
+            let to_point = |x: f32, y: f32| {
+                let x = if let Some(time_axis_values) = time_axis_values {
+                    let timestamp = first_timestamp + (x * SECONDS_OF_DAY).round() as i64;
+                    time_axis_values.get_offset_width(timestamp, width)
+                } else if x_boundary_gap {
+                    unit_width * x + unit_width / 2.0
+                } else {
+                    unit_width * x
+                };
+                Point {
+                    x,
+                    y: y_axis_values.get_offset_height(y, max_height),
+                }
+            };
+            label_index += render_trend_lines(
+                &mut c,
+                TrendLineParams {
+                    trend_lines: &series.trend_lines,
+                    data,
+                    color: get_color(&self.series_colors, series.index.unwrap_or(index)),
+                    font_family: &self.font_family,
+                    font_size: self.series_label_font_size,
+                    height: max_height,
+                },
+                label_index,
+                to_point,
+            );
+        }
+    }
     /// Converts line chart to svg.
     pub fn svg(&self) -> canvas::Result<String> {
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.animation = self.animation.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
 
         self.render_background(c.child(Box::default()));
         let mut x_axis_height = self.x_axis_height;
@@ -227,7 +347,7 @@ impl LineChart {
             title_height
         };
 
-        let (left_y_axis_values, mut left_y_axis_width) = self.get_y_axis_values(0);
+        let (left_y_axis_values, mut left_y_axis_width) = self.get_y_axis_values(0)?;
         if self.y_axis_hidden {
             left_y_axis_width = 0.0;
         }
@@ -240,7 +360,7 @@ impl LineChart {
         let mut right_y_axis_values = AxisValues::default();
         let mut right_y_axis_width = 0.0_f32;
         if exist_right_y_axis {
-            (right_y_axis_values, right_y_axis_width) = self.get_y_axis_values(1);
+            (right_y_axis_values, right_y_axis_width) = self.get_y_axis_values(1)?;
         }
 
         let axis_height = c.height() - x_axis_height - axis_top;
@@ -286,17 +406,27 @@ impl LineChart {
         }
 
         // x axis
+        let time_axis_values = if self.x_axis_time.unwrap_or_default() {
+            Some(get_series_time_axis_values(
+                &self.series_list,
+                self.x_axis_time_format.clone(),
+                false,
+            ))
+        } else {
+            None
+        };
         if !self.x_axis_hidden {
-            self.render_x_axis(
-                c.child(Box {
-                    top: c.height() - x_axis_height,
-                    left: left_y_axis_width,
-                    right: right_y_axis_width,
-                    ..Default::default()
-                }),
-                self.x_axis_data.clone(),
-                axis_width,
-            );
+            let x_axis_canvas = c.child(Box {
+                top: c.height() - x_axis_height,
+                left: left_y_axis_width,
+                right: right_y_axis_width,
+                ..Default::default()
+            });
+            if let Some(ref time_axis_values) = time_axis_values {
+                self.render_time_x_axis(x_axis_canvas, time_axis_values, axis_width);
+            } else {
+                self.render_x_axis(x_axis_canvas, self.x_axis_data.clone(), axis_width);
+            }
         }
 
         // line point
@@ -314,6 +444,8 @@ impl LineChart {
             max_height,
             axis_height,
             self.x_axis_data.len(),
+            &self.x_axis_data,
+            time_axis_values.as_ref(),
         );
         self.render_series_label(
             c.child(Box {
@@ -334,6 +466,16 @@ impl LineChart {
             &y_axis_values_list,
             max_height,
         );
+        self.render_trend_line(
+            c.child(Box {
+                left: left_y_axis_width,
+                right: right_y_axis_width,
+                ..Default::default()
+            }),
+            &y_axis_values_list,
+            max_height,
+            time_axis_values.as_ref(),
+        );
         c.svg()
     }
 }
@@ -341,7 +483,10 @@ impl LineChart {
 #[cfg(test)]
 mod tests {
     use super::LineChart;
-    use crate::{Align, Box, MarkLine, MarkLineCategory, MarkPoint, MarkPointCategory, NIL_VALUE};
+    use crate::{
+        Align, Box, MarkLine, MarkLineCategory, MarkPoint, MarkPointCategory, TrendLine,
+        TrendLineCategory, NIL_VALUE,
+    };
     use pretty_assertions::assert_eq;
     #[test]
     fn line_chart_basic() {
@@ -772,4 +917,168 @@ mod tests {
             line_chart.svg().unwrap()
         );
     }
+    #[test]
+    fn line_chart_stack() {
+        let mut line_chart = LineChart::new(
+            vec![
+                (
+                    "Email",
+                    vec![120.0, 132.0, 101.0, 134.0, 90.0, 230.0, 210.0],
+                )
+                    .into(),
+                (
+                    "Union Ads",
+                    vec![220.0, 182.0, 191.0, 234.0, 290.0, 330.0, 310.0],
+                )
+                    .into(),
+                (
+                    "Direct",
+                    vec![150.0, 232.0, 201.0, 154.0, 190.0, 330.0, 410.0],
+                )
+                    .into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+                "Sat".to_string(),
+                "Sun".to_string(),
+            ],
+        );
+        for series in line_chart.series_list.iter_mut() {
+            series.stack = Some("total".to_string());
+        }
+        line_chart.series_fill = true;
+        line_chart.title_text = "Stacked Area Chart".to_string();
+        line_chart.legend_margin = Some(Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        line_chart.series_list[2].label_show = true;
+        assert_eq!(
+            include_str!("../../asset/line_chart/stack.svg"),
+            line_chart.svg().unwrap()
+        );
+    }
+    #[test]
+    fn line_chart_time_axis() {
+        let mut line_chart = LineChart::new(
+            vec![
+                ("Email", vec![120.0, 132.0, 101.0, 134.0, 90.0, 230.0]).into(),
+                ("Union Ads", vec![220.0, 182.0, 191.0, 234.0, 290.0, 330.0]).into(),
+            ],
+            vec![],
+        );
+        // irregular samples of 2023-11-14
+        let timestamps = vec![
+            1699948800, 1699956000, 1699963200, 1699981200, 1699992000, 1700013600,
+        ];
+        line_chart.series_list[0].timestamps = Some(timestamps.clone());
+        line_chart.series_list[1].timestamps = Some(timestamps);
+        line_chart.x_axis_time = Some(true);
+        line_chart.title_text = "Time Axis".to_string();
+        line_chart.legend_margin = Some(Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            include_str!("../../asset/line_chart/time_axis.svg"),
+            line_chart.svg().unwrap()
+        );
+    }
+    #[test]
+    fn line_chart_log_axis() {
+        let mut line_chart = LineChart::new(
+            vec![
+                ("p50", vec![3.0, 5.0, 4.0, 6.0, 8.0, 5.0, 4.0]).into(),
+                ("p99", vec![45.0, 120.0, 80.0, 350.0, 2100.0, 900.0, 60.0]).into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+                "Sat".to_string(),
+                "Sun".to_string(),
+            ],
+        );
+        line_chart.title_text = "Latency".to_string();
+        line_chart.legend_margin = Some(Box {
+            top: 35.0,
+            bottom: 10.0,
+            ..Default::default()
+        });
+        line_chart.y_axis_configs[0].axis_log_base = Some(10.0);
+        line_chart.y_axis_configs[0].axis_formatter = Some("{c} ms".to_string());
+        assert_eq!(
+            include_str!("../../asset/line_chart/log_axis.svg"),
+            line_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn line_chart_trend_line() {
+        let mut line_chart = LineChart::new(
+            vec![
+                (
+                    "Revenue",
+                    vec![
+                        32.0, 35.0, 41.0, 38.0, 47.0, 52.0, 58.0, 55.0, 67.0, 74.0, 79.0, 91.0,
+                    ],
+                )
+                    .into(),
+                (
+                    "Cost",
+                    vec![
+                        40.0, 28.0, 35.0, 44.0, 30.0, 38.0, 47.0, 36.0, 42.0, 51.0, 40.0, 46.0,
+                    ],
+                )
+                    .into(),
+            ],
+            vec![
+                "Jan".to_string(),
+                "Feb".to_string(),
+                "Mar".to_string(),
+                "Apr".to_string(),
+                "May".to_string(),
+                "Jun".to_string(),
+                "Jul".to_string(),
+                "Aug".to_string(),
+                "Sep".to_string(),
+                "Oct".to_string(),
+                "Nov".to_string(),
+                "Dec".to_string(),
+            ],
+        );
+        line_chart.title_text = "Revenue and cost".to_string();
+        line_chart.title_align = Align::Left;
+        line_chart.legend_align = Align::Right;
+        line_chart.margin.right = 20.0;
+        line_chart.y_axis_configs[0].axis_min = Some(0.0);
+        line_chart.y_axis_configs[0].axis_max = Some(100.0);
+        line_chart.y_axis_configs[0].axis_split_number = 5;
+        line_chart.series_list[0].trend_lines = vec![TrendLine {
+            category: TrendLineCategory::Polynomial(2),
+            label_show: true,
+        }];
+        line_chart.series_list[1].trend_lines = vec![
+            TrendLine {
+                category: TrendLineCategory::MovingAverage(3),
+                label_show: true,
+            },
+            TrendLine {
+                label_show: true,
+                ..Default::default()
+            },
+        ];
+        assert_eq!(
+            include_str!("../../asset/line_chart/trend_line.svg"),
+            line_chart.svg().unwrap()
+        );
+    }
 }
@@ -3,33 +3,297 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use super::canvas;
-use super::component::generate_svg;
+use super::canvas::collect_canvas_paints;
 use super::component::Rect;
-use super::params::{get_color_from_value, get_f32_from_value, get_margin_from_value};
+use super::component::{generate_paint_defs, generate_svg};
+use super::params::{
+    get_color_from_value, get_f32_from_value, get_f32_slice_from_value, get_margin_from_value,
+    get_usize_from_value,
+};
 use super::{
-    BarChart, CandlestickChart, CanvasResult, HorizontalBarChart, LineChart, PieChart, RadarChart,
-    ScatterChart, TableChart,
+    BarChart, BoxPlotChart, CandlestickChart, CanvasResult, FunnelChart, GaugeChart, HeatmapChart,
+    HistogramChart, HorizontalBarChart, LineChart, PieChart, RadarChart, SankeyChart, ScatterChart,
+    TableChart, TreemapChart,
 };
-use super::{Box, Color};
+use super::{Box, Color, Paint};
 use substring::Substring;
 
 pub enum ChildChart {
     Bar(BarChart, Option<(f32, f32)>),
+    BoxPlot(BoxPlotChart, Option<(f32, f32)>),
     Candlestick(CandlestickChart, Option<(f32, f32)>),
+    Funnel(FunnelChart, Option<(f32, f32)>),
+    Gauge(GaugeChart, Option<(f32, f32)>),
+    Heatmap(HeatmapChart, Option<(f32, f32)>),
+    Histogram(HistogramChart, Option<(f32, f32)>),
     HorizontalBar(HorizontalBarChart, Option<(f32, f32)>),
     Line(LineChart, Option<(f32, f32)>),
     Pie(PieChart, Option<(f32, f32)>),
     Radar(RadarChart, Option<(f32, f32)>),
+    Sankey(SankeyChart, Option<(f32, f32)>),
     Scatter(ScatterChart, Option<(f32, f32)>),
     Table(TableChart, Option<(f32, f32)>),
+    Treemap(TreemapChart, Option<(f32, f32)>),
+}
+
+impl ChildChart {
+    /// Gets the width and height of child chart.
+    fn get_size(&self) -> (f32, f32) {
+        match self {
+            ChildChart::Bar(c, _) => (c.width, c.height),
+            ChildChart::BoxPlot(c, _) => (c.width, c.height),
+            ChildChart::Candlestick(c, _) => (c.width, c.height),
+            ChildChart::Funnel(c, _) => (c.width, c.height),
+            ChildChart::Gauge(c, _) => (c.width, c.height),
+            ChildChart::Heatmap(c, _) => (c.width, c.height),
+            ChildChart::Histogram(c, _) => (c.width, c.height),
+            ChildChart::HorizontalBar(c, _) => (c.width, c.height),
+            ChildChart::Line(c, _) => (c.width, c.height),
+            ChildChart::Pie(c, _) => (c.width, c.height),
+            ChildChart::Radar(c, _) => (c.width, c.height),
+            ChildChart::Sankey(c, _) => (c.width, c.height),
+            ChildChart::Scatter(c, _) => (c.width, c.height),
+            ChildChart::Table(c, _) => (c.width, c.height),
+            ChildChart::Treemap(c, _) => (c.width, c.height),
+        }
+    }
+    /// Sets the position and size of child chart, then converts it to svg.
+    fn svg_with_rect(&mut self, x: f32, y: f32, width: f32, height: f32) -> CanvasResult<String> {
+        match self {
+            ChildChart::Bar(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::BoxPlot(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Candlestick(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Funnel(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Gauge(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Heatmap(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Histogram(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::HorizontalBar(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Line(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Pie(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Radar(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Sankey(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Scatter(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Table(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+            ChildChart::Treemap(c, _) => {
+                (c.x, c.y, c.width, c.height) = (x, y, width, height);
+                c.svg()
+            }
+        }
+    }
+}
+
+/// The cell of grid layout, the child chart is placed at the next free cell
+/// if the row or column is none.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GridCell {
+    pub row: Option<usize>,
+    pub column: Option<usize>,
+    // the span is 1 if it's zero
+    pub row_span: usize,
+    pub column_span: usize,
+}
+
+/// The grid layout of multi chart, the size of child chart is derived from
+/// the size of its cells.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GridLayout {
+    // the width of grid, it will be 600 * column count if it's zero
+    pub width: f32,
+    // the height of grid, it will be 400 * row count if it's zero
+    pub height: f32,
+    // the fractional widths of columns, e.g. [1.0, 2.0]
+    pub columns: Vec<f32>,
+    // the fractional heights of rows, the count of rows depends on the cells if it's empty
+    pub rows: Vec<f32>,
+    // the cells of child charts by index
+    pub cells: Vec<GridCell>,
+}
+
+/// Gets the offsets and sizes of the fractional tracks,
+/// the fractions should be greater than 0.
+fn get_grid_tracks(fractions: &[f32], size: f32, gap: f32) -> canvas::Result<Vec<(f32, f32)>> {
+    if let Some(fraction) = fractions.iter().find(|item| item.is_nan() || **item <= 0.0) {
+        return Err(canvas::Error::Params {
+            message: format!("the fraction of grid should be greater than 0, got {fraction}"),
+        });
+    }
+    let total: f32 = fractions.iter().sum();
+    let unit = (size - gap * (fractions.len() - 1) as f32) / total;
+    let mut offset = 0.0;
+    let mut tracks = vec![];
+    for fraction in fractions.iter() {
+        let value = unit * fraction;
+        tracks.push((offset, value));
+        offset += value + gap;
+    }
+    Ok(tracks)
+}
+
+impl GridLayout {
+    /// Places the child charts, it returns the (row, column, row span, column span) of each chart.
+    fn place(&self, count: usize) -> canvas::Result<Vec<(usize, usize, usize, usize)>> {
+        let column_count = self.columns.len().max(1);
+        let row_limit = if self.rows.is_empty() {
+            usize::MAX
+        } else {
+            self.rows.len()
+        };
+        let mut occupied: Vec<Vec<bool>> = vec![];
+        let is_free = |occupied: &[Vec<bool>],
+                       row: usize,
+                       column: usize,
+                       row_span: usize,
+                       column_span: usize| {
+            if column + column_span > column_count || row + row_span > row_limit {
+                return false;
+            }
+            !occupied
+                .iter()
+                .skip(row)
+                .take(row_span)
+                .any(|item| item.iter().skip(column).take(column_span).any(|v| *v))
+        };
+        let fill = |occupied: &mut Vec<Vec<bool>>,
+                    row: usize,
+                    column: usize,
+                    row_span: usize,
+                    column_span: usize| {
+            while occupied.len() < row + row_span {
+                occupied.push(vec![false; column_count]);
+            }
+            for item in occupied.iter_mut().skip(row).take(row_span) {
+                for v in item.iter_mut().skip(column).take(column_span) {
+                    *v = true;
+                }
+            }
+        };
+        let mut places = vec![None; count];
+        let default_cell = GridCell::default();
+        // the cells with row and column are placed first
+        for (index, place) in places.iter_mut().enumerate() {
+            let cell = self.cells.get(index).unwrap_or(&default_cell);
+            if let (Some(row), Some(column)) = (cell.row, cell.column) {
+                let row_span = cell.row_span.max(1);
+                let column_span = cell.column_span.max(1);
+                if row + row_span > row_limit {
+                    return Err(canvas::Error::Params {
+                        message: format!("the row of child chart {index} is out of grid"),
+                    });
+                }
+                if column + column_span > column_count {
+                    return Err(canvas::Error::Params {
+                        message: format!("the column of child chart {index} is out of grid"),
+                    });
+                }
+                if !is_free(&occupied, row, column, row_span, column_span) {
+                    return Err(canvas::Error::Params {
+                        message: format!("the cell of child chart {index} overlaps other chart"),
+                    });
+                }
+                fill(&mut occupied, row, column, row_span, column_span);
+                *place = Some((row, column, row_span, column_span));
+            }
+        }
+        for (index, place) in places.iter_mut().enumerate() {
+            if place.is_some() {
+                continue;
+            }
+            let cell = self.cells.get(index).unwrap_or(&default_cell);
+            let row_span = cell.row_span.max(1);
+            let column_span = cell.column_span.max(1);
+            if cell.column.unwrap_or_default() + column_span > column_count {
+                return Err(canvas::Error::Params {
+                    message: format!("the column of child chart {index} is out of grid"),
+                });
+            }
+            let mut row = cell.row.unwrap_or_default();
+            loop {
+                if row + row_span > row_limit {
+                    return Err(canvas::Error::Params {
+                        message: format!("there is no free cell for child chart {index}"),
+                    });
+                }
+                let columns: Vec<usize> = if let Some(column) = cell.column {
+                    vec![column]
+                } else {
+                    (0..column_count).collect()
+                };
+                if let Some(column) = columns
+                    .into_iter()
+                    .find(|column| is_free(&occupied, row, *column, row_span, column_span))
+                {
+                    fill(&mut occupied, row, column, row_span, column_span);
+                    *place = Some((row, column, row_span, column_span));
+                    break;
+                }
+                row += 1;
+            }
+        }
+        Ok(places.into_iter().flatten().collect())
+    }
 }
+
 #[derive(Default)]
 pub struct MultiChart {
     pub charts: Vec<ChildChart>,
     pub gap: f32,
     pub margin: Box,
     pub background_color: Option<Color>,
+    // the grid layout of child charts, they are stacked vertically if it's none
+    pub grid: Option<GridLayout>,
+}
+// the rect of child chart in grid layout, the row end is exclusive
+struct GridRect {
+    row: usize,
+    row_end: usize,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
 }
+
 struct ChildChartResult {
     svg: String,
     right: f32,
@@ -54,6 +318,15 @@ impl MultiChart {
         if let Some(background_color) = get_color_from_value(&value, "background_color") {
             multi_chart.background_color = Some(background_color);
         }
+        if let Some(grid) = value.get("grid") {
+            multi_chart.grid = Some(GridLayout {
+                width: get_f32_from_value(grid, "width").unwrap_or_default(),
+                height: get_f32_from_value(grid, "height").unwrap_or_default(),
+                columns: get_f32_slice_from_value(grid, "columns").unwrap_or_default(),
+                rows: get_f32_slice_from_value(grid, "rows").unwrap_or_default(),
+                ..Default::default()
+            });
+        }
         if let Some(child_charts) = value.get("child_charts") {
             if let Some(values) = child_charts.as_array() {
                 for item in values.iter() {
@@ -77,6 +350,15 @@ impl MultiChart {
                     if exists_position {
                         position = Some((x, y));
                     }
+                    if let Some(ref mut grid) = multi_chart.grid {
+                        grid.cells.push(GridCell {
+                            row: get_usize_from_value(item, "row"),
+                            column: get_usize_from_value(item, "column"),
+                            row_span: get_usize_from_value(item, "row_span").unwrap_or_default(),
+                            column_span: get_usize_from_value(item, "column_span")
+                                .unwrap_or_default(),
+                        });
+                    }
 
                     // 由json转换，因此不会出错
                     let mut str = serde_json::to_string(item).unwrap();
@@ -107,14 +389,42 @@ impl MultiChart {
                             let chart = TableChart::from_json(&str)?;
                             multi_chart.add(ChildChart::Table(chart, position));
                         }
+                        "treemap" => {
+                            let chart = TreemapChart::from_json(&str)?;
+                            multi_chart.add(ChildChart::Treemap(chart, position));
+                        }
+                        "sankey" => {
+                            let chart = SankeyChart::from_json(&str)?;
+                            multi_chart.add(ChildChart::Sankey(chart, position));
+                        }
                         "scatter" => {
                             let chart = ScatterChart::from_json(&str)?;
                             multi_chart.add(ChildChart::Scatter(chart, position));
                         }
+                        "box_plot" => {
+                            let chart = BoxPlotChart::from_json(&str)?;
+                            multi_chart.add(ChildChart::BoxPlot(chart, position));
+                        }
                         "candlestick" => {
                             let chart = CandlestickChart::from_json(&str)?;
                             multi_chart.add(ChildChart::Candlestick(chart, position));
                         }
+                        "funnel" => {
+                            let chart = FunnelChart::from_json(&str)?;
+                            multi_chart.add(ChildChart::Funnel(chart, position));
+                        }
+                        "gauge" => {
+                            let chart = GaugeChart::from_json(&str)?;
+                            multi_chart.add(ChildChart::Gauge(chart, position));
+                        }
+                        "heatmap" => {
+                            let chart = HeatmapChart::from_json(&str)?;
+                            multi_chart.add(ChildChart::Heatmap(chart, position));
+                        }
+                        "histogram" => {
+                            let chart = HistogramChart::from_json(&str)?;
+                            multi_chart.add(ChildChart::Histogram(chart, position));
+                        }
                         _ => {
                             let chart = BarChart::from_json(&str)?;
                             multi_chart.add(ChildChart::Bar(chart, position));
@@ -138,8 +448,187 @@ impl MultiChart {
     pub fn add(&mut self, c: ChildChart) {
         self.charts.push(c);
     }
+    /// Gets the rects of child charts in grid layout and the size of grid.
+    fn get_grid_rects(&self, grid: &GridLayout) -> CanvasResult<(Vec<GridRect>, f32, f32)> {
+        let places = grid.place(self.charts.len())?;
+        let mut columns = grid.columns.clone();
+        if columns.is_empty() {
+            columns.push(1.0);
+        }
+        let mut rows = grid.rows.clone();
+        if rows.is_empty() {
+            let row_count = places
+                .iter()
+                .map(|(row, _, row_span, _)| row + row_span)
+                .max()
+                .unwrap_or(1);
+            rows = vec![1.0; row_count];
+        }
+        let mut width = grid.width;
+        if width <= 0.0 {
+            width = 600.0 * columns.len() as f32;
+        }
+        let mut height = grid.height;
+        if height <= 0.0 {
+            height = 400.0 * rows.len() as f32;
+        }
+        let column_tracks = get_grid_tracks(&columns, width, self.gap)?;
+        let row_tracks = get_grid_tracks(&rows, height, self.gap)?;
+
+        let rects = places
+            .into_iter()
+            .map(|(row, column, row_span, column_span)| {
+                let (x, _) = column_tracks[column];
+                let (y, _) = row_tracks[row];
+                let (last_x, last_width) = column_tracks[column + column_span - 1];
+                let (last_y, last_height) = row_tracks[row + row_span - 1];
+                GridRect {
+                    row,
+                    row_end: row + row_span,
+                    x,
+                    y,
+                    width: last_x + last_width - x,
+                    height: last_y + last_height - y,
+                }
+            })
+            .collect();
+        Ok((rects, width, height))
+    }
+    /// Generates the svg of width and height, the background is added if it's set.
+    /// The defs of paints are written once for all child charts.
+    fn generate_page_svg(
+        &self,
+        width: f32,
+        height: f32,
+        arr: Vec<String>,
+        paints: &[Paint],
+    ) -> String {
+        let mut arr = arr;
+        if !paints.is_empty() {
+            arr.insert(0, generate_paint_defs(paints));
+        }
+        if let Some(background_color) = self.background_color {
+            arr.insert(
+                0,
+                Rect {
+                    fill: Some(background_color),
+                    left: 0.0,
+                    top: 0.0,
+                    width,
+                    height,
+                    ..Default::default()
+                }
+                .svg(),
+            );
+        }
+        generate_svg(width, height, 0.0, 0.0, arr.join("\n"))
+    }
+    /// Converts the chart to svg with grid layout.
+    fn grid_svg(&mut self, grid: GridLayout) -> CanvasResult<String> {
+        let (rects, width, height) = self.get_grid_rects(&grid)?;
+        let margin = self.margin.clone();
+        let (arr, paints) = collect_canvas_paints(|| -> CanvasResult<Vec<String>> {
+            let mut arr = vec![];
+            for (item, rect) in self.charts.iter_mut().zip(rects) {
+                arr.push(item.svg_with_rect(
+                    margin.left + rect.x,
+                    margin.top + rect.y,
+                    rect.width,
+                    rect.height,
+                )?);
+            }
+            Ok(arr)
+        });
+        let x = self.margin.left + width + self.margin.right;
+        let y = self.margin.top + height + self.margin.bottom;
+        Ok(self.generate_page_svg(x, y, arr?, &paints))
+    }
+    /// Converts the rows of grid layout to pages, the rows joined by
+    /// row span are kept in the same page.
+    fn grid_page_svgs(&mut self, grid: GridLayout) -> CanvasResult<Vec<String>> {
+        let (rects, width, _) = self.get_grid_rects(&grid)?;
+        // the row ranges of pages
+        let mut bands: Vec<(usize, usize)> = vec![];
+        let mut row_ranges: Vec<(usize, usize)> =
+            rects.iter().map(|rect| (rect.row, rect.row_end)).collect();
+        row_ranges.sort();
+        for (start, end) in row_ranges {
+            match bands.last_mut() {
+                Some(band) if start < band.1 => band.1 = band.1.max(end),
+                _ => bands.push((start, end)),
+            }
+        }
+        let mut pages = vec![];
+        for (start, end) in bands {
+            let band_rects: Vec<(usize, &GridRect)> = rects
+                .iter()
+                .enumerate()
+                .filter(|(_, rect)| rect.row >= start && rect.row_end <= end)
+                .collect();
+            let top = band_rects
+                .iter()
+                .map(|(_, rect)| rect.y)
+                .fold(f32::MAX, f32::min);
+            let bottom = band_rects
+                .iter()
+                .map(|(_, rect)| rect.y + rect.height)
+                .fold(f32::MIN, f32::max);
+            let margin = self.margin.clone();
+            let (arr, paints) = collect_canvas_paints(|| -> CanvasResult<Vec<String>> {
+                let mut arr = vec![];
+                for (index, rect) in band_rects {
+                    arr.push(self.charts[index].svg_with_rect(
+                        margin.left + rect.x,
+                        margin.top + rect.y - top,
+                        rect.width,
+                        rect.height,
+                    )?);
+                }
+                Ok(arr)
+            });
+            let x = self.margin.left + width + self.margin.right;
+            let y = self.margin.top + bottom - top + self.margin.bottom;
+            pages.push(self.generate_page_svg(x, y, arr?, &paints));
+        }
+        Ok(pages)
+    }
+    /// Converts each child chart to a standalone svg with the margin
+    /// and background color of multi chart, it can be used as a page.
+    /// The charts in the same rows of grid layout are kept in one page.
+    pub fn page_svgs(&mut self) -> CanvasResult<Vec<String>> {
+        if let Some(grid) = self.grid.clone() {
+            return self.grid_page_svgs(grid);
+        }
+        let mut pages = vec![];
+        for index in 0..self.charts.len() {
+            let (width, height) = self.charts[index].get_size();
+            let (left, top) = (self.margin.left, self.margin.top);
+            let (svg, paints) = collect_canvas_paints(|| {
+                self.charts[index].svg_with_rect(left, top, width, height)
+            });
+            let x = self.margin.left + width + self.margin.right;
+            let y = self.margin.top + height + self.margin.bottom;
+            pages.push(self.generate_page_svg(x, y, vec![svg?], &paints));
+        }
+        Ok(pages)
+    }
+    /// Converts the chart to pdf, each child chart is written to a page.
+    #[cfg(feature = "pdf-encoder")]
+    pub fn pdf(&mut self) -> super::pdf::Result<Vec<u8>> {
+        super::pdf::svgs_to_pdf(&self.page_svgs()?)
+    }
     /// Converts the chart to svg.
     pub fn svg(&mut self) -> CanvasResult<String> {
+        if let Some(grid) = self.grid.clone() {
+            return self.grid_svg(grid);
+        }
+        let (result, paints) = collect_canvas_paints(|| self.child_svgs());
+        let (arr, x, y) = result?;
+        Ok(self.generate_page_svg(x, y, arr, &paints))
+    }
+    /// Converts the child charts to svg one by one from top to bottom,
+    /// the svg list and the size of multi chart are returned.
+    fn child_svgs(&mut self) -> CanvasResult<(Vec<String>, f32, f32)> {
         let mut arr = vec![];
         let mut y = 0.0;
         let mut x = 0.0;
@@ -170,6 +659,28 @@ impl MultiChart {
                         bottom: c.y + c.height,
                     }
                 }
+                ChildChart::BoxPlot(c, position) => {
+                    c.y = y;
+                    if let Some((x, y)) = position {
+                        y.clone_into(&mut c.y);
+                        x.clone_into(&mut c.x);
+                    } else if y == 0.0 {
+                        c.y = margin_top;
+                    } else {
+                        // not the first chart and not set position
+                        y += self.gap;
+                        c.y = y;
+                    }
+                    if position.is_none() {
+                        c.x = c.x.max(margin_left);
+                    }
+
+                    ChildChartResult {
+                        svg: c.svg()?,
+                        right: c.x + c.width,
+                        bottom: c.y + c.height,
+                    }
+                }
                 ChildChart::Candlestick(c, position) => {
                     c.y = y;
                     if let Some((x, y)) = position {
@@ -192,6 +703,90 @@ impl MultiChart {
                         bottom: c.y + c.height,
                     }
                 }
+                ChildChart::Funnel(c, position) => {
+                    c.y = y;
+                    if let Some((x, y)) = position {
+                        y.clone_into(&mut c.y);
+                        x.clone_into(&mut c.x);
+                    } else if y == 0.0 {
+                        c.y = margin_top;
+                    } else {
+                        y += self.gap;
+                        c.y = y;
+                    }
+                    if position.is_none() {
+                        c.x = c.x.max(margin_left);
+                    }
+
+                    ChildChartResult {
+                        svg: c.svg()?,
+                        right: c.x + c.width,
+                        bottom: c.y + c.height,
+                    }
+                }
+                ChildChart::Gauge(c, position) => {
+                    c.y = y;
+                    if let Some((x, y)) = position {
+                        y.clone_into(&mut c.y);
+                        x.clone_into(&mut c.x);
+                    } else if y == 0.0 {
+                        c.y = margin_top;
+                    } else {
+                        y += self.gap;
+                        c.y = y;
+                    }
+                    if position.is_none() {
+                        c.x = c.x.max(margin_left);
+                    }
+
+                    ChildChartResult {
+                        svg: c.svg()?,
+                        right: c.x + c.width,
+                        bottom: c.y + c.height,
+                    }
+                }
+                ChildChart::Heatmap(c, position) => {
+                    c.y = y;
+                    if let Some((x, y)) = position {
+                        y.clone_into(&mut c.y);
+                        x.clone_into(&mut c.x);
+                    } else if y == 0.0 {
+                        c.y = margin_top;
+                    } else {
+                        y += self.gap;
+                        c.y = y;
+                    }
+                    if position.is_none() {
+                        c.x = c.x.max(margin_left);
+                    }
+
+                    ChildChartResult {
+                        svg: c.svg()?,
+                        right: c.x + c.width,
+                        bottom: c.y + c.height,
+                    }
+                }
+                ChildChart::Histogram(c, position) => {
+                    c.y = y;
+                    if let Some((x, y)) = position {
+                        y.clone_into(&mut c.y);
+                        x.clone_into(&mut c.x);
+                    } else if y == 0.0 {
+                        c.y = margin_top;
+                    } else {
+                        y += self.gap;
+                        c.y = y;
+                    }
+                    if position.is_none() {
+                        c.x = c.x.max(margin_left);
+                    }
+
+                    ChildChartResult {
+                        svg: c.svg()?,
+                        right: c.x + c.width,
+                        bottom: c.y + c.height,
+                    }
+                }
                 ChildChart::HorizontalBar(c, position) => {
                     c.y = y;
                     if let Some((x, y)) = position {
@@ -276,6 +871,27 @@ impl MultiChart {
                         bottom: c.y + c.height,
                     }
                 }
+                ChildChart::Sankey(c, position) => {
+                    c.y = y;
+                    if let Some((x, y)) = position {
+                        y.clone_into(&mut c.y);
+                        x.clone_into(&mut c.x);
+                    } else if y == 0.0 {
+                        c.y = margin_top;
+                    } else {
+                        y += self.gap;
+                        c.y = y;
+                    }
+                    if position.is_none() {
+                        c.x = c.x.max(margin_left);
+                    }
+
+                    ChildChartResult {
+                        svg: c.svg()?,
+                        right: c.x + c.width,
+                        bottom: c.y + c.height,
+                    }
+                }
                 ChildChart::Scatter(c, position) => {
                     c.y = y;
                     if let Some((x, y)) = position {
@@ -319,6 +935,27 @@ impl MultiChart {
                         bottom: c.y + c.height,
                     }
                 }
+                ChildChart::Treemap(c, position) => {
+                    c.y = y;
+                    if let Some((x, y)) = position {
+                        y.clone_into(&mut c.y);
+                        x.clone_into(&mut c.x);
+                    } else if y == 0.0 {
+                        c.y = margin_top;
+                    } else {
+                        y += self.gap;
+                        c.y = y;
+                    }
+                    if position.is_none() {
+                        c.x = c.x.max(margin_left);
+                    }
+
+                    ChildChartResult {
+                        svg: c.svg()?,
+                        right: c.x + c.width,
+                        bottom: c.y + c.height,
+                    }
+                }
             };
             if result.bottom > y {
                 y = result.bottom;
@@ -331,28 +968,13 @@ impl MultiChart {
         x += self.margin.right;
         y += self.margin.bottom;
 
-        if let Some(background_color) = self.background_color {
-            arr.insert(
-                0,
-                Rect {
-                    fill: Some(background_color),
-                    left: 0.0,
-                    top: 0.0,
-                    width: x,
-                    height: y,
-                    ..Default::default()
-                }
-                .svg(),
-            );
-        }
-
-        Ok(generate_svg(x, y, 0.0, 0.0, arr.join("\n")))
+        Ok((arr, x, y))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ChildChart, MultiChart};
+    use super::{ChildChart, GridCell, GridLayout, MultiChart};
     use crate::{
         BarChart, CandlestickChart, HorizontalBarChart, LineChart, PieChart, RadarChart,
         ScatterChart, TableChart,
@@ -628,4 +1250,174 @@ mod tests {
             charts.svg().unwrap()
         );
     }
+
+    #[test]
+    fn multi_chart_grid() {
+        let mut charts = MultiChart::new();
+        charts.margin = (10.0).into();
+        charts.gap = 10.0;
+        charts.background_color = Some((31, 29, 29).into());
+        charts.grid = Some(GridLayout {
+            width: 900.0,
+            height: 600.0,
+            columns: vec![2.0, 1.0],
+            rows: vec![1.0, 1.0],
+            cells: vec![
+                GridCell {
+                    column_span: 2,
+                    ..Default::default()
+                },
+                GridCell::default(),
+                GridCell::default(),
+            ],
+        });
+
+        let mut bar_chart = BarChart::new_with_theme(
+            vec![
+                ("Email", vec![120.0, 132.0, 101.0, 134.0, 90.0]).into(),
+                ("Direct", vec![320.0, 332.0, 301.0, 334.0, 390.0]).into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+            ],
+            "dark",
+        );
+        bar_chart.title_text = "Bar Chart".to_string();
+        charts.add(ChildChart::Bar(bar_chart, None));
+
+        let mut line_chart = LineChart::new_with_theme(
+            vec![("Email", vec![120.0, 132.0, 101.0, 134.0, 90.0]).into()],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+                "Fri".to_string(),
+            ],
+            "dark",
+        );
+        line_chart.title_text = "Line Chart".to_string();
+        charts.add(ChildChart::Line(line_chart, None));
+
+        let mut pie_chart = PieChart::new_with_theme(
+            vec![
+                ("rose 1", vec![40.0]).into(),
+                ("rose 2", vec![38.0]).into(),
+                ("rose 3", vec![32.0]).into(),
+            ],
+            "dark",
+        );
+        pie_chart.title_text = "Pie Chart".to_string();
+        charts.add(ChildChart::Pie(pie_chart, None));
+
+        assert_eq!(
+            include_str!("../../asset/multi_chart/grid.svg"),
+            charts.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn multi_chart_grid_no_free_cell() {
+        let mut charts = MultiChart::new();
+        charts.grid = Some(GridLayout {
+            columns: vec![1.0],
+            rows: vec![1.0],
+            ..Default::default()
+        });
+        charts.add(ChildChart::Pie(
+            PieChart::new(vec![("rose 1", vec![40.0]).into()]),
+            None,
+        ));
+        charts.add(ChildChart::Pie(
+            PieChart::new(vec![("rose 2", vec![38.0]).into()]),
+            None,
+        ));
+        assert_eq!(
+            "Params is invalid: there is no free cell for child chart 1",
+            charts.svg().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn multi_chart_grid_invalid_cell() {
+        let new_charts = |grid: GridLayout| {
+            let mut charts = MultiChart::new();
+            charts.grid = Some(grid);
+            charts.add(ChildChart::Pie(
+                PieChart::new(vec![("rose 1", vec![40.0]).into()]),
+                None,
+            ));
+            charts.add(ChildChart::Pie(
+                PieChart::new(vec![("rose 2", vec![38.0]).into()]),
+                None,
+            ));
+            charts
+        };
+
+        let mut charts = new_charts(GridLayout {
+            columns: vec![1.0, 1.0],
+            cells: vec![GridCell {
+                row: Some(0),
+                column: Some(2),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        assert_eq!(
+            "Params is invalid: the column of child chart 0 is out of grid",
+            charts.svg().unwrap_err().to_string()
+        );
+
+        let mut charts = new_charts(GridLayout {
+            columns: vec![1.0, 1.0],
+            cells: vec![
+                GridCell::default(),
+                GridCell {
+                    column: Some(1),
+                    column_span: 2,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        assert_eq!(
+            "Params is invalid: the column of child chart 1 is out of grid",
+            charts.svg().unwrap_err().to_string()
+        );
+
+        let mut charts = new_charts(GridLayout {
+            columns: vec![1.0, 1.0],
+            cells: vec![
+                GridCell {
+                    row: Some(0),
+                    column: Some(0),
+                    column_span: 2,
+                    ..Default::default()
+                },
+                GridCell {
+                    row: Some(0),
+                    column: Some(1),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        assert_eq!(
+            "Params is invalid: the cell of child chart 1 overlaps other chart",
+            charts.svg().unwrap_err().to_string()
+        );
+
+        let mut charts = new_charts(GridLayout {
+            columns: vec![0.0, 0.0],
+            ..Default::default()
+        });
+        assert_eq!(
+            "Params is invalid: the fraction of grid should be greater than 0, got 0",
+            charts.svg().unwrap_err().to_string()
+        );
+    }
 }
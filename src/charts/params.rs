@@ -2,9 +2,14 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::{Align, Box, Color, LegendCategory, Series, SeriesCategory, Theme, YAxisConfig};
+use super::canvas;
+use super::{
+    get_palette, Align, Box, Color, ColorScale, Gradient, GradientStop, LegendCategory,
+    LinearGradient, RadialGradient, Series, SeriesCategory, Theme, VisualMapOption, YAxisConfig,
+};
 use crate::{
-    MarkLine, MarkLineCategory, MarkPoint, MarkPointCategory, Position, Symbol, NIL_VALUE,
+    MarkLine, MarkLineCategory, MarkPoint, MarkPointCategory, Position, Symbol, TrendLine,
+    TrendLineCategory, NIL_VALUE,
 };
 use std::sync::Arc;
 
@@ -52,6 +57,34 @@ pub(crate) fn get_usize_slice_from_value(
     None
 }
 
+/// Gets int64 slice value from serde json, the float value is accepted
+/// if it is a whole number in range, otherwise it returns an error.
+pub(crate) fn get_i64_slice_from_value(
+    value: &serde_json::Value,
+    key: &str,
+) -> canvas::Result<Option<Vec<i64>>> {
+    let Some(values) = value.get(key).and_then(|arr| arr.as_array()) else {
+        return Ok(None);
+    };
+    let mut result = vec![];
+    for item in values.iter() {
+        let v = match (item.as_i64(), item.as_f64()) {
+            (Some(v), _) => v,
+            // i64::MAX can't be represented by f64, so the upper bound is exclusive
+            (None, Some(v)) if v.fract() == 0.0 && v >= i64::MIN as f64 && v < i64::MAX as f64 => {
+                v as i64
+            }
+            _ => {
+                return Err(canvas::Error::Params {
+                    message: format!("{key} should be integers, {item} is invalid"),
+                });
+            }
+        };
+        result.push(v);
+    }
+    Ok(Some(result))
+}
+
 /// Gets float32 value from serde json.
 pub(crate) fn get_f32_from_value(value: &serde_json::Value, key: &str) -> Option<f32> {
     if let Some(value) = value.get(key) {
@@ -258,6 +291,9 @@ pub(crate) fn get_y_axis_config_from_value(t: Arc<Theme>, item: &serde_json::Val
     if let Some(axis_max) = get_f32_from_value(item, "axis_max") {
         y_config.axis_max = Some(axis_max);
     }
+    if let Some(axis_log_base) = get_f32_from_value(item, "axis_log_base") {
+        y_config.axis_log_base = Some(axis_log_base);
+    }
     y_config
 }
 
@@ -401,6 +437,31 @@ fn get_mark_points(value: &serde_json::Value, key: &str) -> Vec<MarkPoint> {
     mark_points
 }
 
+fn get_trend_lines(value: &serde_json::Value, key: &str) -> Vec<TrendLine> {
+    let mut trend_lines = vec![];
+    if let Some(arr) = value.get(key).and_then(|item| item.as_array()) {
+        for item in arr.iter() {
+            if let Some(value) = item.get("category") {
+                let category = match value.as_str().unwrap_or_default() {
+                    "polynomial" => TrendLineCategory::Polynomial(
+                        get_usize_from_value(item, "degree").unwrap_or(2),
+                    ),
+                    "exponential" => TrendLineCategory::Exponential,
+                    "moving_average" => TrendLineCategory::MovingAverage(
+                        get_usize_from_value(item, "period").unwrap_or(3),
+                    ),
+                    _ => TrendLineCategory::Linear,
+                };
+                trend_lines.push(TrendLine {
+                    category,
+                    label_show: get_bool_from_value(item, "label_show").unwrap_or_default(),
+                })
+            }
+        }
+    }
+    trend_lines
+}
+
 fn get_series_colors_from_value(
     value: &serde_json::Value,
     key: &str,
@@ -421,13 +482,114 @@ fn get_series_colors_from_value(
     None
 }
 
-fn get_series_from_value(value: &serde_json::Value) -> Option<Series> {
+/// Gets gradient value from serde json, the category is linear(default) or radial.
+pub(crate) fn get_gradient_from_value(value: &serde_json::Value, key: &str) -> Option<Gradient> {
+    let value = value.get(key)?;
+    let mut stops = vec![];
+    if let Some(arr) = value.get("stops").and_then(|item| item.as_array()) {
+        for item in arr.iter() {
+            if let Some(color) = get_color_from_value(item, "color") {
+                stops.push(GradientStop {
+                    offset: get_f32_from_value(item, "offset").unwrap_or_default(),
+                    color,
+                });
+            }
+        }
+    }
+    if stops.is_empty() {
+        return None;
+    }
+    let category = get_string_from_value(value, "category").unwrap_or_default();
+    if category.to_lowercase() == "radial" {
+        let default_value = RadialGradient::default();
+        return Some(Gradient::Radial(RadialGradient {
+            cx: get_f32_from_value(value, "cx").unwrap_or(default_value.cx),
+            cy: get_f32_from_value(value, "cy").unwrap_or(default_value.cy),
+            r: get_f32_from_value(value, "r").unwrap_or(default_value.r),
+            stops,
+        }));
+    }
+    let default_value = LinearGradient::default();
+    Some(Gradient::Linear(LinearGradient {
+        x1: get_f32_from_value(value, "x1").unwrap_or(default_value.x1),
+        y1: get_f32_from_value(value, "y1").unwrap_or(default_value.y1),
+        x2: get_f32_from_value(value, "x2").unwrap_or(default_value.x2),
+        y2: get_f32_from_value(value, "y2").unwrap_or(default_value.y2),
+        stops,
+    }))
+}
+
+/// Gets color scale value from serde json,
+/// it is the name of built-in palette or the option of scale.
+pub(crate) fn get_color_scale_from_value(
+    value: &serde_json::Value,
+    key: &str,
+) -> Option<ColorScale> {
+    get_color_scale(value.get(key)?)
+}
+
+/// Gets color scale slice value from serde json, the null item is none.
+pub(crate) fn get_color_scale_slice_from_value(
+    value: &serde_json::Value,
+    key: &str,
+) -> Option<Vec<Option<ColorScale>>> {
+    let arr = value.get(key)?.as_array()?;
+    Some(arr.iter().map(get_color_scale).collect())
+}
+
+fn get_color_scale(value: &serde_json::Value) -> Option<ColorScale> {
+    if let Some(palette) = value.as_str() {
+        return Some(ColorScale::new(get_palette(palette), 0.0, 0.0));
+    }
+    if !value.is_object() {
+        return None;
+    }
+    let colors = if let Some(colors) = get_color_slice_from_value(value, "colors") {
+        colors
+    } else {
+        get_palette(&get_string_from_value(value, "palette").unwrap_or_default())
+    };
+    Some(ColorScale {
+        colors,
+        min: get_f32_from_value(value, "min").unwrap_or_default(),
+        max: get_f32_from_value(value, "max").unwrap_or_default(),
+        mid: get_f32_from_value(value, "mid"),
+        steps: get_usize_from_value(value, "steps"),
+    })
+}
+
+/// Gets visual map option from serde json, true is the default option.
+pub(crate) fn get_visual_map_option_from_value(
+    value: &serde_json::Value,
+    key: &str,
+) -> Option<VisualMapOption> {
+    let value = value.get(key)?;
+    if let Some(show) = value.as_bool() {
+        return if show {
+            Some(VisualMapOption::default())
+        } else {
+            None
+        };
+    }
+    if !value.is_object() {
+        return None;
+    }
+    Some(VisualMapOption {
+        position: get_position_from_value(value, "position").unwrap_or_default(),
+        align: get_align_from_value(value, "align").unwrap_or_default(),
+        formatter: get_string_from_value(value, "formatter"),
+        split_number: get_usize_from_value(value, "split_number").unwrap_or_default(),
+        length: get_f32_from_value(value, "length").unwrap_or_default(),
+    })
+}
+
+fn get_series_from_value(value: &serde_json::Value) -> canvas::Result<Option<Series>> {
     let name = get_string_from_value(value, "name").unwrap_or_default();
     let data = get_f32_slice_from_value_support_nil(value, "data").unwrap_or_default();
     if data.is_empty() {
-        return None;
+        return Ok(None);
     }
-    Some(Series {
+    Ok(Some(Series {
         name,
         data,
         index: get_usize_from_value(value, "index"),
@@ -439,24 +601,31 @@ fn get_series_from_value(value: &serde_json::Value) -> Option<Series> {
         mark_points: get_mark_points(value, "mark_points"),
         colors: get_series_colors_from_value(value, "colors"),
         stroke_dash_array: get_string_from_value(value, "stroke_dash_array"),
-    })
+        stack: get_string_from_value(value, "stack"),
+        timestamps: get_i64_slice_from_value(value, "timestamps")?,
+        gradient: get_gradient_from_value(value, "gradient"),
+        pattern: get_string_from_value(value, "pattern").map(|item| item.as_str().into()),
+        trend_lines: get_trend_lines(value, "trend_lines"),
+    }))
 }
 
 /// Gets series list value from serde json.
-pub(crate) fn get_series_list_from_value(value: &serde_json::Value) -> Option<Vec<Series>> {
+pub(crate) fn get_series_list_from_value(
+    value: &serde_json::Value,
+) -> canvas::Result<Option<Vec<Series>>> {
     if let Some(data) = value.get("series_list") {
         if let Some(arr) = data.as_array() {
             let mut series_list = vec![];
             for (index, item) in arr.iter().enumerate() {
-                if let Some(mut series) = get_series_from_value(item) {
+                if let Some(mut series) = get_series_from_value(item)? {
                     if series.index.is_none() {
                         series.index = Some(index)
                     }
                     series_list.push(series);
                 }
             }
-            return Some(series_list);
+            return Ok(Some(series_list));
         }
     }
-    None
+    Ok(None)
 }
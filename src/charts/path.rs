@@ -23,6 +23,39 @@ impl fmt::Display for QuadraticBezier {
     }
 }
 
+/// The band between two vertical edges, the top and bottom edges of band
+/// are cubic bezier curves which are horizontal at both ends.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct CubicBezierBand {
+    // the top point of start edge
+    pub x1: f32,
+    pub y1: f32,
+    // the top point of end edge
+    pub x2: f32,
+    pub y2: f32,
+    // the thickness of band
+    pub width: f32,
+}
+impl fmt::Display for CubicBezierBand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let point = |x: f32, y: f32| format!("{} {}", format_float(x), format_float(y));
+        let cx = (self.x1 + self.x2) / 2.0;
+        let (y1_bottom, y2_bottom) = (self.y1 + self.width, self.y2 + self.width);
+        write!(
+            f,
+            "M{} C{}, {}, {} L{} C{}, {}, {} Z",
+            point(self.x1, self.y1),
+            point(cx, self.y1),
+            point(cx, self.y2),
+            point(self.x2, self.y2),
+            point(self.x2, y2_bottom),
+            point(cx, y2_bottom),
+            point(cx, y1_bottom),
+            point(self.x1, y1_bottom),
+        )
+    }
+}
+
 #[derive(Clone, PartialEq, Debug, Default)]
 struct ControlPoint {
     left: Option<Point>,
@@ -75,8 +108,40 @@ pub struct SmoothCurve {
     pub points: Vec<Point>,
     pub close: bool,
 }
-impl fmt::Display for SmoothCurve {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+// the bezier segment of smooth curve, it's cubic if both control points exist
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+struct CurveSegment {
+    start: Point,
+    cp1: Option<Point>,
+    cp2: Option<Point>,
+    end: Point,
+}
+
+impl CurveSegment {
+    fn get_point(&self, t: f32) -> Point {
+        let mt = 1.0 - t;
+        let (p0, p3) = (self.start, self.end);
+        if let (Some(p1), Some(p2)) = (self.cp1, self.cp2) {
+            let a = mt * mt * mt;
+            let b = 3.0 * mt * mt * t;
+            let c = 3.0 * mt * t * t;
+            let d = t * t * t;
+            return (
+                a * p0.x + b * p1.x + c * p2.x + d * p3.x,
+                a * p0.y + b * p1.y + c * p2.y + d * p3.y,
+            )
+                .into();
+        }
+        let p = self.cp1.unwrap_or(self.cp2.unwrap_or_default());
+        let a = mt * mt;
+        let b = 2.0 * mt * t;
+        let c = t * t;
+        (a * p0.x + b * p.x + c * p3.x, a * p0.y + b * p.y + c * p3.y).into()
+    }
+}
+
+impl SmoothCurve {
+    fn get_segments(&self) -> Vec<CurveSegment> {
         let tension = 0.25;
 
         let close = self.close;
@@ -100,15 +165,8 @@ impl fmt::Display for SmoothCurve {
             control_points.push(get_control_points(point, left, right, tension));
         }
 
-        let mut arr = vec![];
+        let mut segments = vec![];
         for (index, point) in self.points.iter().enumerate() {
-            if index == 0 {
-                arr.push(format!(
-                    "M{},{}",
-                    format_float(point.x),
-                    format_float(point.y)
-                ));
-            }
             let cp1 = control_points[index].right;
             let mut cp2 = None;
             if let Some(value) = control_points.get(index + 1) {
@@ -123,33 +181,67 @@ impl fmt::Display for SmoothCurve {
             if close && index == count - 1 {
                 next_point = self.points.first();
             }
-            if let Some(next_point_value) = next_point {
-                let next_point = format!(
+            if let Some(next_point) = next_point {
+                segments.push(CurveSegment {
+                    start: *point,
+                    cp1,
+                    cp2,
+                    end: *next_point,
+                });
+            }
+        }
+        segments
+    }
+    /// Gets the approximate length of smooth curve.
+    pub fn length(&self) -> f32 {
+        let steps = 20;
+        let mut length = 0.0;
+        for segment in self.get_segments().iter() {
+            let mut prev = segment.start;
+            for i in 1..=steps {
+                let p = segment.get_point(i as f32 / steps as f32);
+                length += ((p.x - prev.x).powi(2) + (p.y - prev.y).powi(2)).sqrt();
+                prev = p;
+            }
+        }
+        length
+    }
+}
+
+impl fmt::Display for SmoothCurve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut arr = vec![];
+        if let Some(point) = self.points.first() {
+            arr.push(format!(
+                "M{},{}",
+                format_float(point.x),
+                format_float(point.y)
+            ));
+        }
+        for segment in self.get_segments().iter() {
+            let next_point = format!(
+                "{} {}",
+                format_float(segment.end.x),
+                format_float(segment.end.y)
+            );
+            if let (Some(cp1_value), Some(cp2_value)) = (segment.cp1, segment.cp2) {
+                let c1 = format!(
+                    "{} {}",
+                    format_float(cp1_value.x),
+                    format_float(cp1_value.y)
+                );
+                let c2 = format!(
                     "{} {}",
-                    format_float(next_point_value.x),
-                    format_float(next_point_value.y)
+                    format_float(cp2_value.x),
+                    format_float(cp2_value.y)
                 );
-                if let Some(cp1_value) = cp1 {
-                    if let Some(cp2_value) = cp2 {
-                        let c1 = format!(
-                            "{} {}",
-                            format_float(cp1_value.x),
-                            format_float(cp1_value.y)
-                        );
-                        let c2 = format!(
-                            "{} {}",
-                            format_float(cp2_value.x),
-                            format_float(cp2_value.y)
-                        );
-                        arr.push(format!("C{}, {}, {}", c1, c2, next_point));
-                        continue;
-                    }
-                }
-                let p = cp1.unwrap_or(cp2.unwrap_or_default());
-
-                let q = format!("{} {}", format_float(p.x), format_float(p.y));
-                arr.push(format!("Q{}, {}", q, next_point));
+                arr.push(format!("C{}, {}, {}", c1, c2, next_point));
+                continue;
             }
+            let p = segment.cp1.unwrap_or(segment.cp2.unwrap_or_default());
+
+            let q = format!("{} {}", format_float(p.x), format_float(p.y));
+            arr.push(format!("Q{}, {}", q, next_point));
         }
         write!(f, "{}", arr.join(" "))
     }
@@ -157,7 +249,7 @@ impl fmt::Display for SmoothCurve {
 
 #[cfg(test)]
 mod tests {
-    use super::{QuadraticBezier, SmoothCurve};
+    use super::{CubicBezierBand, QuadraticBezier, SmoothCurve};
     use pretty_assertions::assert_eq;
     #[test]
     fn quadratic_bezier() {
@@ -171,6 +263,22 @@ mod tests {
         assert_eq!("M10 30 Q10 20, 30 10", str);
     }
 
+    #[test]
+    fn cubic_bezier_band() {
+        let str = CubicBezierBand {
+            x1: 10.0,
+            y1: 20.0,
+            x2: 110.0,
+            y2: 60.0,
+            width: 15.0,
+        }
+        .to_string();
+        assert_eq!(
+            "M10 20 C60 20, 60 60, 110 60 L110 75 C60 75, 60 35, 10 35 Z",
+            str
+        );
+    }
+
     #[test]
     fn smooth_curve() {
         let str = SmoothCurve {
@@ -199,4 +307,25 @@ mod tests {
         .to_string();
         assert_eq!("M10,10 C6.2 15.1, 17.2 40.1, 20 50 C22.2 57.6, 28.1 81.9, 30 80 C33.1 76.9, 36.5 42.2, 40 30 C41.5 24.7, 52.7 11.8, 50 10 C45.2 6.8, 13.7 5.1, 10 10", str);
     }
+
+    #[test]
+    fn smooth_curve_length() {
+        let curve = SmoothCurve {
+            points: vec![(0.0, 0.0).into(), (30.0, 40.0).into()],
+            close: false,
+        };
+        assert_eq!("50", format!("{:.0}", curve.length()));
+
+        let curve = SmoothCurve {
+            points: vec![
+                (10.0, 10.0).into(),
+                (20.0, 50.0).into(),
+                (30.0, 80.0).into(),
+                (40.0, 30.0).into(),
+                (50.0, 10.0).into(),
+            ],
+            close: false,
+        };
+        assert_eq!("147", format!("{:.0}", curve.length()));
+    }
 }
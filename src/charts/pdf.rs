@@ -0,0 +1,1162 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use resvg::usvg;
+use snafu::{ResultExt, Snafu};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::Write;
+use usvg::tiny_skia_path::{PathSegment, Transform};
+use usvg::{fontdb, Node, Paint};
+
+use super::canvas;
+use super::font::get_or_init_fontdb;
+
+// the bounding box of soft mask, it covers the whole page
+static SOFT_MASK_BBOX: &str = "-100000 -100000 100000 100000";
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Error to parse: {source}"))]
+    Parse { source: usvg::Error },
+    #[snafu(display("Error to compress: {source}"))]
+    Compress { source: std::io::Error },
+    #[snafu(display("Error font: {message}"))]
+    Font { message: String },
+    #[snafu(display("Error to render chart: {source}"))]
+    Chart { source: canvas::Error },
+    #[snafu(display("Error unsupported: {message}"))]
+    Unsupported { message: String },
+}
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl From<canvas::Error> for Error {
+    fn from(source: canvas::Error) -> Self {
+        Error::Chart { source }
+    }
+}
+
+// the font resources of pdf, the glyphs are written with their glyph id
+struct PdfFont {
+    name: String,
+    id: fontdb::ID,
+    data: Vec<u8>,
+    // the outlines are cff, otherwise they are truetype
+    cff: bool,
+    metrics: fontdue::Font,
+    // glyph id -> text
+    glyphs: BTreeMap<u16, String>,
+}
+
+// the paint of pdf, the gradient is written as shading pattern
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum PdfPaint {
+    // (r, g, b) and opacity
+    Color((u8, u8, u8), f32),
+    // the index of pattern resource, opacity and the object id of soft mask
+    Pattern(usize, f32, Option<usize>),
+}
+
+impl PdfPaint {
+    fn opacity(&self) -> f32 {
+        match self {
+            PdfPaint::Color(_, opacity) => *opacity,
+            PdfPaint::Pattern(_, opacity, _) => *opacity,
+        }
+    }
+    fn soft_mask(&self) -> Option<usize> {
+        match self {
+            PdfPaint::Color(..) => None,
+            PdfPaint::Pattern(_, _, soft_mask) => *soft_mask,
+        }
+    }
+}
+
+#[derive(Default)]
+struct PdfWriter {
+    objects: Vec<Option<Vec<u8>>>,
+    fonts: Vec<PdfFont>,
+    // (fill opacity, stroke opacity, the object id of soft mask)
+    ext_graphic_states: Vec<(f32, f32, Option<usize>)>,
+    // the object ids of patterns
+    patterns: Vec<usize>,
+    // the object id of resources, it's shared by pages and tiling patterns
+    resources: usize,
+    // the transform from svg to the default space of page
+    page_transform: Transform,
+}
+
+fn format_number(value: f32) -> String {
+    let value = (value * 10000.0).round() / 10000.0;
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    format!("{value}")
+}
+
+fn format_transform(ts: &Transform) -> String {
+    [ts.sx, ts.ky, ts.kx, ts.sy, ts.tx, ts.ty]
+        .iter()
+        .map(|v| format_number(*v))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn deflate(data: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+    encoder.write_all(data).context(CompressSnafu)?;
+    encoder.finish().context(CompressSnafu)
+}
+
+/// Returns true if the font of index has cff outlines, the tables are read
+/// from the table directory of font or font collection.
+/// The cff2 outlines(variable font) are not supported by pdf.
+fn is_cff_font(name: &str, data: &[u8], index: u32) -> Result<bool> {
+    let read_u32 = |offset: usize| {
+        data.get(offset..offset + 4)
+            .map(|v| u32::from_be_bytes([v[0], v[1], v[2], v[3]]) as usize)
+    };
+    let offset = if data.starts_with(b"ttcf") {
+        read_u32(12 + 4 * index as usize)
+    } else {
+        Some(0)
+    };
+    let Some(offset) = offset else {
+        return FontSnafu {
+            message: format!("{name} is invalid"),
+        }
+        .fail();
+    };
+    let count = data
+        .get(offset + 4..offset + 6)
+        .map(|v| u16::from_be_bytes([v[0], v[1]]) as usize)
+        .unwrap_or_default();
+    let mut cff = false;
+    for i in 0..count {
+        let start = offset + 12 + 16 * i;
+        match data.get(start..start + 4) {
+            Some(b"CFF ") => cff = true,
+            Some(b"CFF2") => {
+                return FontSnafu {
+                    message: format!("cff2 outlines of {name} are not supported"),
+                }
+                .fail();
+            }
+            _ => {}
+        }
+    }
+    Ok(cff)
+}
+
+fn format_color(color: usvg::Color) -> String {
+    format!(
+        "{} {} {}",
+        format_number(color.red as f32 / 255.0),
+        format_number(color.green as f32 / 255.0),
+        format_number(color.blue as f32 / 255.0)
+    )
+}
+
+/// Gets the function of gradient stops, the values(e.g. rgb color) are interpolated
+/// linearly and the functions are stitched if there are more than two stops.
+/// It returns none if the stops can't be interpolated.
+fn get_stops_function(stops: &[(f32, String)]) -> Option<String> {
+    let mut items: Vec<&(f32, String)> = stops.iter().collect();
+    let first = *items.first()?;
+    let last = *items.last()?;
+    let (start_item, end_item) = ((0.0, first.1.clone()), (1.0, last.1.clone()));
+    if first.0 > 0.0 {
+        items.insert(0, &start_item);
+    }
+    if last.0 < 1.0 {
+        items.push(&end_item);
+    }
+    let mut functions = vec![];
+    let mut bounds = vec![];
+    for values in items.windows(2) {
+        let (start, end) = (values[0], values[1]);
+        // the hard stop has no interval
+        if end.0 <= start.0 {
+            continue;
+        }
+        if !functions.is_empty() {
+            bounds.push(format_number(start.0));
+        }
+        functions.push(format!(
+            "<< /FunctionType 2 /Domain [0 1] /C0 [{}] /C1 [{}] /N 1 >>",
+            start.1, end.1
+        ));
+    }
+    if functions.len() <= 1 {
+        return functions.pop();
+    }
+    Some(format!(
+        "<< /FunctionType 3 /Domain [0 1] /Functions [{}] /Bounds [{}] /Encode [{}] >>",
+        functions.join(" "),
+        bounds.join(" "),
+        vec!["0 1"; functions.len()].join(" ")
+    ))
+}
+
+fn write_color(content: &mut String, color: (u8, u8, u8), operator: &str) {
+    let _ = writeln!(
+        content,
+        "{} {} {} {operator}",
+        format_number(color.0 as f32 / 255.0),
+        format_number(color.1 as f32 / 255.0),
+        format_number(color.2 as f32 / 255.0),
+    );
+}
+
+fn write_paint(content: &mut String, paint: PdfPaint, stroke: bool) {
+    match paint {
+        PdfPaint::Color(color, _) => write_color(content, color, if stroke { "RG" } else { "rg" }),
+        PdfPaint::Pattern(index, ..) => {
+            if stroke {
+                let _ = writeln!(content, "/Pattern CS /P{index} SCN");
+            } else {
+                let _ = writeln!(content, "/Pattern cs /P{index} scn");
+            }
+        }
+    }
+}
+
+fn write_path_data(content: &mut String, path: &usvg::tiny_skia_path::Path) {
+    let mut last = (0.0, 0.0);
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => {
+                let _ = writeln!(content, "{} {} m", format_number(p.x), format_number(p.y));
+                last = (p.x, p.y);
+            }
+            PathSegment::LineTo(p) => {
+                let _ = writeln!(content, "{} {} l", format_number(p.x), format_number(p.y));
+                last = (p.x, p.y);
+            }
+            PathSegment::QuadTo(p1, p) => {
+                // convert quadratic curve to cubic curve
+                let c1 = (
+                    last.0 + 2.0 / 3.0 * (p1.x - last.0),
+                    last.1 + 2.0 / 3.0 * (p1.y - last.1),
+                );
+                let c2 = (
+                    p.x + 2.0 / 3.0 * (p1.x - p.x),
+                    p.y + 2.0 / 3.0 * (p1.y - p.y),
+                );
+                let _ = writeln!(
+                    content,
+                    "{} {} {} {} {} {} c",
+                    format_number(c1.0),
+                    format_number(c1.1),
+                    format_number(c2.0),
+                    format_number(c2.1),
+                    format_number(p.x),
+                    format_number(p.y)
+                );
+                last = (p.x, p.y);
+            }
+            PathSegment::CubicTo(p1, p2, p) => {
+                let _ = writeln!(
+                    content,
+                    "{} {} {} {} {} {} c",
+                    format_number(p1.x),
+                    format_number(p1.y),
+                    format_number(p2.x),
+                    format_number(p2.y),
+                    format_number(p.x),
+                    format_number(p.y)
+                );
+                last = (p.x, p.y);
+            }
+            PathSegment::Close => {
+                content.push_str("h\n");
+            }
+        }
+    }
+}
+
+fn collect_clip_paths<'a>(group: &'a usvg::Group, paths: &mut Vec<&'a usvg::Path>) -> Result<()> {
+    for node in group.children() {
+        match node {
+            Node::Group(group) => collect_clip_paths(group, paths)?,
+            Node::Path(path) => paths.push(path),
+            _ => {
+                return UnsupportedSnafu {
+                    message: "clip path only supports shape",
+                }
+                .fail();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes the clip path, it only supports the shapes
+/// and the nested clip path is not supported.
+fn write_clip_path(content: &mut String, clip_path: &usvg::ClipPath) -> Result<()> {
+    if clip_path.clip_path().is_some() {
+        return UnsupportedSnafu {
+            message: "nested clip path is not supported",
+        }
+        .fail();
+    }
+    let mut paths = vec![];
+    collect_clip_paths(clip_path.root(), &mut paths)?;
+    let mut even_odd = false;
+    for path in paths.iter() {
+        let ts = clip_path.transform().pre_concat(path.abs_transform());
+        if let Some(data) = path.data().clone().transform(ts) {
+            write_path_data(content, &data);
+        }
+        even_odd = path
+            .fill()
+            .map(|fill| fill.rule() == usvg::FillRule::EvenOdd)
+            .unwrap_or_default();
+    }
+    if paths.is_empty() {
+        // nothing is visible if the clip path is empty
+        content.push_str("0 0 m\nh\n");
+    }
+    if even_odd {
+        content.push_str("W* n\n");
+    } else {
+        content.push_str("W n\n");
+    }
+    Ok(())
+}
+
+fn write_stroke_style(content: &mut String, stroke: &usvg::Stroke) {
+    let cap = match stroke.linecap() {
+        usvg::LineCap::Butt => 0,
+        usvg::LineCap::Round => 1,
+        usvg::LineCap::Square => 2,
+    };
+    let join = match stroke.linejoin() {
+        usvg::LineJoin::Miter | usvg::LineJoin::MiterClip => 0,
+        usvg::LineJoin::Round => 1,
+        usvg::LineJoin::Bevel => 2,
+    };
+    let _ = writeln!(
+        content,
+        "{} w {cap} J {join} j {} M",
+        format_number(stroke.width().get()),
+        format_number(stroke.miterlimit().get())
+    );
+    if let Some(dasharray) = stroke.dasharray() {
+        let values: Vec<String> = dasharray.iter().map(|v| format_number(*v)).collect();
+        let _ = writeln!(
+            content,
+            "[{}] {} d",
+            values.join(" "),
+            format_number(stroke.dashoffset())
+        );
+    }
+}
+
+impl PdfWriter {
+    fn reserve(&mut self) -> usize {
+        self.objects.push(None);
+        self.objects.len()
+    }
+    fn set(&mut self, id: usize, data: Vec<u8>) {
+        self.objects[id - 1] = Some(data);
+    }
+    fn add(&mut self, data: Vec<u8>) -> usize {
+        let id = self.reserve();
+        self.set(id, data);
+        id
+    }
+    fn add_stream(&mut self, dict: &str, data: &[u8]) -> Result<usize> {
+        let data = deflate(data)?;
+        let mut buf = format!(
+            "<< {dict} /Filter /FlateDecode /Length {} >>\nstream\n",
+            data.len()
+        )
+        .into_bytes();
+        buf.extend(data);
+        buf.extend(b"\nendstream");
+        Ok(self.add(buf))
+    }
+    fn get_ext_graphic_state(
+        &mut self,
+        fill_opacity: f32,
+        stroke_opacity: f32,
+        soft_mask: Option<usize>,
+    ) -> usize {
+        let value = (fill_opacity, stroke_opacity, soft_mask);
+        if let Some(index) = self.ext_graphic_states.iter().position(|v| *v == value) {
+            return index;
+        }
+        self.ext_graphic_states.push(value);
+        self.ext_graphic_states.len() - 1
+    }
+    fn get_font(&mut self, db: &fontdb::Database, id: fontdb::ID) -> Result<usize> {
+        if let Some(index) = self.fonts.iter().position(|item| item.id == id) {
+            return Ok(index);
+        }
+        let name = db
+            .face(id)
+            .map(|face| face.post_script_name.clone())
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect::<String>();
+        let Some((data, index)) = db.with_face_data(id, |data, index| (data.to_vec(), index))
+        else {
+            return FontSnafu {
+                message: format!("{name} is not found"),
+            }
+            .fail();
+        };
+        let metrics = fontdue::Font::from_bytes(
+            data.as_slice(),
+            fontdue::FontSettings {
+                collection_index: index,
+                ..Default::default()
+            },
+        )
+        .map_err(|message| Error::Font {
+            message: message.to_string(),
+        })?;
+        let cff = is_cff_font(&name, &data, index)?;
+        self.fonts.push(PdfFont {
+            name: if name.is_empty() {
+                format!("Font{}", self.fonts.len())
+            } else {
+                name
+            },
+            id,
+            data,
+            cff,
+            metrics,
+            glyphs: BTreeMap::new(),
+        });
+        Ok(self.fonts.len() - 1)
+    }
+    /// Gets the tiling pattern of pdf, the content of pattern is written
+    /// in a cell of pattern rect size, and the cell is repeated.
+    fn get_tiling_pattern(
+        &mut self,
+        db: &fontdb::Database,
+        pattern: &usvg::Pattern,
+        ts: Transform,
+    ) -> Result<PdfPaint> {
+        let rect = pattern.rect();
+        let mut content = String::new();
+        // the paints of content are in the space of pattern cell
+        let page_transform = std::mem::take(&mut self.page_transform);
+        let result = self.write_group(&mut content, db, pattern.root(), 1.0);
+        self.page_transform = page_transform;
+        result?;
+        let matrix = self
+            .page_transform
+            .pre_concat(ts)
+            .pre_concat(pattern.transform())
+            .pre_translate(rect.x(), rect.y());
+        let id = self.add_stream(
+            &format!(
+                "/Type /Pattern /PatternType 1 /PaintType 1 /TilingType 1 /BBox [0 0 {} {}] /XStep {} /YStep {} /Resources {} 0 R /Matrix [{}]",
+                format_number(rect.width()),
+                format_number(rect.height()),
+                format_number(rect.width()),
+                format_number(rect.height()),
+                self.resources,
+                format_transform(&matrix)
+            ),
+            content.as_bytes(),
+        )?;
+        self.patterns.push(id);
+        Ok(PdfPaint::Pattern(self.patterns.len() - 1, 1.0, None))
+    }
+    /// Gets the paint of pdf, the transform converts the user space of paint to svg.
+    /// The gradient is written as shading pattern with pad spread method, and the opacity
+    /// of stops is written as soft mask if it is not the same.
+    /// The pattern is written as tiling pattern.
+    fn get_paint(
+        &mut self,
+        db: &fontdb::Database,
+        paint: &Paint,
+        ts: Transform,
+    ) -> Result<Option<PdfPaint>> {
+        let (gradient, shading) = match paint {
+            Paint::Color(c) => return Ok(Some(PdfPaint::Color((c.red, c.green, c.blue), 1.0))),
+            Paint::LinearGradient(g) => (
+                g.as_ref() as &usvg::BaseGradient,
+                format!(
+                    "/ShadingType 2 /Coords [{} {} {} {}]",
+                    format_number(g.x1()),
+                    format_number(g.y1()),
+                    format_number(g.x2()),
+                    format_number(g.y2())
+                ),
+            ),
+            Paint::RadialGradient(g) => (
+                g.as_ref() as &usvg::BaseGradient,
+                format!(
+                    "/ShadingType 3 /Coords [{} {} 0 {} {} {}]",
+                    format_number(g.fx()),
+                    format_number(g.fy()),
+                    format_number(g.cx()),
+                    format_number(g.cy()),
+                    format_number(g.r().get())
+                ),
+            ),
+            Paint::Pattern(pattern) => {
+                return self.get_tiling_pattern(db, pattern, ts).map(Some);
+            }
+        };
+        let stops = gradient.stops();
+        let Some(last) = stops.last() else {
+            return Ok(None);
+        };
+        let colors: Vec<(f32, String)> = stops
+            .iter()
+            .map(|stop| (stop.offset().get(), format_color(stop.color())))
+            .collect();
+        let Some(function) = get_stops_function(&colors) else {
+            let c = last.color();
+            return Ok(Some(PdfPaint::Color(
+                (c.red, c.green, c.blue),
+                last.opacity().get(),
+            )));
+        };
+        let opacities: Vec<(f32, String)> = stops
+            .iter()
+            .map(|stop| (stop.offset().get(), format_number(stop.opacity().get())))
+            .collect();
+        let (opacity, soft_mask) = if opacities.iter().all(|item| item.1 == opacities[0].1) {
+            (last.opacity().get(), None)
+        } else {
+            let soft_mask = get_stops_function(&opacities)
+                .map(|function| self.get_soft_mask(&shading, &function, gradient.transform()))
+                .transpose()?;
+            (1.0, soft_mask)
+        };
+        let matrix = self
+            .page_transform
+            .pre_concat(ts)
+            .pre_concat(gradient.transform());
+        let id = self.add(
+            format!(
+                "<< /Type /Pattern /PatternType 2 /Shading << {shading} /ColorSpace /DeviceRGB /Function {function} /Extend [true true] >> /Matrix [{}] >>",
+                format_transform(&matrix)
+            )
+            .into_bytes(),
+        );
+        self.patterns.push(id);
+        Ok(Some(PdfPaint::Pattern(
+            self.patterns.len() - 1,
+            opacity,
+            soft_mask,
+        )))
+    }
+    /// Gets the soft mask of gradient opacity, it's a luminosity group which paints
+    /// the opacity of stops as gray shading in the user space of path.
+    fn get_soft_mask(&mut self, shading: &str, function: &str, ts: Transform) -> Result<usize> {
+        self.add_stream(
+            &format!(
+                "/Type /XObject /Subtype /Form /BBox [{SOFT_MASK_BBOX}] /Group << /S /Transparency /CS /DeviceGray >> /Resources << /Shading << /Sh0 << {shading} /ColorSpace /DeviceGray /Function {function} /Extend [true true] >> >> >>"
+            ),
+            format!("{} cm\n/Sh0 sh\n", format_transform(&ts)).as_bytes(),
+        )
+    }
+    fn write_opacity(
+        &mut self,
+        content: &mut String,
+        fill_opacity: f32,
+        stroke_opacity: f32,
+        soft_mask: Option<usize>,
+    ) {
+        if fill_opacity < 1.0 || stroke_opacity < 1.0 || soft_mask.is_some() {
+            let index = self.get_ext_graphic_state(fill_opacity, stroke_opacity, soft_mask);
+            let _ = writeln!(content, "/GS{index} gs");
+        }
+    }
+    fn write_path(
+        &mut self,
+        content: &mut String,
+        db: &fontdb::Database,
+        path: &usvg::Path,
+        opacity: f32,
+    ) -> Result<()> {
+        if !path.is_visible() {
+            return Ok(());
+        }
+        let ts = path.abs_transform();
+        if let Some(fill) = path.fill() {
+            if let Some(paint) = self.get_paint(db, fill.paint(), ts)? {
+                content.push_str("q\n");
+                self.write_opacity(
+                    content,
+                    opacity * fill.opacity().get() * paint.opacity(),
+                    1.0,
+                    paint.soft_mask(),
+                );
+                write_paint(content, paint, false);
+                write_path_data(content, path.data());
+                if fill.rule() == usvg::FillRule::EvenOdd {
+                    content.push_str("f*\nQ\n");
+                } else {
+                    content.push_str("f\nQ\n");
+                }
+            }
+        }
+        if let Some(stroke) = path.stroke() {
+            if let Some(paint) = self.get_paint(db, stroke.paint(), ts)? {
+                content.push_str("q\n");
+                self.write_opacity(
+                    content,
+                    1.0,
+                    opacity * stroke.opacity().get() * paint.opacity(),
+                    paint.soft_mask(),
+                );
+                write_paint(content, paint, true);
+                write_stroke_style(content, stroke);
+                write_path_data(content, path.data());
+                content.push_str("S\nQ\n");
+            }
+        }
+        Ok(())
+    }
+    fn write_text(
+        &mut self,
+        content: &mut String,
+        db: &fontdb::Database,
+        text: &usvg::Text,
+        opacity: f32,
+    ) -> Result<()> {
+        for span in text.layouted() {
+            if !span.visible {
+                continue;
+            }
+            for path in [span.underline.as_ref(), span.overline.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                self.write_path(content, db, path, opacity)?;
+            }
+            let ts = text.abs_transform();
+            let fill = match span.fill.as_ref() {
+                Some(fill) => self
+                    .get_paint(db, fill.paint(), ts)?
+                    .map(|paint| (paint, fill.opacity().get())),
+                None => None,
+            };
+            let stroke = match span.stroke.as_ref() {
+                Some(stroke) => self
+                    .get_paint(db, stroke.paint(), ts)?
+                    .map(|paint| (paint, stroke.opacity().get())),
+                None => None,
+            };
+            let mode = match (fill.is_some(), stroke.is_some()) {
+                (true, false) => 0,
+                (false, true) => 1,
+                (true, true) => 2,
+                // the text is invisible, but it can be selected
+                (false, false) => 3,
+            };
+            content.push_str("q\n");
+            let fill_opacity = fill.map(|(p, o)| opacity * o * p.opacity()).unwrap_or(1.0);
+            let stroke_opacity = stroke
+                .map(|(p, o)| opacity * o * p.opacity())
+                .unwrap_or(1.0);
+            // the fill and stroke of text share the graphic state
+            let soft_mask = match (
+                fill.and_then(|(p, _)| p.soft_mask()),
+                stroke.and_then(|(p, _)| p.soft_mask()),
+            ) {
+                (Some(_), Some(_)) => {
+                    return UnsupportedSnafu {
+                        message: "gradient opacity of both text fill and stroke is not supported",
+                    }
+                    .fail();
+                }
+                (fill_mask, stroke_mask) => fill_mask.or(stroke_mask),
+            };
+            self.write_opacity(content, fill_opacity, stroke_opacity, soft_mask);
+            if let Some((paint, _)) = fill {
+                write_paint(content, paint, false);
+            }
+            if let (Some((paint, _)), Some(value)) = (stroke, span.stroke.as_ref()) {
+                write_paint(content, paint, true);
+                write_stroke_style(content, value);
+            }
+            let _ = writeln!(content, "BT\n{mode} Tr");
+            let mut current_font = None;
+            for glyph in span.positioned_glyphs.iter() {
+                let index = self.get_font(db, glyph.font)?;
+                if current_font != Some(index) {
+                    let _ = writeln!(content, "/F{index} 1 Tf");
+                    current_font = Some(index);
+                }
+                let font = &mut self.fonts[index];
+                font.glyphs
+                    .entry(glyph.id.0)
+                    .or_insert_with(|| glyph.text.clone());
+                // the text space unit is an em with font size 1,
+                // so the outline transform is scaled by units per em
+                let units_per_em = font.metrics.units_per_em();
+                let ts = glyph
+                    .outline_transform()
+                    .pre_scale(units_per_em, units_per_em);
+                let _ = writeln!(
+                    content,
+                    "{} Tm <{:04X}> Tj",
+                    format_transform(&ts),
+                    glyph.id.0
+                );
+            }
+            content.push_str("ET\nQ\n");
+            if let Some(path) = span.line_through.as_ref() {
+                self.write_path(content, db, path, opacity)?;
+            }
+        }
+        Ok(())
+    }
+    fn write_group(
+        &mut self,
+        content: &mut String,
+        db: &fontdb::Database,
+        group: &usvg::Group,
+        opacity: f32,
+    ) -> Result<()> {
+        // the mask and filter are not used by charts
+        if group.mask().is_some() {
+            return UnsupportedSnafu {
+                message: "mask is not supported",
+            }
+            .fail();
+        }
+        if !group.filters().is_empty() {
+            return UnsupportedSnafu {
+                message: "filter is not supported",
+            }
+            .fail();
+        }
+        content.push_str("q\n");
+        if !group.transform().is_identity() {
+            let _ = writeln!(content, "{} cm", format_transform(&group.transform()));
+        }
+        // the clip path(e.g. the viewport of nested svg) is applied after the transform
+        if let Some(clip_path) = group.clip_path() {
+            write_clip_path(content, clip_path)?;
+        }
+        let opacity = opacity * group.opacity().get();
+        for node in group.children() {
+            match node {
+                Node::Group(group) => self.write_group(content, db, group, opacity)?,
+                Node::Path(path) => self.write_path(content, db, path, opacity)?,
+                Node::Text(text) => self.write_text(content, db, text, opacity)?,
+                Node::Image(_) => {
+                    return UnsupportedSnafu {
+                        message: "image is not supported",
+                    }
+                    .fail();
+                }
+            }
+        }
+        content.push_str("Q\n");
+        Ok(())
+    }
+    fn write_fonts(&mut self) -> Result<Vec<usize>> {
+        let mut ids = vec![];
+        let fonts = std::mem::take(&mut self.fonts);
+        for font in fonts.iter() {
+            let (ascent, descent) = font
+                .metrics
+                .horizontal_line_metrics(1000.0)
+                .map(|m| (m.ascent, m.descent))
+                .unwrap_or((1000.0, -200.0));
+
+            // the cff font is embedded as opentype font program,
+            // and the cids of identity encoding are used as glyph ids
+            let (font_file_dict, font_file_key, subtype, cid_to_gid_map) = if font.cff {
+                (
+                    "/Subtype /OpenType".to_string(),
+                    "FontFile3",
+                    "CIDFontType0",
+                    "",
+                )
+            } else {
+                (
+                    format!("/Length1 {}", font.data.len()),
+                    "FontFile2",
+                    "CIDFontType2",
+                    " /CIDToGIDMap /Identity",
+                )
+            };
+            let font_file = self.add_stream(&font_file_dict, &font.data)?;
+            let descriptor = self.add(
+                format!(
+                    "<< /Type /FontDescriptor /FontName /{} /Flags 32 /FontBBox [-1000 {} 2000 {}] /ItalicAngle 0 /Ascent {} /Descent {} /CapHeight {} /StemV 80 /{font_file_key} {font_file} 0 R >>",
+                    font.name,
+                    format_number(descent),
+                    format_number(ascent),
+                    format_number(ascent),
+                    format_number(descent),
+                    format_number(ascent),
+                )
+                .into_bytes(),
+            );
+            let widths: Vec<String> = font
+                .glyphs
+                .keys()
+                .map(|gid| {
+                    format!(
+                        "{gid} [{}]",
+                        format_number(font.metrics.metrics_indexed(*gid, 1000.0).advance_width)
+                    )
+                })
+                .collect();
+            let cid_font = self.add(
+                format!(
+                    "<< /Type /Font /Subtype /{subtype} /BaseFont /{} /CIDSystemInfo << /Registry (Adobe) /Ordering (Identity) /Supplement 0 >> /FontDescriptor {descriptor} 0 R /W [{}]{cid_to_gid_map} >>",
+                    font.name,
+                    widths.join(" ")
+                )
+                .into_bytes(),
+            );
+            let to_unicode = self.add_stream("", get_to_unicode_cmap(&font.glyphs).as_bytes())?;
+            ids.push(self.add(
+                format!(
+                    "<< /Type /Font /Subtype /Type0 /BaseFont /{} /Encoding /Identity-H /DescendantFonts [{cid_font} 0 R] /ToUnicode {to_unicode} 0 R >>",
+                    font.name
+                )
+                .into_bytes(),
+            ));
+        }
+        self.fonts = fonts;
+        Ok(ids)
+    }
+    fn finish(self, catalog: usize) -> Vec<u8> {
+        let mut buf = b"%PDF-1.7\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = vec![];
+        for (index, data) in self.objects.into_iter().enumerate() {
+            offsets.push(buf.len());
+            buf.extend(format!("{} 0 obj\n", index + 1).into_bytes());
+            buf.extend(data.unwrap_or_else(|| b"null".to_vec()));
+            buf.extend(b"\nendobj\n");
+        }
+        let xref = buf.len();
+        let mut table = format!("xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1);
+        for offset in offsets.iter() {
+            let _ = writeln!(table, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            table,
+            "trailer\n<< /Size {} /Root {catalog} 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            offsets.len() + 1
+        );
+        buf.extend(table.into_bytes());
+        buf
+    }
+}
+
+fn get_to_unicode_cmap(glyphs: &BTreeMap<u16, String>) -> String {
+    let mut cmap = "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n/CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n/CMapName /Adobe-Identity-UCS def\n/CMapType 2 def\n1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n".to_string();
+    let items: Vec<(&u16, &String)> = glyphs.iter().filter(|(_, text)| !text.is_empty()).collect();
+    for chunk in items.chunks(100) {
+        let _ = writeln!(cmap, "{} beginbfchar", chunk.len());
+        for (gid, text) in chunk.iter() {
+            let value: String = text.encode_utf16().map(|v| format!("{v:04X}")).collect();
+            let _ = writeln!(cmap, "<{gid:04X}> <{value}>");
+        }
+        cmap.push_str("endbfchar\n");
+    }
+    cmap.push_str("endcmap\nCMapName currentdict /CMap defineresource pop\nend\nend\n");
+    cmap
+}
+
+/// Converts svg list to pdf, each svg is a page of pdf.
+/// The text is written with the fonts of `get_or_try_init_fonts`.
+/// The opacity of gradient stops is written as soft mask,
+/// the pattern is written as tiling pattern,
+/// and it returns error if the svg has image, mask or filter.
+pub fn svgs_to_pdf(svgs: &[String]) -> Result<Vec<u8>> {
+    let db = get_or_init_fontdb();
+    let options = usvg::Options {
+        fontdb: db.clone(),
+        ..Default::default()
+    };
+    let mut writer = PdfWriter::default();
+    let catalog = writer.reserve();
+    let pages = writer.reserve();
+    let resources = writer.reserve();
+    writer.resources = resources;
+    let mut page_ids = vec![];
+    for svg in svgs.iter() {
+        let tree = usvg::Tree::from_str(svg, &options).context(ParseSnafu)?;
+        let size = tree.size();
+        let mut content = String::new();
+        // flip the y axis, the origin of svg is top left
+        writer.page_transform = Transform::from_row(1.0, 0.0, 0.0, -1.0, 0.0, size.height());
+        let _ = writeln!(content, "{} cm", format_transform(&writer.page_transform));
+        writer.write_group(&mut content, &db, tree.root(), 1.0)?;
+        let content_id = writer.add_stream("", content.as_bytes())?;
+        page_ids.push(writer.add(
+            format!(
+                "<< /Type /Page /Parent {pages} 0 R /MediaBox [0 0 {} {}] /Resources {resources} 0 R /Contents {content_id} 0 R >>",
+                format_number(size.width()),
+                format_number(size.height())
+            )
+            .into_bytes(),
+        ));
+    }
+    let font_ids = writer.write_fonts()?;
+    let fonts: Vec<String> = font_ids
+        .iter()
+        .enumerate()
+        .map(|(index, id)| format!("/F{index} {id} 0 R"))
+        .collect();
+    let states: Vec<String> = writer
+        .ext_graphic_states
+        .iter()
+        .enumerate()
+        .map(|(index, (fill, stroke, soft_mask))| {
+            let soft_mask = soft_mask
+                .map(|id| format!(" /SMask << /Type /Mask /S /Luminosity /G {id} 0 R >>"))
+                .unwrap_or_default();
+            format!(
+                "/GS{index} << /Type /ExtGState /ca {} /CA {}{soft_mask} >>",
+                format_number(*fill),
+                format_number(*stroke)
+            )
+        })
+        .collect();
+    let patterns: Vec<String> = writer
+        .patterns
+        .iter()
+        .enumerate()
+        .map(|(index, id)| format!("/P{index} {id} 0 R"))
+        .collect();
+    writer.set(
+        resources,
+        format!(
+            "<< /Font << {} >> /ExtGState << {} >> /Pattern << {} >> >>",
+            fonts.join(" "),
+            states.join(" "),
+            patterns.join(" ")
+        )
+        .into_bytes(),
+    );
+    let kids: Vec<String> = page_ids.iter().map(|id| format!("{id} 0 R")).collect();
+    writer.set(
+        pages,
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            page_ids.len()
+        )
+        .into_bytes(),
+    );
+    writer.set(
+        catalog,
+        format!("<< /Type /Catalog /Pages {pages} 0 R >>").into_bytes(),
+    );
+    Ok(writer.finish(catalog))
+}
+
+/// Converts svg to pdf, the shapes and text are kept as vector.
+pub fn svg_to_pdf(svg: &str) -> Result<Vec<u8>> {
+    svgs_to_pdf(&[svg.to_string()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{svg_to_pdf, svgs_to_pdf};
+    use crate::{
+        BarChart, ChildChart, Color, Gradient, GridCell, GridLayout, MultiChart, PatternCategory,
+        PieChart,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn new_bar_chart() -> BarChart {
+        BarChart::new(
+            vec![
+                ("Email", vec![120.0, 132.0, 101.0, 134.0]).into(),
+                ("Direct", vec![320.0, 332.0, 301.0, 334.0]).into(),
+            ],
+            vec![
+                "Mon".to_string(),
+                "Tue".to_string(),
+                "Wed".to_string(),
+                "Thu".to_string(),
+            ],
+        )
+    }
+
+    fn count(data: &[u8], pattern: &str) -> usize {
+        data.windows(pattern.len())
+            .filter(|item| *item == pattern.as_bytes())
+            .count()
+    }
+
+    #[test]
+    fn bar_chart_pdf() {
+        let bar_chart = new_bar_chart();
+        let buf = svg_to_pdf(&bar_chart.svg().unwrap()).unwrap();
+
+        assert_eq!(true, buf.starts_with(b"%PDF-1.7"));
+        assert_eq!(true, buf.ends_with(b"%%EOF\n"));
+        assert_eq!(1, count(&buf, "/Type /Page "));
+        assert_eq!(1, count(&buf, "/MediaBox [0 0 600 400]"));
+        // the default font is embedded
+        assert_eq!(1, count(&buf, "/FontFile2"));
+        assert_eq!(
+            1,
+            count(&buf, "/BaseFont /Roboto-Regular /Encoding /Identity-H")
+        );
+        assert_eq!(1, count(&buf, "/ToUnicode"));
+    }
+
+    #[test]
+    fn multi_chart_pdf() {
+        let mut charts = MultiChart::new();
+        charts.margin = (10.0).into();
+        charts.add(ChildChart::Bar(new_bar_chart(), None));
+        let mut pie_chart = PieChart::new(vec![
+            ("rose 1", vec![40.0]).into(),
+            ("rose 2", vec![38.0]).into(),
+        ]);
+        pie_chart.height = 300.0;
+        charts.add(ChildChart::Pie(pie_chart, None));
+
+        let buf = charts.pdf().unwrap();
+        assert_eq!(2, count(&buf, "/Type /Page "));
+        assert_eq!(1, count(&buf, "/Count 2"));
+        assert_eq!(1, count(&buf, "/MediaBox [0 0 620 420]"));
+        assert_eq!(1, count(&buf, "/MediaBox [0 0 620 320]"));
+        // the font is shared by pages
+        assert_eq!(1, count(&buf, "/FontFile2"));
+
+        let svgs = charts.page_svgs().unwrap();
+        assert_eq!(2, svgs.len());
+        assert_eq!(buf.len(), svgs_to_pdf(&svgs).unwrap().len());
+    }
+
+    #[test]
+    fn gradient_pdf() {
+        let mut bar_chart = new_bar_chart();
+        bar_chart.series_list[0].gradient = Some(Gradient::new_linear(vec![
+            (0.0, Color::from("#83bff6")).into(),
+            (0.5, Color::from("#5470c6")).into(),
+            (1.0, Color::from("#188df0")).into(),
+        ]));
+        let buf = svg_to_pdf(&bar_chart.svg().unwrap()).unwrap();
+        // the gradient of each bar is a shading pattern
+        assert_eq!(4, count(&buf, "/PatternType 2"));
+        assert_eq!(4, count(&buf, "/ShadingType 2"));
+        assert_eq!(4, count(&buf, "/FunctionType 3"));
+        assert_eq!(1, count(&buf, "/Pattern << /P0 "));
+
+        bar_chart.series_list[0].gradient = Some(Gradient::new_radial(vec![
+            (0.0, Color::from("#83bff6")).into(),
+            (1.0, Color::from("#188df0")).into(),
+        ]));
+        let buf = svg_to_pdf(&bar_chart.svg().unwrap()).unwrap();
+        assert_eq!(4, count(&buf, "/ShadingType 3"));
+        assert_eq!(0, count(&buf, "/FunctionType 3"));
+    }
+
+    #[test]
+    fn gradient_opacity_pdf() {
+        let mut bar_chart = new_bar_chart();
+        bar_chart.series_list[0].gradient = Some(Gradient::new_linear(vec![
+            (0.0, Color::from("#fac858").with_alpha(200)).into(),
+            (1.0, Color::from("#fac858").with_alpha(0)).into(),
+        ]));
+        let buf = svg_to_pdf(&bar_chart.svg().unwrap()).unwrap();
+        // the opacity of stops is a gray shading of soft mask
+        assert_eq!(4, count(&buf, "/S /Luminosity"));
+        assert_eq!(
+            4,
+            count(
+                &buf,
+                "/ColorSpace /DeviceGray /Function << /FunctionType 2 /Domain [0 1] /C0 [0.8] /C1 [0] /N 1 >>"
+            )
+        );
+        assert_eq!(
+            4,
+            count(&buf, "/Group << /S /Transparency /CS /DeviceGray >>")
+        );
+
+        // the same opacity of stops is a constant opacity
+        bar_chart.series_list[0].gradient = Some(Gradient::new_linear(vec![
+            (0.0, Color::from("#83bff6").with_alpha(51)).into(),
+            (1.0, Color::from("#188df0").with_alpha(51)).into(),
+        ]));
+        let buf = svg_to_pdf(&bar_chart.svg().unwrap()).unwrap();
+        assert_eq!(0, count(&buf, "/SMask"));
+        assert_eq!(1, count(&buf, "/ca 0.2 /CA 1 >>"));
+    }
+
+    #[test]
+    fn pattern_pdf() {
+        let mut bar_chart = new_bar_chart();
+        bar_chart.series_list[0].pattern = Some(PatternCategory::DiagonalHatch);
+        bar_chart.series_list[1].pattern = Some(PatternCategory::Dots);
+        let buf = svg_to_pdf(&bar_chart.svg().unwrap()).unwrap();
+        // the pattern of each bar is a tiling pattern
+        assert_eq!(8, count(&buf, "/PatternType 1"));
+        assert_eq!(8, count(&buf, "/BBox [0 0 8 8] /XStep 8 /YStep 8"));
+        assert_eq!(0, count(&buf, "/PatternType 2"));
+        assert_eq!(1, count(&buf, "/Pattern << /P0 "));
+    }
+
+    #[test]
+    fn unsupported_pdf() {
+        let svg = r###"<svg width="100" height="100" viewBox="0 0 100 100" xmlns="http://www.w3.org/2000/svg">
+<mask id="mask"><rect x="0" y="0" width="50" height="50" fill="white"/></mask>
+<rect x="0" y="0" width="100" height="100" fill="red" mask="url(#mask)"/>
+</svg>"###;
+        assert_eq!(
+            "Error unsupported: mask is not supported",
+            svg_to_pdf(svg).unwrap_err().to_string()
+        );
+
+        let svg = r###"<svg width="100" height="100" viewBox="0 0 100 100" xmlns="http://www.w3.org/2000/svg">
+<image x="0" y="0" width="1" height="1" href="data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8z8BQDwAEhQGAhKmMIQAAAABJRU5ErkJggg=="/>
+</svg>"###;
+        assert_eq!(
+            "Error unsupported: image is not supported",
+            svg_to_pdf(svg).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn multi_chart_grid_pdf() {
+        let mut charts = MultiChart::new();
+        charts.grid = Some(GridLayout {
+            width: 1000.0,
+            height: 920.0,
+            columns: vec![1.0, 1.0],
+            rows: vec![1.0, 1.0, 1.0],
+            cells: vec![
+                GridCell {
+                    row_span: 2,
+                    ..Default::default()
+                },
+                GridCell::default(),
+                GridCell::default(),
+                GridCell {
+                    column_span: 2,
+                    ..Default::default()
+                },
+            ],
+        });
+        for _ in 0..4 {
+            charts.add(ChildChart::Bar(new_bar_chart(), None));
+        }
+
+        // the charts of the first two rows are joined by row span
+        let svgs = charts.page_svgs().unwrap();
+        assert_eq!(2, svgs.len());
+        let buf = charts.pdf().unwrap();
+        assert_eq!(2, count(&buf, "/Type /Page "));
+        assert_eq!(1, count(&buf, "/MediaBox [0 0 1020 630]"));
+        assert_eq!(1, count(&buf, "/MediaBox [0 0 1020 320]"));
+    }
+}
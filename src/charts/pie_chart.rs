@@ -85,9 +85,18 @@ pub struct PieChart {
     pub series_label_font_weight: Option<String>,
     pub series_label_formatter: String,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
+    // tooltip of the value and percentage of each sector
+    pub interactive: Option<bool>,
+    // sectors sweep clockwise from the top
+    pub animation: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the value of each sector, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl PieChart {
@@ -116,6 +125,12 @@ impl PieChart {
         if let Some(border_radius) = get_f32_from_value(&value, "border_radius") {
             p.border_radius = Some(border_radius);
         }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            p.interactive = Some(interactive);
+        }
+        if let Some(animation) = get_bool_from_value(&value, "animation") {
+            p.animation = Some(animation);
+        }
         Ok(p)
     }
     /// Creates a pie chart with custom theme.
@@ -135,6 +150,16 @@ impl PieChart {
     /// Converts pie chart to svg.
     pub fn svg(&self) -> canvas::Result<String> {
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.animation = self.animation.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
 
         self.render_background(c.child(Box::default()));
         c.margin = self.margin.clone();
@@ -193,6 +218,12 @@ impl PieChart {
 
         let mut prev_quadrant = u8::MAX;
         let mut prev_end_y = f32::MAX;
+        // the pies are swept together, so they are revealed one by one
+        let sweep_canvas = if c.animation {
+            c.animation_child(Animation::Sweep { cx, cy, r })
+        } else {
+            c.clone()
+        };
         for (index, series) in self.series_list.iter().enumerate() {
             let value = values[index];
             let mut cr = value / max * (r - self.inner_radius) + self.inner_radius;
@@ -208,6 +239,12 @@ impl PieChart {
             }
             let mut pie = Pie {
                 fill: color,
+                paint: get_series_paint(
+                    series,
+                    series.index.unwrap_or(index),
+                    &self.series_patterns,
+                    color,
+                ),
                 cx,
                 cy,
                 r: cr,
@@ -220,7 +257,23 @@ impl PieChart {
                 pie.border_radius = border_radius;
             }
 
-            c.pie(pie);
+            let mut pie_canvas = sweep_canvas.clone();
+            if c.interactive {
+                let text = LabelOption {
+                    value,
+                    percentage: value / sum,
+                    formatter: "{c} ({d})".to_string(),
+                    ..Default::default()
+                }
+                .format();
+                pie_canvas = pie_canvas.interactive_child(Interactive::new_item(
+                    &series.name,
+                    None,
+                    format_float(value),
+                    &text,
+                ));
+            }
+            pie_canvas.pie(pie);
 
             let angle = start_angle + half_delta;
             let mut points = vec![];
@@ -278,14 +331,20 @@ impl PieChart {
             }
 
             points.push(end);
-            c.smooth_line(SmoothLine {
+            // the label fades in after the sector
+            let mut label_canvas = if c.animation {
+                c.animation_child(Animation::Fade)
+            } else {
+                c.clone()
+            };
+            label_canvas.smooth_line(SmoothLine {
                 color: Some(color),
                 points,
                 symbol: None,
                 ..Default::default()
             });
 
-            c.child(label_margin).text(Text {
+            label_canvas.child(label_margin).text(Text {
                 text: label_text,
                 font_family: Some(self.font_family.clone()),
                 font_size: Some(self.series_label_font_size),
@@ -368,6 +427,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pie_chart_animation() {
+        let mut pie_chart = PieChart::new(vec![
+            ("rose 1", vec![400.0]).into(),
+            ("rose 2", vec![38.0]).into(),
+            ("rose 3", vec![32.0]).into(),
+            ("rose 4", vec![30.0]).into(),
+        ]);
+        pie_chart.title_text = "Pie Chart".to_string();
+        pie_chart.animation = Some(true);
+        pie_chart.interactive = Some(true);
+        assert_eq!(
+            include_str!("../../asset/pie_chart/animation.svg").trim(),
+            pie_chart.svg().unwrap()
+        );
+    }
     #[test]
     fn not_rose_radius_pie() {
         let mut pie_chart = PieChart::new(vec![
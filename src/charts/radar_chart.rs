@@ -110,12 +110,17 @@ pub struct RadarChart {
     pub series_label_font_weight: Option<String>,
     pub series_label_formatter: String,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
 
     // indicators
     pub indicators: Vec<RadarIndicator>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the values of each series, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl RadarChart {
@@ -157,6 +162,14 @@ impl RadarChart {
             });
         }
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
 
         self.render_background(c.child(Box::default()));
         c.margin = self.margin.clone();
@@ -291,9 +304,25 @@ impl RadarChart {
                     points.push(p);
                 }
             }
+            // the pattern can't be used by line, so the area is filled by polygon
+            let pattern = get_series_pattern(
+                series,
+                series.index.unwrap_or(index),
+                &self.series_patterns,
+                color,
+            );
+            let mut fill = Some(color.with_alpha(50));
+            if let Some(pattern) = pattern {
+                c.polygon(Polygon {
+                    paint: Some(pattern.into()),
+                    points: points.clone(),
+                    ..Default::default()
+                });
+                fill = None;
+            }
             c.straight_line(StraightLine {
                 color: Some(color),
-                fill: Some(color.with_alpha(50)),
+                fill,
                 points: points.clone(),
                 stroke_width: self.series_stroke_width,
                 close: true,
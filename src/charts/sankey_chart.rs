@@ -0,0 +1,595 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::canvas;
+use super::color::*;
+use super::common::*;
+use super::component::*;
+use super::params::*;
+use super::theme::{get_default_theme_name, get_theme, Theme, DEFAULT_Y_AXIS_WIDTH};
+use super::util::*;
+use super::Canvas;
+use crate::charts::measure_text_width_family;
+use charts_rs_derive::Chart;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// the count of sweeps for reducing crossings
+static ORDER_ITERATIONS: usize = 6;
+
+/// The weighted link of sankey, the source and target are the names of nodes.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct SankeyLink {
+    pub source: String,
+    pub target: String,
+    pub value: f32,
+}
+
+impl From<(&str, &str, f32)> for SankeyLink {
+    fn from(value: (&str, &str, f32)) -> Self {
+        SankeyLink {
+            source: value.0.to_string(),
+            target: value.1.to_string(),
+            value: value.2,
+        }
+    }
+}
+
+/// Gets the links of sankey from serde json.
+fn get_sankey_links_from_value(value: &serde_json::Value, key: &str) -> Option<Vec<SankeyLink>> {
+    let links = value.get(key)?.as_array()?;
+    Some(
+        links
+            .iter()
+            .map(|item| SankeyLink {
+                source: get_string_from_value(item, "source").unwrap_or_default(),
+                target: get_string_from_value(item, "target").unwrap_or_default(),
+                value: get_f32_from_value(item, "value").unwrap_or_default(),
+            })
+            .collect(),
+    )
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+struct SankeyNodeLayout {
+    name: String,
+    value: f32,
+    layer: usize,
+    x: f32,
+    y: f32,
+    height: f32,
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+struct SankeyLinkLayout {
+    source: usize,
+    target: usize,
+    value: f32,
+    source_y: f32,
+    target_y: f32,
+    width: f32,
+}
+
+#[derive(Clone, Debug, Default, Chart)]
+pub struct SankeyChart {
+    pub width: f32,
+    pub height: f32,
+    pub x: f32,
+    pub y: f32,
+    pub margin: Box,
+    // no use, but for derive chart
+    series_list: Vec<Series>,
+    // the nodes are added from links if they are not in the list
+    pub nodes: Vec<String>,
+    pub links: Vec<SankeyLink>,
+    pub node_width: f32,
+    pub node_gap: f32,
+    pub font_family: String,
+    pub background_color: Color,
+    pub is_light: bool,
+
+    // title
+    pub title_text: String,
+    pub title_font_size: f32,
+    pub title_font_color: Color,
+    pub title_font_weight: Option<String>,
+    pub title_margin: Option<Box>,
+    pub title_align: Align,
+    pub title_height: f32,
+
+    // sub title
+    pub sub_title_text: String,
+    pub sub_title_font_size: f32,
+    pub sub_title_font_color: Color,
+    pub sub_title_font_weight: Option<String>,
+    pub sub_title_margin: Option<Box>,
+    pub sub_title_align: Align,
+    pub sub_title_height: f32,
+
+    // legend
+    pub legend_font_size: f32,
+    pub legend_font_color: Color,
+    pub legend_font_weight: Option<String>,
+    pub legend_align: Align,
+    pub legend_margin: Option<Box>,
+    pub legend_category: LegendCategory,
+    pub legend_show: Option<bool>,
+
+    // x axis
+    pub x_axis_data: Vec<String>,
+    pub x_axis_height: f32,
+    pub x_axis_stroke_color: Color,
+    pub x_axis_font_size: f32,
+    pub x_axis_font_color: Color,
+    pub x_axis_font_weight: Option<String>,
+    pub x_axis_name_gap: f32,
+    pub x_axis_name_rotate: f32,
+    pub x_axis_margin: Option<Box>,
+    pub x_boundary_gap: Option<bool>,
+
+    // y axis
+    pub y_axis_configs: Vec<YAxisConfig>,
+
+    // grid
+    pub grid_stroke_color: Color,
+    pub grid_stroke_width: f32,
+
+    // series
+    pub series_stroke_width: f32,
+    pub series_label_font_color: Color,
+    pub series_label_font_size: f32,
+    pub series_label_font_weight: Option<String>,
+    pub series_label_formatter: String,
+    pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
+    pub series_symbol: Option<Symbol>,
+    pub series_smooth: bool,
+    pub series_fill: bool,
+    // tooltip of each node and link, hovering highlights it
+    pub interactive: Option<bool>,
+    // title and desc(node names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the value of each node, it implies accessible
+    pub accessible_table: Option<bool>,
+}
+
+impl SankeyChart {
+    fn fill_default(&mut self) {
+        self.node_width = 20.0;
+        self.node_gap = 10.0;
+        self.legend_show = Some(false);
+    }
+    /// Creates a sankey chart from json.
+    pub fn from_json(data: &str) -> canvas::Result<SankeyChart> {
+        let mut s = SankeyChart {
+            ..Default::default()
+        };
+        s.fill_default();
+        let value = s.fill_option(data)?;
+        if let Some(nodes) = get_string_slice_from_value(&value, "nodes") {
+            s.nodes = nodes;
+        }
+        if let Some(links) = get_sankey_links_from_value(&value, "links") {
+            s.links = links;
+        }
+        if let Some(node_width) = get_f32_from_value(&value, "node_width") {
+            s.node_width = node_width;
+        }
+        if let Some(node_gap) = get_f32_from_value(&value, "node_gap") {
+            s.node_gap = node_gap;
+        }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            s.interactive = Some(interactive);
+        }
+        Ok(s)
+    }
+    /// Creates a sankey chart with custom theme.
+    pub fn new_with_theme(nodes: Vec<&str>, links: Vec<SankeyLink>, theme: &str) -> SankeyChart {
+        let mut s = SankeyChart {
+            nodes: nodes.iter().map(|item| item.to_string()).collect(),
+            links,
+            ..Default::default()
+        };
+        s.fill_default();
+        s.fill_theme(get_theme(theme));
+        s
+    }
+    /// Creates a sankey chart with default theme.
+    pub fn new(nodes: Vec<&str>, links: Vec<SankeyLink>) -> SankeyChart {
+        SankeyChart::new_with_theme(nodes, links, &get_default_theme_name())
+    }
+    /// Gets the names of all nodes, include the nodes only in links.
+    fn get_node_names(&self) -> Vec<String> {
+        let mut names = self.nodes.clone();
+        for link in self.links.iter() {
+            for name in [&link.source, &link.target] {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        names
+    }
+    /// Computes the layered layout of nodes and links,
+    /// the nodes of each layer are ordered by barycenter to reduce crossings.
+    fn get_layout(
+        &self,
+        width: f32,
+        height: f32,
+    ) -> (Vec<SankeyNodeLayout>, Vec<SankeyLinkLayout>) {
+        let names = self.get_node_names();
+        let count = names.len();
+        let indexes: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), index))
+            .collect();
+        let mut links: Vec<SankeyLinkLayout> = self
+            .links
+            .iter()
+            .filter(|item| item.value > 0.0 && item.source != item.target)
+            .map(|item| SankeyLinkLayout {
+                source: indexes[item.source.as_str()],
+                target: indexes[item.target.as_str()],
+                value: item.value,
+                ..Default::default()
+            })
+            .collect();
+
+        let mut nodes: Vec<SankeyNodeLayout> = names
+            .iter()
+            .map(|name| SankeyNodeLayout {
+                name: name.clone(),
+                ..Default::default()
+            })
+            .collect();
+        let mut in_values = vec![0.0; count];
+        let mut out_values = vec![0.0; count];
+        for link in links.iter() {
+            out_values[link.source] += link.value;
+            in_values[link.target] += link.value;
+        }
+        for (index, node) in nodes.iter_mut().enumerate() {
+            node.value = f32::max(in_values[index], out_values[index]);
+        }
+
+        // the layer is the longest path from source nodes,
+        // it's limited by the count of nodes for cycles
+        for _ in 0..count {
+            let mut changed = false;
+            for link in links.iter() {
+                let layer = nodes[link.source].layer + 1;
+                if layer > nodes[link.target].layer && layer < count {
+                    nodes[link.target].layer = layer;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        let max_layer = nodes
+            .iter()
+            .map(|item| item.layer)
+            .max()
+            .unwrap_or_default();
+        // the sink nodes are aligned to the right
+        for (index, node) in nodes.iter_mut().enumerate() {
+            if out_values[index] == 0.0 && in_values[index] > 0.0 {
+                node.layer = max_layer;
+            }
+        }
+        let mut layers = vec![vec![]; max_layer + 1];
+        for (index, node) in nodes.iter().enumerate() {
+            layers[node.layer].push(index);
+        }
+
+        // barycenter ordering, sweep forward by sources and backward by targets
+        let mut positions = vec![0.0_f32; count];
+        let update_positions = |layers: &Vec<Vec<usize>>, positions: &mut Vec<f32>| {
+            for layer in layers.iter() {
+                for (i, index) in layer.iter().enumerate() {
+                    positions[*index] = (i as f32 + 0.5) / layer.len() as f32;
+                }
+            }
+        };
+        update_positions(&layers, &mut positions);
+        for _ in 0..ORDER_ITERATIONS {
+            for forward in [true, false] {
+                let layer_indexes: Vec<usize> = if forward {
+                    (1..layers.len()).collect()
+                } else {
+                    (0..layers.len().saturating_sub(1)).rev().collect()
+                };
+                for l in layer_indexes {
+                    let mut barycenters = HashMap::new();
+                    for index in layers[l].iter() {
+                        let mut sum = 0.0;
+                        let mut weight = 0.0;
+                        for link in links.iter() {
+                            let other = if forward && link.target == *index {
+                                link.source
+                            } else if !forward && link.source == *index {
+                                link.target
+                            } else {
+                                continue;
+                            };
+                            let is_neighbor = if forward {
+                                nodes[other].layer < l
+                            } else {
+                                nodes[other].layer > l
+                            };
+                            if is_neighbor {
+                                sum += positions[other] * link.value;
+                                weight += link.value;
+                            }
+                        }
+                        let barycenter = if weight > 0.0 {
+                            sum / weight
+                        } else {
+                            positions[*index]
+                        };
+                        barycenters.insert(*index, barycenter);
+                    }
+                    layers[l].sort_by(|a, b| barycenters[a].total_cmp(&barycenters[b]));
+                    update_positions(&layers, &mut positions);
+                }
+            }
+        }
+
+        // the scale of value is limited by the largest layer
+        let mut ky = f32::MAX;
+        for layer in layers.iter() {
+            let sum: f32 = layer.iter().map(|index| nodes[*index].value).sum();
+            if sum <= 0.0 {
+                continue;
+            }
+            let space = height - self.node_gap * (layer.len() as f32 - 1.0);
+            ky = ky.min(space.max(0.0) / sum);
+        }
+        if ky == f32::MAX {
+            ky = 0.0;
+        }
+        for layer in layers.iter() {
+            let total: f32 = layer
+                .iter()
+                .map(|index| nodes[*index].value * ky)
+                .sum::<f32>()
+                + self.node_gap * (layer.len() as f32 - 1.0);
+            let mut y = (height - total) / 2.0;
+            for index in layer.iter() {
+                let node = &mut nodes[*index];
+                node.x = if max_layer == 0 {
+                    0.0
+                } else {
+                    (width - self.node_width) * node.layer as f32 / max_layer as f32
+                };
+                node.y = y;
+                node.height = node.value * ky;
+                y += node.height + self.node_gap;
+            }
+        }
+
+        // the links are stacked in the order of the other side
+        let mut source_offsets = vec![0.0; count];
+        let mut target_offsets = vec![0.0; count];
+        let center = |index: usize| nodes[index].y + nodes[index].height / 2.0;
+        let mut link_orders: Vec<usize> = (0..links.len()).collect();
+        link_orders.sort_by(|a, b| center(links[*a].target).total_cmp(&center(links[*b].target)));
+        for i in link_orders.iter() {
+            let link = &mut links[*i];
+            link.width = link.value * ky;
+            link.source_y = nodes[link.source].y + source_offsets[link.source];
+            source_offsets[link.source] += link.width;
+        }
+        link_orders.sort_by(|a, b| center(links[*a].source).total_cmp(&center(links[*b].source)));
+        for i in link_orders.iter() {
+            let link = &mut links[*i];
+            link.target_y = nodes[link.target].y + target_offsets[link.target];
+            target_offsets[link.target] += link.width;
+        }
+
+        (nodes, links)
+    }
+    /// Gets the accessible metadata of sankey chart, each node is a row of data table.
+    fn get_accessibility(&self, nodes: &[SankeyNodeLayout]) -> Option<Accessibility> {
+        let series_list: Vec<Series> = nodes
+            .iter()
+            .map(|item| (item.name.as_str(), vec![item.value]).into())
+            .collect();
+        get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        )
+    }
+    /// Converts sankey chart to svg.
+    pub fn svg(&self) -> canvas::Result<String> {
+        let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+
+        self.render_background(c.child(Box::default()));
+        c.margin = self.margin.clone();
+
+        let title_height = self.render_title(c.child(Box::default()));
+
+        let legend_height = self.render_legend(c.child(Box::default()));
+        // get the max height of title and legend
+        let axis_top = if legend_height > title_height {
+            legend_height
+        } else {
+            title_height
+        };
+        if axis_top > 0.0 {
+            c = c.child(Box {
+                top: axis_top,
+                ..Default::default()
+            });
+        }
+
+        let (nodes, links) = self.get_layout(c.width(), c.height());
+        c.accessibility = self.get_accessibility(&nodes);
+        let max_layer = nodes
+            .iter()
+            .map(|item| item.layer)
+            .max()
+            .unwrap_or_default();
+
+        for link in links.iter() {
+            let source = &nodes[link.source];
+            let target = &nodes[link.target];
+            let color = get_color(&self.series_colors, link.source);
+            let band = BezierBand {
+                fill: color.with_alpha(100).into(),
+                x1: source.x + self.node_width,
+                y1: link.source_y,
+                x2: target.x,
+                y2: link.target_y,
+                width: link.width,
+            };
+            if c.interactive {
+                let value = format_float(link.value);
+                c.interactive_child(Interactive::new_item(
+                    &source.name,
+                    Some(target.name.clone()),
+                    value.clone(),
+                    &value,
+                ))
+                .bezier_band(band);
+            } else {
+                c.bezier_band(band);
+            }
+        }
+
+        let mut series_label_formatter = self.series_label_formatter.clone();
+        if series_label_formatter.is_empty() {
+            series_label_formatter = "{a}".to_string();
+        }
+        for (index, node) in nodes.iter().enumerate() {
+            let color = get_color(&self.series_colors, index);
+            let rect = Rect {
+                fill: Some(color),
+                left: node.x,
+                top: node.y,
+                width: self.node_width,
+                height: node.height.max(1.0),
+                ..Default::default()
+            };
+            if c.interactive {
+                let value = format_float(node.value);
+                c.interactive_child(Interactive::new_item(
+                    &node.name,
+                    None,
+                    value.clone(),
+                    &value,
+                ))
+                .rect(rect);
+            } else {
+                c.rect(rect);
+            }
+
+            let label_text = LabelOption {
+                series_name: node.name.clone(),
+                value: node.value,
+                formatter: series_label_formatter.clone(),
+                ..Default::default()
+            }
+            .format();
+            // the labels of last layer are on the left side
+            let left = if node.layer == max_layer && max_layer != 0 {
+                let label_width = if let Ok(b) = measure_text_width_family(
+                    &self.font_family,
+                    self.series_label_font_size,
+                    &label_text,
+                ) {
+                    b.width()
+                } else {
+                    0.0
+                };
+                node.x - label_width - 5.0
+            } else {
+                node.x + self.node_width + 5.0
+            };
+            c.child(Box {
+                left,
+                top: node.y + (node.height + self.series_label_font_size) / 2.0 - 2.0,
+                ..Default::default()
+            })
+            .text(Text {
+                text: label_text,
+                font_family: Some(self.font_family.clone()),
+                font_size: Some(self.series_label_font_size),
+                font_color: Some(self.series_label_font_color),
+                font_weight: self.series_label_font_weight.clone(),
+                ..Default::default()
+            });
+        }
+
+        c.svg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SankeyChart;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn sankey_layout() {
+        let sankey_chart = SankeyChart::new(
+            vec!["a", "b", "c", "d"],
+            vec![
+                ("a", "c", 5.0).into(),
+                ("b", "d", 5.0).into(),
+                ("a", "d", 2.0).into(),
+                ("b", "c", 1.0).into(),
+                ("d", "e", 3.0).into(),
+            ],
+        );
+        let (nodes, links) = sankey_chart.get_layout(200.0, 100.0);
+        let layers: Vec<usize> = nodes.iter().map(|item| item.layer).collect();
+        // the sink nodes are aligned to the last layer
+        assert_eq!(vec![0, 0, 2, 1, 2], layers);
+        assert_eq!(
+            vec![7.0, 6.0, 6.0, 7.0, 3.0],
+            nodes.iter().map(|item| item.value).collect::<Vec<f32>>()
+        );
+        // a and c are on the top to avoid crossing of the heavy links
+        assert!(nodes[0].y < nodes[1].y);
+        assert!(nodes[2].y < nodes[4].y);
+        assert_eq!(5, links.len());
+        // the thickness of link is proportional to the value
+        assert_eq!(links[0].width, links[1].width);
+        assert_eq!(
+            format!("{:.2}", links[0].width * 2.0 / 5.0),
+            format!("{:.2}", links[2].width)
+        );
+    }
+
+    #[test]
+    fn sankey_basic() {
+        let mut sankey_chart = SankeyChart::new(
+            vec!["gateway", "auth", "orders", "payments", "inventory", "db"],
+            vec![
+                ("gateway", "auth", 120.0).into(),
+                ("gateway", "orders", 80.0).into(),
+                ("gateway", "inventory", 40.0).into(),
+                ("orders", "payments", 50.0).into(),
+                ("orders", "inventory", 30.0).into(),
+                ("auth", "db", 120.0).into(),
+                ("payments", "db", 50.0).into(),
+                ("inventory", "db", 70.0).into(),
+            ],
+        );
+        sankey_chart.title_text = "Request Flow".to_string();
+        assert_eq!(
+            include_str!("../../asset/sankey_chart/basic.svg"),
+            sankey_chart.svg().unwrap()
+        );
+    }
+}
@@ -15,6 +15,49 @@ use charts_rs_derive::Chart;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+/// The bubble mode of scatter chart, each point of series data is (x, y, size),
+/// or (x, y, size, color value) if the color value is enabled.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ScatterBubble {
+    // the radius of min and max size, the area of bubble is linear to the size
+    pub min_radius: f32,
+    pub max_radius: f32,
+    // the domain of size, the range of size values is used if it is none
+    pub min_size: Option<f32>,
+    pub max_size: Option<f32>,
+    // the fourth value of point is mapped to color by series color scale
+    pub color_value: bool,
+    // size legend, it is placed like visual map
+    pub legend: Option<VisualMapOption>,
+}
+
+impl Default for ScatterBubble {
+    fn default() -> Self {
+        ScatterBubble {
+            min_radius: 4.0,
+            max_radius: 24.0,
+            min_size: None,
+            max_size: None,
+            color_value: false,
+            legend: None,
+        }
+    }
+}
+
+impl ScatterBubble {
+    /// Gets the radius of size value.
+    pub fn get_radius(&self, size: f32, min_size: f32, max_size: f32) -> f32 {
+        let percent = if max_size > min_size {
+            ((size - min_size) / (max_size - min_size)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let min_area = self.min_radius * self.min_radius;
+        let max_area = self.max_radius * self.max_radius;
+        (min_area + (max_area - min_area) * percent).sqrt()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default, Chart)]
 pub struct ScatterChart {
     pub width: f32,
@@ -83,12 +126,26 @@ pub struct ScatterChart {
     pub series_label_font_weight: Option<String>,
     pub series_label_formatter: String,
     pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
     pub series_symbol: Option<Symbol>,
     pub series_smooth: bool,
     pub series_fill: bool,
 
     // symbol
     pub series_symbol_sizes: Vec<f32>,
+    // the color of symbol is mapped from y value(or the color value of bubble) by scale,
+    // the range of values is used if the domain of scale is empty
+    pub series_color_scale: Option<ColorScale>,
+    // bubble mode, the size of symbol is mapped from the size value
+    pub series_bubble: Option<ScatterBubble>,
+    // visual map of the color scale of series
+    pub visual_map: Option<VisualMapOption>,
+    // tooltip of the x and y values of each point
+    pub interactive: Option<bool>,
+    // title and desc(series names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the points of each series, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl ScatterChart {
@@ -103,6 +160,27 @@ impl ScatterChart {
         if let Some(series_symbol_sizes) = get_f32_slice_from_value(&value, "series_symbol_sizes") {
             s.series_symbol_sizes = series_symbol_sizes;
         }
+        if let Some(series_color_scale) = get_color_scale_from_value(&value, "series_color_scale") {
+            s.series_color_scale = Some(series_color_scale);
+        }
+        if let Some(visual_map) = get_visual_map_option_from_value(&value, "visual_map") {
+            s.visual_map = Some(visual_map);
+        }
+        if let Some(bubble) = value.get("series_bubble") {
+            let mut series_bubble = ScatterBubble::default();
+            if let Some(min_radius) = get_f32_from_value(bubble, "min_radius") {
+                series_bubble.min_radius = min_radius;
+            }
+            if let Some(max_radius) = get_f32_from_value(bubble, "max_radius") {
+                series_bubble.max_radius = max_radius;
+            }
+            series_bubble.min_size = get_f32_from_value(bubble, "min_size");
+            series_bubble.max_size = get_f32_from_value(bubble, "max_size");
+            series_bubble.color_value =
+                get_bool_from_value(bubble, "color_value").unwrap_or_default();
+            series_bubble.legend = get_visual_map_option_from_value(bubble, "legend");
+            s.series_bubble = Some(series_bubble);
+        }
         if let Some(x_axis_hidden) = get_bool_from_value(&value, "x_axis_hidden") {
             s.x_axis_hidden = x_axis_hidden;
         }
@@ -113,6 +191,9 @@ impl ScatterChart {
         if let Some(x_axis_config) = value.get("x_axis_config") {
             s.x_axis_config = get_y_axis_config_from_value(get_theme(&theme), x_axis_config);
         }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            s.interactive = Some(interactive);
+        }
         Ok(s)
     }
     /// Creates a scatter chart with  theme.
@@ -140,9 +221,154 @@ impl ScatterChart {
     pub fn new(series_list: Vec<Series>) -> ScatterChart {
         ScatterChart::new_with_theme(series_list, &get_default_theme_name())
     }
+    /// Gets the count of values of each point.
+    fn get_dimension(&self) -> usize {
+        match &self.series_bubble {
+            Some(bubble) if bubble.color_value => 4,
+            Some(_) => 3,
+            None => 2,
+        }
+    }
+    /// Gets the range of the values of point at index, the nil values are ignored.
+    fn get_value_range(&self, index: usize) -> (f32, f32) {
+        let dimension = self.get_dimension();
+        self.series_list
+            .iter()
+            .flat_map(|series| {
+                series
+                    .data
+                    .chunks(dimension)
+                    .filter(move |chunk| chunk.len() == dimension)
+                    .map(move |chunk| chunk[index])
+            })
+            .filter(|value| *value != NIL_VALUE)
+            .fold((f32::MAX, f32::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            })
+    }
+    /// Gets the index of the value mapped to color.
+    fn get_color_index(&self) -> usize {
+        if self.get_dimension() == 4 {
+            3
+        } else {
+            1
+        }
+    }
+    /// Gets the color scale of series,
+    /// the range of color values is used if the domain of scale is empty.
+    fn get_color_scale(&self) -> Option<ColorScale> {
+        let mut scale = self.series_color_scale.clone()?;
+        if scale.is_domain_empty() {
+            let (min, max) = self.get_value_range(self.get_color_index());
+            scale.min = min;
+            scale.max = max;
+        }
+        Some(scale)
+    }
+    /// Gets the domain of size values of bubble.
+    fn get_size_domain(&self, bubble: &ScatterBubble) -> (f32, f32) {
+        let (min, max) = self.get_value_range(2);
+        (
+            bubble.min_size.unwrap_or(min),
+            bubble.max_size.unwrap_or(max),
+        )
+    }
+    /// Renders the size legend of bubble at the side of canvas,
+    /// it shows the bubbles of min, middle and max size.
+    fn render_bubble_legend(&self, c: &mut Canvas, bubble: &ScatterBubble) -> canvas::Result<()> {
+        let Some(option) = &bubble.legend else {
+            return Ok(());
+        };
+        let (min_size, max_size) = self.get_size_domain(bubble);
+        let formatter = option.formatter.clone().unwrap_or_default();
+        let font_size = self.x_axis_font_size;
+        let label_margin = 4.0;
+        let item_gap = 10.0;
+        let mut items = vec![];
+        for size in [min_size, (min_size + max_size) / 2.0, max_size] {
+            let text = format_string(&format_float(size), &formatter);
+            let text_width =
+                measure_text_width_family(&self.font_family, font_size, &text)?.width();
+            items.push((
+                bubble.get_radius(size, min_size, max_size),
+                text,
+                text_width,
+            ));
+        }
+        let max_r = bubble.max_radius;
+        let vertical = !matches!(option.position, Position::Top | Position::Bottom);
+        let (width, height) = if vertical {
+            let label_width = items.iter().fold(0.0_f32, |acc, item| acc.max(item.2));
+            let height: f32 = items.iter().map(|item| item.0 * 2.0).sum();
+            (
+                max_r * 2.0 + label_margin + label_width,
+                height + item_gap * (items.len() - 1) as f32,
+            )
+        } else {
+            let width: f32 = items.iter().map(|item| (item.0 * 2.0).max(item.2)).sum();
+            (
+                width + item_gap * (items.len() - 1) as f32,
+                max_r * 2.0 + label_margin + font_size,
+            )
+        };
+        let mut legend_canvas = c.child(Box::default());
+        let (mut left, mut top) =
+            take_canvas_side(c, &option.position, &option.align, width, height);
+        for (r, text, text_width) in items {
+            let (cx, cy, x, y) = if vertical {
+                let cy = top + r;
+                top += r * 2.0 + item_gap;
+                (
+                    left + max_r,
+                    cy,
+                    left + max_r * 2.0 + label_margin,
+                    cy + font_size / 2.0 - 1.0,
+                )
+            } else {
+                let item_width = (r * 2.0).max(text_width);
+                let cx = left + item_width / 2.0;
+                left += item_width + item_gap;
+                // the bubbles are aligned to the bottom
+                (
+                    cx,
+                    top + max_r * 2.0 - r,
+                    cx - text_width / 2.0,
+                    top + max_r * 2.0 + label_margin + font_size,
+                )
+            };
+            legend_canvas.circle(Circle {
+                stroke_color: Some(self.x_axis_font_color),
+                fill: Some(self.x_axis_font_color.with_alpha(40)),
+                cx,
+                cy,
+                r,
+                ..Default::default()
+            });
+            legend_canvas.text(Text {
+                text,
+                font_family: Some(self.font_family.clone()),
+                font_size: Some(font_size),
+                font_color: Some(self.x_axis_font_color),
+                font_weight: self.x_axis_font_weight.clone(),
+                x: Some(x),
+                y: Some(y),
+                ..Default::default()
+            });
+        }
+        Ok(())
+    }
     /// Converts scatter chart to svg.
     pub fn svg(&self) -> canvas::Result<String> {
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &self.series_list,
+            self.get_dimension(),
+            self.accessible,
+            self.accessible_table,
+        );
 
         self.render_background(c.child(Box::default()));
         let mut x_axis_height = self.x_axis_height;
@@ -163,17 +389,21 @@ impl ScatterChart {
 
         let y_axis_config = self.get_y_axis_config(0);
 
+        let dimension = self.get_dimension();
         let mut y_axis_data_list = vec![];
         let mut x_axis_data_list = vec![];
         for series in self.series_list.iter() {
             for (index, data) in series.data.iter().enumerate() {
-                if index % 2 == 0 {
-                    x_axis_data_list.push(*data);
-                } else {
-                    y_axis_data_list.push(*data);
+                match index % dimension {
+                    0 => x_axis_data_list.push(*data),
+                    1 => y_axis_data_list.push(*data),
+                    _ => {}
                 }
             }
         }
+        if let Some(log_base) = y_axis_config.axis_log_base {
+            check_log_axis_values(&y_axis_data_list, log_base)?;
+        }
         let y_axis_values = get_axis_values(AxisValueParams {
             data_list: y_axis_data_list,
             split_number: y_axis_config.axis_split_number,
@@ -181,6 +411,7 @@ impl ScatterChart {
             min: y_axis_config.axis_min,
             max: y_axis_config.axis_max,
             thousands_format: false,
+            log_base: y_axis_config.axis_log_base,
         });
         let y_axis_width = if self.y_axis_hidden {
             0.0
@@ -198,8 +429,6 @@ impl ScatterChart {
             }
         };
 
-        let axis_height = c.height() - x_axis_height - axis_top;
-        let axis_width = c.width() - y_axis_width;
         // minus the height of top text area
         if axis_top > 0.0 {
             c = c.child(Box {
@@ -207,16 +436,54 @@ impl ScatterChart {
                 ..Default::default()
             });
         }
+        let color_scale = self.get_color_scale();
+        if let (Some(option), Some(scale)) = (&self.visual_map, &color_scale) {
+            render_visual_map(
+                &mut c,
+                option,
+                VisualMap {
+                    scale: scale.clone(),
+                    font_family: self.font_family.clone(),
+                    font_size: self.x_axis_font_size,
+                    font_color: Some(self.x_axis_font_color),
+                    font_weight: self.x_axis_font_weight.clone(),
+                    ..Default::default()
+                },
+            )?;
+        }
+        if let Some(bubble) = &self.series_bubble {
+            self.render_bubble_legend(&mut c, bubble)?;
+        }
+        let axis_height = c.height() - x_axis_height;
+        let axis_width = c.width() - y_axis_width;
 
         // grid
-        self.render_grid(
+        if y_axis_config.axis_log_base.is_some() {
+            // the ticks of log axis are the powers of base
+            let horizontals = y_axis_values.data.len().saturating_sub(1);
             c.child(Box {
                 left: y_axis_width,
                 ..Default::default()
-            }),
-            axis_width,
-            axis_height,
-        );
+            })
+            .grid(Grid {
+                right: axis_width,
+                bottom: axis_height,
+                color: Some(self.grid_stroke_color),
+                stroke_width: self.grid_stroke_width,
+                horizontals,
+                hidden_horizontals: vec![horizontals],
+                ..Default::default()
+            });
+        } else {
+            self.render_grid(
+                c.child(Box {
+                    left: y_axis_width,
+                    ..Default::default()
+                }),
+                axis_width,
+                axis_height,
+            );
+        }
         let x_axis_width = c.width() - y_axis_width;
         c.child(Box {
             left: y_axis_width,
@@ -280,6 +547,11 @@ impl ScatterChart {
             ..Default::default()
         });
         let default_symbol_size = 10.0_f32;
+        let color_index = self.get_color_index();
+        let size_domain = self
+            .series_bubble
+            .as_ref()
+            .map(|bubble| self.get_size_domain(bubble));
         for (index, series) in self.series_list.iter().enumerate() {
             let mut color = get_color(&self.series_colors, series.index.unwrap_or(index));
             let symbol_size = self
@@ -287,30 +559,104 @@ impl ScatterChart {
                 .get(series.index.unwrap_or(index))
                 .unwrap_or(&default_symbol_size);
             color = color.with_alpha(210);
-            for chunk in series.data.chunks(2) {
-                if chunk.len() != 2 {
-                    continue;
-                }
+            let mut points: Vec<&[f32]> = series
+                .data
+                .chunks(dimension)
+                .filter(|chunk| chunk.len() == dimension)
+                .collect();
+            // the larger bubble is rendered first, so the smaller one is not covered
+            if dimension > 2 {
+                points.sort_by(|a, b| b[2].total_cmp(&a[2]));
+            }
+            for chunk in points {
                 let x = content_width - x_axis_values.get_offset_height(chunk[0], content_width);
                 let y = y_axis_values.get_offset_height(chunk[1], content_height);
-                content_canvas.circle(Circle {
-                    fill: Some(color),
-                    cx: x,
-                    cy: y,
-                    r: *symbol_size,
-                    ..Default::default()
-                });
+                let fill = if let Some(scale) = &color_scale {
+                    scale.get_color(chunk[color_index]).with_alpha(210)
+                } else {
+                    color
+                };
+                let circle = if let (Some(bubble), Some((min_size, max_size))) =
+                    (&self.series_bubble, size_domain)
+                {
+                    Circle {
+                        stroke_color: Some(fill.with_alpha(255)),
+                        fill: Some(fill.with_alpha(160)),
+                        stroke_width: 1.0,
+                        cx: x,
+                        cy: y,
+                        r: bubble.get_radius(chunk[2], min_size, max_size),
+                    }
+                } else {
+                    Circle {
+                        fill: Some(fill),
+                        cx: x,
+                        cy: y,
+                        r: *symbol_size,
+                        ..Default::default()
+                    }
+                };
+                if content_canvas.interactive {
+                    let value = chunk
+                        .iter()
+                        .map(|item| format_float(*item))
+                        .collect::<Vec<String>>()
+                        .join(",");
+                    content_canvas
+                        .interactive_child(Interactive::new_item(
+                            &series.name,
+                            None,
+                            value.clone(),
+                            &format!("({value})"),
+                        ))
+                        .circle(circle);
+                } else {
+                    content_canvas.circle(circle);
+                }
             }
         }
 
+        // trend line
+        let mut label_index = 0;
+        for (index, series) in self.series_list.iter().enumerate() {
+            if series.trend_lines.is_empty() {
+                continue;
+            }
+            let data = series
+                .data
+                .chunks(dimension)
+                .filter(|chunk| chunk.len() == dimension)
+                .map(|chunk| (chunk[0], chunk[1]))
+                .collect();
+            label_index += render_trend_lines(
+                &mut content_canvas,
+                TrendLineParams {
+                    trend_lines: &series.trend_lines,
+                    data,
+                    color: get_color(&self.series_colors, series.index.unwrap_or(index)),
+                    font_family: &self.font_family,
+                    font_size: self.series_label_font_size,
+                    height: content_height,
+                },
+                label_index,
+                |x, y| Point {
+                    x: content_width - x_axis_values.get_offset_height(x, content_width),
+                    y: y_axis_values.get_offset_height(y, content_height),
+                },
+            );
+        }
+
         c.svg()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ScatterChart;
-    use crate::Align;
+    use super::{ScatterBubble, ScatterChart};
+    use crate::{
+        Align, ColorScale, Position, TrendLine, TrendLineCategory, VisualMapOption, NIL_VALUE,
+        PALETTE_MAGMA, PALETTE_VIRIDIS,
+    };
     use pretty_assertions::assert_eq;
     #[test]
     fn scatter_chart_basic() {
@@ -411,4 +757,226 @@ mod tests {
             scatter_chart.svg().unwrap()
         );
     }
+
+    #[test]
+    fn scatter_chart_color_scale() {
+        let mut scatter_chart = ScatterChart::new(vec![(
+            "Weight",
+            vec![
+                161.2, 51.6, 167.5, 59.0, 159.5, 49.2, 157.0, 63.0, 155.8, 53.6, 170.0, 59.0,
+                159.1, 47.6, 166.0, 69.8, 176.2, 66.8, 160.2, 75.2, 172.5, 55.2, 170.9, 54.2,
+                172.9, 62.5, 153.4, 42.0, 160.0, 50.0, 147.2, 49.8, 168.2, 49.2, 175.0, 73.2,
+                174.0, 65.6, 175.3, 71.8, 193.5, 80.7, 186.5, 72.6, 187.2, 78.8, 181.5, 74.8,
+                184.0, 86.4, 184.5, 78.4, 175.0, 62.0, 184.0, 81.6, 180.0, 76.6, 177.8, 83.6,
+                192.0, 90.0, 176.0, 74.6, 174.0, 71.0, 184.0, 79.6, 192.7, 93.8, 171.5, 70.0,
+            ],
+        )
+            .into()]);
+
+        scatter_chart.title_text = "Weight distribution".to_string();
+        scatter_chart.margin.right = 20.0;
+        scatter_chart.title_align = Align::Left;
+        scatter_chart.legend_show = Some(false);
+        scatter_chart.y_axis_configs[0].axis_min = Some(40.0);
+        scatter_chart.y_axis_configs[0].axis_max = Some(100.0);
+        scatter_chart.x_axis_config.axis_min = Some(140.0);
+        scatter_chart.x_axis_config.axis_max = Some(200.0);
+        scatter_chart.series_symbol_sizes = vec![6.0];
+        scatter_chart.series_color_scale = Some(ColorScale::new_palette(PALETTE_VIRIDIS, 0.0, 0.0));
+
+        assert_eq!(
+            include_str!("../../asset/scatter_chart/color_scale.svg"),
+            scatter_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn scatter_chart_color_scale_nil_value() {
+        let mut scatter_chart = ScatterChart::new(vec![(
+            "Weight",
+            vec![161.2, 51.6, 167.5, NIL_VALUE, 159.5, 49.2, 157.0, 63.0],
+        )
+            .into()]);
+        assert_eq!((49.2, 63.0), scatter_chart.get_value_range(1));
+
+        scatter_chart.series_color_scale = Some(ColorScale::new_palette(PALETTE_VIRIDIS, 0.0, 0.0));
+        let scale = scatter_chart.get_color_scale().unwrap();
+        assert_eq!((49.2, 63.0), (scale.min, scale.max));
+    }
+
+    #[test]
+    fn scatter_chart_visual_map() {
+        let mut scatter_chart = ScatterChart::new(vec![(
+            "Weight",
+            vec![
+                161.2, 51.6, 167.5, 59.0, 159.5, 49.2, 157.0, 63.0, 155.8, 53.6, 170.0, 59.0,
+                159.1, 47.6, 166.0, 69.8, 176.2, 66.8, 160.2, 75.2, 172.5, 55.2, 170.9, 54.2,
+                172.9, 62.5, 153.4, 42.0, 160.0, 50.0, 147.2, 49.8, 168.2, 49.2, 175.0, 73.2,
+                174.0, 65.6, 175.3, 71.8, 193.5, 80.7, 186.5, 72.6, 187.2, 78.8, 181.5, 74.8,
+                184.0, 86.4, 184.5, 78.4, 175.0, 62.0, 184.0, 81.6, 180.0, 76.6, 177.8, 83.6,
+                192.0, 90.0, 176.0, 74.6, 174.0, 71.0, 184.0, 79.6, 192.7, 93.8, 171.5, 70.0,
+            ],
+        )
+            .into()]);
+
+        scatter_chart.title_text = "Weight distribution".to_string();
+        scatter_chart.margin.right = 20.0;
+        scatter_chart.title_align = Align::Left;
+        scatter_chart.legend_show = Some(false);
+        scatter_chart.y_axis_configs[0].axis_min = Some(40.0);
+        scatter_chart.y_axis_configs[0].axis_max = Some(100.0);
+        scatter_chart.x_axis_config.axis_min = Some(140.0);
+        scatter_chart.x_axis_config.axis_max = Some(200.0);
+        scatter_chart.series_symbol_sizes = vec![6.0];
+        let mut color_scale = ColorScale::new_palette(PALETTE_VIRIDIS, 40.0, 100.0);
+        color_scale.steps = Some(6);
+        scatter_chart.series_color_scale = Some(color_scale);
+        scatter_chart.visual_map = Some(VisualMapOption {
+            position: Position::Top,
+            align: Align::Right,
+            formatter: Some("{c}kg".to_string()),
+            length: 240.0,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            include_str!("../../asset/scatter_chart/visual_map.svg"),
+            scatter_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn scatter_bubble_radius() {
+        let bubble = ScatterBubble {
+            min_radius: 3.0,
+            max_radius: 5.0,
+            ..Default::default()
+        };
+        assert_eq!(3.0, bubble.get_radius(0.0, 0.0, 100.0));
+        assert_eq!(5.0, bubble.get_radius(100.0, 0.0, 100.0));
+        // the area is linear to the size
+        assert_eq!(4.1231055, bubble.get_radius(50.0, 0.0, 100.0));
+        assert_eq!(3.0, bubble.get_radius(-10.0, 0.0, 100.0));
+        assert_eq!(5.0, bubble.get_radius(10.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn scatter_chart_bubble() {
+        let mut scatter_chart = ScatterChart::new(vec![
+            (
+                "Asia",
+                vec![
+                    // income, life expectancy, population, co2 per capita
+                    12.5, 77.1, 1412.0, 8.0, 6.9, 70.8, 1408.0, 1.9, 42.1, 84.5, 125.0, 8.5, 12.1,
+                    71.7, 275.0, 2.3, 46.8, 83.7, 5.6, 8.9,
+                ],
+            )
+                .into(),
+            (
+                "Europe",
+                vec![
+                    51.2, 80.6, 83.2, 8.1, 44.9, 82.3, 67.8, 4.7, 46.5, 80.7, 67.3, 5.0, 34.8,
+                    82.7, 59.0, 5.3, 64.7, 83.0, 5.4, 7.5,
+                ],
+            )
+                .into(),
+            (
+                "Africa",
+                vec![
+                    2.1, 52.7, 218.5, 0.6, 4.3, 71.8, 111.0, 2.3, 1.0, 65.0, 123.4, 0.1, 6.8, 62.3,
+                    59.9, 6.7,
+                ],
+            )
+                .into(),
+        ]);
+
+        scatter_chart.title_text = "Income and life expectancy".to_string();
+        scatter_chart.margin.right = 20.0;
+        scatter_chart.title_align = Align::Left;
+        scatter_chart.legend_align = Align::Right;
+        scatter_chart.y_axis_configs[0].axis_min = Some(50.0);
+        scatter_chart.y_axis_configs[0].axis_max = Some(90.0);
+        scatter_chart.y_axis_configs[0].axis_split_number = 4;
+        scatter_chart.x_axis_config.axis_min = Some(0.0);
+        scatter_chart.x_axis_config.axis_max = Some(70.0);
+        scatter_chart.x_axis_config.axis_split_number = 7;
+        scatter_chart.x_axis_config.axis_formatter = Some("{c}k".to_string());
+        scatter_chart.series_color_scale = Some(ColorScale::new_palette(PALETTE_MAGMA, 0.0, 10.0));
+        scatter_chart.visual_map = Some(VisualMapOption {
+            position: Position::Right,
+            formatter: Some("{c}t".to_string()),
+            ..Default::default()
+        });
+        scatter_chart.series_bubble = Some(ScatterBubble {
+            color_value: true,
+            legend: Some(VisualMapOption {
+                position: Position::Bottom,
+                align: Align::Right,
+                formatter: Some("{c}M".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        scatter_chart.height = 480.0;
+
+        assert_eq!(
+            include_str!("../../asset/scatter_chart/bubble.svg"),
+            scatter_chart.svg().unwrap()
+        );
+    }
+
+    #[test]
+    fn scatter_chart_trend_line() {
+        let mut scatter_chart = ScatterChart::new(vec![
+            (
+                "Female",
+                vec![
+                    161.2, 51.6, 167.5, 59.0, 159.5, 49.2, 157.0, 63.0, 155.8, 53.6, 170.0, 59.0,
+                    159.1, 47.6, 166.0, 69.8, 176.2, 66.8, 160.2, 75.2, 172.5, 55.2, 170.9, 54.2,
+                    172.9, 62.5, 153.4, 42.0, 160.0, 50.0, 147.2, 49.8, 168.2, 49.2, 175.0, 73.2,
+                    157.0, 47.8, 167.6, 68.8, 159.5, 50.6, 175.0, 82.5, 166.8, 57.2, 176.5, 87.8,
+                    170.2, 72.8,
+                ],
+            )
+                .into(),
+            (
+                "Male",
+                vec![
+                    174.0, 65.6, 175.3, 71.8, 193.5, 80.7, 186.5, 72.6, 187.2, 78.8, 181.5, 74.8,
+                    184.0, 86.4, 184.5, 78.4, 175.0, 62.0, 184.0, 81.6, 180.0, 76.6, 177.8, 83.6,
+                    192.0, 90.0, 176.0, 74.6, 174.0, 71.0, 184.0, 79.6, 192.7, 93.8, 171.5, 70.0,
+                    173.0, 72.4, 176.0, 85.9, 176.0, 78.8, 180.5, 77.8, 172.7, 66.2, 176.0, 86.4,
+                    173.5, 81.8,
+                ],
+            )
+                .into(),
+        ]);
+
+        scatter_chart.title_text = "Male and female height and weight distribution".to_string();
+        scatter_chart.margin.right = 20.0;
+        scatter_chart.title_align = Align::Left;
+        scatter_chart.legend_align = Align::Right;
+        scatter_chart.y_axis_configs[0].axis_min = Some(40.0);
+        scatter_chart.y_axis_configs[0].axis_max = Some(130.0);
+        scatter_chart.y_axis_configs[0].axis_formatter = Some("{c} kg".to_string());
+
+        scatter_chart.x_axis_config.axis_min = Some(140.0);
+        scatter_chart.x_axis_config.axis_max = Some(230.0);
+        scatter_chart.x_axis_config.axis_formatter = Some("{c} cm".to_string());
+
+        scatter_chart.series_symbol_sizes = vec![6.0, 6.0];
+
+        scatter_chart.series_list[0].trend_lines = vec![TrendLine {
+            label_show: true,
+            ..Default::default()
+        }];
+        scatter_chart.series_list[1].trend_lines = vec![TrendLine {
+            category: TrendLineCategory::Exponential,
+            label_show: true,
+        }];
+        assert_eq!(
+            include_str!("../../asset/scatter_chart/trend_line.svg"),
+            scatter_chart.svg().unwrap()
+        );
+    }
 }
@@ -22,6 +22,16 @@ pub struct TableCellStyle {
     pub indexes: Vec<usize>,
 }
 
+/// Parses the numeric value of cell, e.g. 12.5, -7.49% or +4.32%.
+fn parse_cell_value(value: &str) -> Option<f32> {
+    value
+        .trim()
+        .trim_end_matches('%')
+        .trim_start_matches('+')
+        .parse::<f32>()
+        .ok()
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct TableChart {
     pub width: f32,
@@ -69,6 +79,13 @@ pub struct TableChart {
     pub body_background_colors: Vec<Color>,
 
     pub cell_styles: Vec<TableCellStyle>,
+    // the background color of numeric body cell is mapped by the scale of column,
+    // the range of column values is used if the domain of scale is empty
+    pub column_color_scales: Vec<Option<ColorScale>>,
+    // title and desc(sub title) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden copy of the table rows, it implies accessible
+    pub accessible_table: Option<bool>,
 }
 
 impl TableChart {
@@ -95,6 +112,12 @@ impl TableChart {
         if let Some(title_text) = get_string_from_value(&data, "title_text") {
             self.title_text = title_text;
         }
+        if let Some(accessible) = get_bool_from_value(&data, "accessible") {
+            self.accessible = Some(accessible);
+        }
+        if let Some(accessible_table) = get_bool_from_value(&data, "accessible_table") {
+            self.accessible_table = Some(accessible_table);
+        }
         if let Some(title_font_size) = get_f32_from_value(&data, "title_font_size") {
             self.title_font_size = title_font_size;
         }
@@ -180,6 +203,11 @@ impl TableChart {
         if let Some(spans) = get_f32_slice_from_value(&data, "spans") {
             self.spans = spans;
         }
+        if let Some(column_color_scales) =
+            get_color_scale_slice_from_value(&data, "column_color_scales")
+        {
+            self.column_color_scales = column_color_scales;
+        }
         if let Some(text_aligns) = get_align_slice_from_value(&data, "text_aligns") {
             self.text_aligns = text_aligns;
         }
@@ -358,6 +386,18 @@ impl TableChart {
         }
 
         let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        let accessible_table = self.accessible_table.unwrap_or_default();
+        if self.accessible.unwrap_or_default() || accessible_table {
+            c.accessibility = Some(Accessibility {
+                title: self.title_text.clone(),
+                desc: self.sub_title_text.clone(),
+                table: if accessible_table {
+                    Some(self.data.clone())
+                } else {
+                    None
+                },
+            });
+        }
 
         if !self.title_text.is_empty() {
             let mut title_height = self.title_height;
@@ -449,6 +489,32 @@ impl TableChart {
             }
             table_content_list.push(row_content_list);
         }
+        // the value range of each column with color scale
+        let column_ranges: Vec<(f32, f32)> = (0..column_count)
+            .map(|column| {
+                self.data
+                    .iter()
+                    .skip(1)
+                    .filter_map(|items| items.get(column).and_then(|item| parse_cell_value(item)))
+                    .fold((f32::MAX, f32::MIN), |(min, max), value| {
+                        (min.min(value), max.max(value))
+                    })
+            })
+            .collect();
+        let get_scale_color = |row: usize, column: usize| -> Option<Color> {
+            if row == 0 {
+                return None;
+            }
+            let scale = self.column_color_scales.get(column)?.as_ref()?;
+            let value = parse_cell_value(self.data.get(row)?.get(column)?)?;
+            if scale.is_domain_empty() {
+                let (min, max) = column_ranges[column];
+                Some(scale.get_color_with_domain(value, min, max))
+            } else {
+                Some(scale.get_color(value))
+            }
+        };
+
         let mut top = 0.0;
         let body_background_color_count = self.body_background_colors.len();
 
@@ -510,6 +576,21 @@ impl TableChart {
                 let mut cell_font_color = font_color;
                 let mut cell_font_weight = font_weight.clone();
 
+                if let Some(value) = get_scale_color(i, j) {
+                    cell_font_color = if value.is_light() {
+                        (70, 70, 70).into()
+                    } else {
+                        Color::white()
+                    };
+                    c.rect(Rect {
+                        fill: Some(value),
+                        left,
+                        top: top + 1.0,
+                        width: span_width,
+                        height: row_height - 1.0,
+                        ..Default::default()
+                    });
+                }
                 // get the table cell's background color
                 if let Some(cell_style) = find_cell_style(i, j) {
                     if let Some(value) = cell_style.font_color {
@@ -587,7 +668,9 @@ impl TableChart {
 #[cfg(test)]
 mod tests {
     use super::{TableCellStyle, TableChart};
-    use crate::{Align, THEME_ANT, THEME_DARK, THEME_GRAFANA};
+    use crate::{
+        Align, ColorScale, PALETTE_MAGMA, PALETTE_RDBU, THEME_ANT, THEME_DARK, THEME_GRAFANA,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -803,4 +886,32 @@ mod tests {
             table_chart.svg().unwrap()
         );
     }
+
+    #[test]
+    fn table_color_scale() {
+        let mut table_chart = TableChart::new(
+            [
+                ["Name", "Price", "Change"],
+                ["Datadog Inc", "97.32", "-7.49%"],
+                ["Hashicorp Inc", "28.66", "-9.25%"],
+                ["Gitlab Inc", "51.63", "+4.32%"],
+                ["Cloudflare Inc", "78.40", "+1.05%"],
+                ["Elastic NV", "105.21", "-0.62%"],
+            ]
+            .iter()
+            .map(|items| items.iter().map(|item| item.to_string()).collect())
+            .collect(),
+        );
+        table_chart.title_text = "NASDAQ".to_string();
+        table_chart.text_aligns = vec![Align::Left, Align::Center, Align::Center];
+        let mut price_scale = ColorScale::new_palette(PALETTE_MAGMA, 0.0, 0.0);
+        price_scale.steps = Some(4);
+        let mut change_scale = ColorScale::new_palette(PALETTE_RDBU, -10.0, 10.0);
+        change_scale.mid = Some(0.0);
+        table_chart.column_color_scales = vec![None, Some(price_scale), Some(change_scale)];
+        assert_eq!(
+            include_str!("../../asset/table_chart/color_scale.svg"),
+            table_chart.svg().unwrap()
+        );
+    }
 }
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::color::Color;
+use super::color::{Color, PatternCategory};
 use super::common::Align;
 use super::font::DEFAULT_FONT_FAMILY;
 use super::util::Box;
@@ -31,6 +31,7 @@ pub static DEFAULT_SERIES_STROKE_WIDTH: f32 = 2.0;
 pub static THEME_DARK: &str = "dark";
 pub static THEME_ANT: &str = "ant";
 pub static THEME_GRAFANA: &str = "grafana";
+pub static THEME_PRINT: &str = "print";
 
 static LIGHT_THEME_NAME: &str = "light";
 
@@ -88,6 +89,8 @@ pub struct Theme {
     pub series_label_font_size: f32,
     pub series_label_font_color: Color,
     pub series_colors: Vec<Color>,
+    // pattern fills of series, it's useful for monochrome printing
+    pub series_patterns: Option<Vec<PatternCategory>>,
 
     // table
     pub table_header_color: Color,
@@ -153,6 +156,7 @@ static LIGHT_THEME: Lazy<Theme> = Lazy::new(|| {
             "#9a60b4".into(),
             "#ea7ccc".into(),
         ],
+        series_patterns: None,
 
         table_header_color: (242, 243, 245).into(),
         table_body_colors: vec![(255, 255, 255).into()],
@@ -220,6 +224,7 @@ static DARK_THEME: Lazy<Theme> = Lazy::new(|| {
             "#9a60b4".into(),
             "#ea7ccc".into(),
         ],
+        series_patterns: None,
 
         table_header_color: bg_color,
         table_body_colors: vec![bg_color.with_alpha(230)],
@@ -286,6 +291,7 @@ static ANT_THEME: Lazy<Theme> = Lazy::new(|| {
             "#945fb9".into(),
             "#ff9845".into(),
         ],
+        series_patterns: None,
 
         table_header_color: (250, 250, 250).into(),
         table_body_colors: vec![(255, 255, 255).into()],
@@ -354,6 +360,7 @@ static VINTAGE_THEME: Lazy<Theme> = Lazy::new(|| {
             "#724e58".into(),
             "#4b565b".into(),
         ],
+        series_patterns: None,
 
         table_header_color: (250, 250, 250).into(),
         table_body_colors: vec![(255, 255, 255).into()],
@@ -420,6 +427,7 @@ static SHINE_THEME: Lazy<Theme> = Lazy::new(|| {
             "#cda819".into(),
             "#32a487".into(),
         ],
+        series_patterns: None,
 
         table_header_color: (250, 250, 250).into(),
         table_body_colors: vec![(255, 255, 255).into()],
@@ -484,6 +492,7 @@ static WALDEN_THEME: Lazy<Theme> = Lazy::new(|| {
             "#c4ebad".into(),
             "#96dee8".into(),
         ],
+        series_patterns: None,
 
         table_header_color: (250, 250, 250).into(),
         table_body_colors: vec![(255, 255, 255).into()],
@@ -548,6 +557,7 @@ static WESTEROS_THEME: Lazy<Theme> = Lazy::new(|| {
             "#a5e7f0".into(),
             "#cbb0e3".into(),
         ],
+        series_patterns: None,
 
         table_header_color: (250, 250, 250).into(),
         table_body_colors: vec![(255, 255, 255).into()],
@@ -615,6 +625,7 @@ static CHALK_THEME: Lazy<Theme> = Lazy::new(|| {
             "#d2f5a6".into(),
             "#76f2f2".into(),
         ],
+        series_patterns: None,
 
         table_header_color: bg_color,
         table_body_colors: vec![bg_color.with_alpha(230)],
@@ -682,6 +693,7 @@ static GRAFANA_THEME: Lazy<Theme> = Lazy::new(|| {
             "#705DA0".into(),
             "#508642".into(),
         ],
+        series_patterns: None,
 
         table_header_color: bg_color,
         table_body_colors: vec![bg_color.with_alpha(230)],
@@ -749,6 +761,7 @@ static SHADCN_THEME: Lazy<Theme> = Lazy::new(|| {
             "#3b86f7".into(),
             "#f17e92".into(),
         ],
+        series_patterns: None,
 
         table_header_color: bg_color.with_alpha(230),
         table_body_colors: vec![bg_color],
@@ -756,6 +769,22 @@ static SHADCN_THEME: Lazy<Theme> = Lazy::new(|| {
     }
 });
 
+// monochrome theme for printing, the series are distinguished by patterns
+static PRINT_THEME: Lazy<Theme> = Lazy::new(|| Theme {
+    series_colors: vec![
+        "#262626".into(),
+        "#595959".into(),
+        "#8c8c8c".into(),
+        "#404040".into(),
+    ],
+    series_patterns: Some(vec![
+        PatternCategory::DiagonalHatch,
+        PatternCategory::Dots,
+        PatternCategory::CrossHatch,
+    ]),
+    ..LIGHT_THEME.clone()
+});
+
 type Themes = AHashMap<String, Arc<Theme>>;
 static THEME_MAP: Lazy<ArcSwap<Themes>> = Lazy::new(|| {
     let mut m = AHashMap::new();
@@ -768,6 +797,7 @@ static THEME_MAP: Lazy<ArcSwap<Themes>> = Lazy::new(|| {
     m.insert("westeros".to_string(), Arc::new(WESTEROS_THEME.clone()));
     m.insert("chalk".to_string(), Arc::new(CHALK_THEME.clone()));
     m.insert("shadcn".to_string(), Arc::new(SHADCN_THEME.clone()));
+    m.insert("print".to_string(), Arc::new(PRINT_THEME.clone()));
     m.insert("light".to_string(), Arc::new(LIGHT_THEME.clone()));
     ArcSwap::from_pointee(m)
 });
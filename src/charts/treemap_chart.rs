@@ -0,0 +1,572 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::canvas;
+use super::color::*;
+use super::common::*;
+use super::component::*;
+use super::font::{measure_text_width_family_weight, text_wrap_fit};
+use super::params::*;
+use super::theme::{get_default_theme_name, get_theme, Theme, DEFAULT_Y_AXIS_WIDTH};
+use super::util::*;
+use super::Canvas;
+use crate::charts::measure_text_width_family;
+use charts_rs_derive::Chart;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The node of treemap, the value of parent node is the sum of children.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct TreemapNode {
+    pub name: String,
+    pub value: f32,
+    pub children: Vec<TreemapNode>,
+}
+
+impl TreemapNode {
+    /// Creates a parent node of treemap.
+    pub fn new_parent(name: &str, children: Vec<TreemapNode>) -> Self {
+        TreemapNode {
+            name: name.to_string(),
+            children,
+            ..Default::default()
+        }
+    }
+    /// Gets the value of node, it is the sum of children for parent node.
+    pub fn get_value(&self) -> f32 {
+        if self.children.is_empty() {
+            return self.value.max(0.0);
+        }
+        self.children.iter().map(|item| item.get_value()).sum()
+    }
+}
+
+impl From<(&str, f32)> for TreemapNode {
+    fn from(value: (&str, f32)) -> Self {
+        TreemapNode {
+            name: value.0.to_string(),
+            value: value.1,
+            ..Default::default()
+        }
+    }
+}
+
+/// Gets the nodes of treemap from serde json recursively.
+fn get_treemap_nodes_from_value(value: &serde_json::Value, key: &str) -> Option<Vec<TreemapNode>> {
+    let nodes = value.get(key)?.as_array()?;
+    Some(
+        nodes
+            .iter()
+            .map(|item| TreemapNode {
+                name: get_string_from_value(item, "name").unwrap_or_default(),
+                value: get_f32_from_value(item, "value").unwrap_or_default(),
+                children: get_treemap_nodes_from_value(item, "children").unwrap_or_default(),
+            })
+            .collect(),
+    )
+}
+
+// the worst aspect ratio of row, the row is laid along the side
+fn get_worst_ratio(row: &[f32], side: f32) -> f32 {
+    let sum: f32 = row.iter().sum();
+    let max = row.iter().fold(f32::MIN, |acc, item| acc.max(*item));
+    let min = row.iter().fold(f32::MAX, |acc, item| acc.min(*item));
+    let side2 = side * side;
+    let sum2 = sum * sum;
+    f32::max(side2 * max / sum2, sum2 / (side2 * min))
+}
+
+/// Lays out the values by squarified algorithm,
+/// the values should be sorted descending and the boxes are in the same order.
+pub(crate) fn squarify(values: &[f32], b: Box) -> Vec<Box> {
+    let total: f32 = values.iter().sum();
+    let (mut left, mut top) = (b.left, b.top);
+    let (mut width, mut height) = (b.width(), b.height());
+    if total <= 0.0 || width <= 0.0 || height <= 0.0 {
+        return vec![Box::default(); values.len()];
+    }
+    let scale = width * height / total;
+    let areas: Vec<f32> = values.iter().map(|item| item * scale).collect();
+    let mut result = Vec::with_capacity(values.len());
+    let mut i = 0;
+    while i < areas.len() {
+        let side = width.min(height);
+        let mut j = i + 1;
+        while j < areas.len()
+            && get_worst_ratio(&areas[i..=j], side) <= get_worst_ratio(&areas[i..j], side)
+        {
+            j += 1;
+        }
+        let row = &areas[i..j];
+        let row_sum: f32 = row.iter().sum();
+        let thickness = if side > 0.0 { row_sum / side } else { 0.0 };
+        let mut offset = 0.0;
+        for area in row.iter() {
+            let length = if thickness > 0.0 {
+                area / thickness
+            } else {
+                0.0
+            };
+            // the row is a column on the left side of wide area
+            if width >= height {
+                result.push(Box {
+                    left,
+                    top: top + offset,
+                    right: left + thickness,
+                    bottom: top + offset + length,
+                });
+            } else {
+                result.push(Box {
+                    left: left + offset,
+                    top,
+                    right: left + offset + length,
+                    bottom: top + thickness,
+                });
+            }
+            offset += length;
+        }
+        if width >= height {
+            left += thickness;
+            width -= thickness;
+        } else {
+            top += thickness;
+            height -= thickness;
+        }
+        i = j;
+    }
+    result
+}
+
+// mix the color with background, the ratio is the weight of background
+fn mix_color(color: Color, background: Color, ratio: f32) -> Color {
+    let mix = |a: u8, b: u8| -> u8 { (a as f32 * (1.0 - ratio) + b as f32 * ratio).round() as u8 };
+    Color {
+        r: mix(color.r, background.r),
+        g: mix(color.g, background.g),
+        b: mix(color.b, background.b),
+        a: color.a,
+    }
+}
+
+// the shared values of nodes under the same root
+struct TreemapRoot<'a> {
+    name: &'a str,
+    color: Color,
+    // the sum of all roots
+    total: f32,
+}
+
+#[derive(Clone, Debug, Default, Chart)]
+pub struct TreemapChart {
+    pub width: f32,
+    pub height: f32,
+    pub x: f32,
+    pub y: f32,
+    pub margin: Box,
+    // no use, but for derive chart
+    series_list: Vec<Series>,
+    pub data: Vec<TreemapNode>,
+    // the gap between nested nodes
+    pub node_gap: f32,
+    // show the name of parent node as header
+    pub parent_header: Option<bool>,
+    pub header_height: f32,
+    pub font_family: String,
+    pub background_color: Color,
+    pub is_light: bool,
+
+    // title
+    pub title_text: String,
+    pub title_font_size: f32,
+    pub title_font_color: Color,
+    pub title_font_weight: Option<String>,
+    pub title_margin: Option<Box>,
+    pub title_align: Align,
+    pub title_height: f32,
+
+    // sub title
+    pub sub_title_text: String,
+    pub sub_title_font_size: f32,
+    pub sub_title_font_color: Color,
+    pub sub_title_font_weight: Option<String>,
+    pub sub_title_margin: Option<Box>,
+    pub sub_title_align: Align,
+    pub sub_title_height: f32,
+
+    // legend
+    pub legend_font_size: f32,
+    pub legend_font_color: Color,
+    pub legend_font_weight: Option<String>,
+    pub legend_align: Align,
+    pub legend_margin: Option<Box>,
+    pub legend_category: LegendCategory,
+    pub legend_show: Option<bool>,
+
+    // x axis
+    pub x_axis_data: Vec<String>,
+    pub x_axis_height: f32,
+    pub x_axis_stroke_color: Color,
+    pub x_axis_font_size: f32,
+    pub x_axis_font_color: Color,
+    pub x_axis_font_weight: Option<String>,
+    pub x_axis_name_gap: f32,
+    pub x_axis_name_rotate: f32,
+    pub x_axis_margin: Option<Box>,
+    pub x_boundary_gap: Option<bool>,
+
+    // y axis
+    pub y_axis_configs: Vec<YAxisConfig>,
+
+    // grid
+    pub grid_stroke_color: Color,
+    pub grid_stroke_width: f32,
+
+    // series
+    pub series_stroke_width: f32,
+    pub series_label_font_color: Color,
+    pub series_label_font_size: f32,
+    pub series_label_font_weight: Option<String>,
+    pub series_label_formatter: String,
+    pub series_colors: Vec<Color>,
+    pub series_patterns: Option<Vec<PatternCategory>>,
+    pub series_symbol: Option<Symbol>,
+    pub series_smooth: bool,
+    pub series_fill: bool,
+    // tooltip of the value and percentage of each node
+    pub interactive: Option<bool>,
+    // title and desc(root node names) of svg for screen readers
+    pub accessible: Option<bool>,
+    // hidden table of the value of each root node, it implies accessible
+    pub accessible_table: Option<bool>,
+}
+
+impl TreemapChart {
+    fn fill_default(&mut self) {
+        self.node_gap = 2.0;
+        self.header_height = 20.0;
+        self.legend_show = Some(false);
+    }
+    /// Creates a treemap chart from json.
+    pub fn from_json(data: &str) -> canvas::Result<TreemapChart> {
+        let mut t = TreemapChart {
+            ..Default::default()
+        };
+        t.fill_default();
+        let value = t.fill_option(data)?;
+        if let Some(data) = get_treemap_nodes_from_value(&value, "data") {
+            t.data = data;
+        }
+        if let Some(node_gap) = get_f32_from_value(&value, "node_gap") {
+            t.node_gap = node_gap;
+        }
+        if let Some(parent_header) = get_bool_from_value(&value, "parent_header") {
+            t.parent_header = Some(parent_header);
+        }
+        if let Some(header_height) = get_f32_from_value(&value, "header_height") {
+            t.header_height = header_height;
+        }
+        if let Some(interactive) = get_bool_from_value(&value, "interactive") {
+            t.interactive = Some(interactive);
+        }
+        Ok(t)
+    }
+    /// Creates a treemap chart with custom theme.
+    pub fn new_with_theme(data: Vec<TreemapNode>, theme: &str) -> TreemapChart {
+        let mut t = TreemapChart {
+            data,
+            ..Default::default()
+        };
+        t.fill_default();
+        t.fill_theme(get_theme(theme));
+        t
+    }
+    /// Creates a treemap chart with default theme.
+    pub fn new(data: Vec<TreemapNode>) -> TreemapChart {
+        TreemapChart::new_with_theme(data, &get_default_theme_name())
+    }
+    /// Truncates the line to fit the width of cell, the ellipsis is appended
+    /// if the line is truncated or the lines after it are dropped.
+    fn truncate_label_line(&self, line: &str, font_size: f32, width: f32, more: bool) -> String {
+        let font_weight = self.series_label_font_weight.clone().unwrap_or_default();
+        let fit = |text: &str| {
+            measure_text_width_family_weight(&self.font_family, &font_weight, font_size, text)
+                .map(|b| b.width() <= width)
+                .unwrap_or(true)
+        };
+        if !more && fit(line) {
+            return line.to_string();
+        }
+        let mut chars: Vec<char> = line.chars().collect();
+        loop {
+            let text = format!("{}…", chars.iter().collect::<String>().trim_end());
+            if chars.is_empty() || fit(&text) {
+                return text;
+            }
+            chars.pop();
+        }
+    }
+    /// Renders the label of node, the lines out of the cell are dropped
+    /// and the long line is truncated with ellipsis.
+    fn render_label(&self, c: &mut Canvas, text: &str, b: &Box, font_color: Color) {
+        let padding = 4.0;
+        let font_size = self.series_label_font_size;
+        let line_height = font_size + 2.0;
+        let width = b.width() - padding * 2.0;
+        if width < font_size || b.height() < line_height {
+            return;
+        }
+        let Ok(lines) = text_wrap_fit(&self.font_family, font_size, text, width) else {
+            return;
+        };
+        let max_count = ((b.height() - padding) / line_height).floor() as usize;
+        for (index, line) in lines.iter().take(max_count).enumerate() {
+            let more = index + 1 == max_count && lines.len() > max_count;
+            c.child(Box {
+                left: b.left + padding,
+                top: b.top + padding + line_height * (index as f32 + 1.0) - 3.0,
+                ..Default::default()
+            })
+            .text(Text {
+                text: self.truncate_label_line(line.trim(), font_size, width, more),
+                font_family: Some(self.font_family.clone()),
+                font_size: Some(font_size),
+                font_color: Some(font_color),
+                font_weight: self.series_label_font_weight.clone(),
+                ..Default::default()
+            });
+        }
+    }
+    /// Renders the node and its children.
+    fn render_node(
+        &self,
+        c: &mut Canvas,
+        node: &TreemapNode,
+        b: Box,
+        depth: usize,
+        root: &TreemapRoot,
+    ) {
+        if b.width() <= 0.0 || b.height() <= 0.0 {
+            return;
+        }
+        // the deeper node is lighter
+        let fill = mix_color(
+            root.color,
+            self.background_color,
+            (depth as f32 * 0.15).min(0.6),
+        );
+        let font_color = if fill.is_light() {
+            (70, 70, 70).into()
+        } else {
+            Color::white()
+        };
+        let value = node.get_value();
+        let rect = Rect {
+            color: Some(self.background_color),
+            fill: Some(fill),
+            left: b.left,
+            top: b.top,
+            width: b.width(),
+            height: b.height(),
+            ..Default::default()
+        };
+        let mut series_label_formatter = self.series_label_formatter.clone();
+        if series_label_formatter.is_empty() {
+            series_label_formatter = "{a}".to_string();
+        }
+        let label_text = LabelOption {
+            series_name: node.name.clone(),
+            value,
+            percentage: if root.total > 0.0 {
+                value / root.total
+            } else {
+                0.0
+            },
+            formatter: series_label_formatter,
+            ..Default::default()
+        }
+        .format();
+
+        if node.children.is_empty() {
+            if c.interactive {
+                let value = format_float(value);
+                c.interactive_child(Interactive::new_item(
+                    root.name,
+                    Some(node.name.clone()),
+                    value.clone(),
+                    &value,
+                ))
+                .rect(rect);
+            } else {
+                c.rect(rect);
+            }
+            self.render_label(c, &label_text, &b, font_color);
+            return;
+        }
+
+        c.rect(rect);
+        let mut inner = Box {
+            left: b.left + self.node_gap,
+            top: b.top + self.node_gap,
+            right: b.right - self.node_gap,
+            bottom: b.bottom - self.node_gap,
+        };
+        if self.parent_header.unwrap_or_default() && inner.height() > self.header_height {
+            let header = Box {
+                bottom: b.top + self.header_height,
+                ..b.clone()
+            };
+            self.render_label(c, &label_text, &header, font_color);
+            inner.top = header.bottom;
+        }
+        let mut children: Vec<&TreemapNode> = node
+            .children
+            .iter()
+            .filter(|item| item.get_value() > 0.0)
+            .collect();
+        children.sort_by(|a, b| b.get_value().total_cmp(&a.get_value()));
+        let values: Vec<f32> = children.iter().map(|item| item.get_value()).collect();
+        for (child, child_box) in children.iter().zip(squarify(&values, inner)) {
+            self.render_node(c, child, child_box, depth + 1, root);
+        }
+    }
+    /// Converts treemap chart to svg.
+    pub fn svg(&self) -> canvas::Result<String> {
+        let mut c = Canvas::new_width_xy(self.width, self.height, self.x, self.y);
+        c.interactive = self.interactive.unwrap_or_default();
+        // each root node is a row of data table
+        let series_list: Vec<Series> = self
+            .data
+            .iter()
+            .map(|item| (item.name.as_str(), vec![item.get_value()]).into())
+            .collect();
+        c.accessibility = get_series_accessibility(
+            &self.title_text,
+            &self.sub_title_text,
+            &series_list,
+            1,
+            self.accessible,
+            self.accessible_table,
+        );
+
+        self.render_background(c.child(Box::default()));
+        c.margin = self.margin.clone();
+
+        let title_height = self.render_title(c.child(Box::default()));
+
+        let legend_height = self.render_legend(c.child(Box::default()));
+        // get the max height of title and legend
+        let axis_top = if legend_height > title_height {
+            legend_height
+        } else {
+            title_height
+        };
+        if axis_top > 0.0 {
+            c = c.child(Box {
+                top: axis_top,
+                ..Default::default()
+            });
+        }
+
+        // the color of root node is from theme
+        let mut roots: Vec<(usize, &TreemapNode)> = self
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.get_value() > 0.0)
+            .collect();
+        roots.sort_by(|a, b| b.1.get_value().total_cmp(&a.1.get_value()));
+        let values: Vec<f32> = roots.iter().map(|(_, item)| item.get_value()).collect();
+        let total: f32 = values.iter().sum();
+        let boxes = squarify(
+            &values,
+            Box {
+                right: c.width(),
+                bottom: c.height(),
+                ..Default::default()
+            },
+        );
+        for ((index, node), b) in roots.iter().zip(boxes) {
+            let color = get_color(&self.series_colors, *index);
+            self.render_node(
+                &mut c,
+                node,
+                b,
+                0,
+                &TreemapRoot {
+                    name: &node.name,
+                    color,
+                    total,
+                },
+            );
+        }
+
+        c.svg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{squarify, TreemapChart, TreemapNode};
+    use crate::Box;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn treemap_squarify() {
+        // the example of squarified treemaps paper
+        let boxes = squarify(
+            &[6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0],
+            Box {
+                right: 6.0,
+                bottom: 4.0,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            vec![
+                "(0,0,3,2)",
+                "(0,2,3,4)",
+                "(3,0,4.7,2.3)",
+                "(4.7,0,6,2.3)",
+                "(3,2.3,4.2,4)",
+                "(4.2,2.3,5.4,4)",
+                "(5.4,2.3,6,4)",
+            ],
+            boxes
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn treemap_basic() {
+        let mut treemap_chart = TreemapChart::new(vec![
+            TreemapNode::new_parent(
+                "src",
+                vec![
+                    ("charts", 420.0).into(),
+                    ("lib.rs", 30.0).into(),
+                    ("font", 120.0).into(),
+                ],
+            ),
+            TreemapNode::new_parent(
+                "asset",
+                vec![
+                    ("image", 260.0).into(),
+                    ("font", 180.0).into(),
+                    ("bar_chart", 90.0).into(),
+                    ("line_chart", 70.0).into(),
+                ],
+            ),
+            ("README.md", 40.0).into(),
+            ("Cargo.toml", 5.0).into(),
+        ]);
+        treemap_chart.title_text = "Disk Usage".to_string();
+        treemap_chart.series_label_formatter = "{a} {c}KB".to_string();
+        assert_eq!(
+            include_str!("../../asset/treemap_chart/basic.svg"),
+            treemap_chart.svg().unwrap()
+        );
+    }
+}
@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use super::canvas;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use substring::Substring;
@@ -143,6 +144,18 @@ pub(crate) fn format_float(value: f32) -> String {
     str
 }
 
+/// Gets the quantile of sorted values by linear interpolation,
+/// the values should not be empty.
+pub(crate) fn get_quantile(sorted_values: &[f32], p: f32) -> f32 {
+    let position = (sorted_values.len() - 1) as f32 * p;
+    let index = position.floor() as usize;
+    let fraction = position - index as f32;
+    match sorted_values.get(index + 1) {
+        Some(next) => sorted_values[index] + (next - sorted_values[index]) * fraction,
+        None => sorted_values[index],
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub(crate) struct AxisValueParams {
     pub data_list: Vec<f32>,
@@ -151,12 +164,15 @@ pub(crate) struct AxisValueParams {
     pub split_number: usize,
     pub reverse: Option<bool>,
     pub thousands_format: bool,
+    pub log_base: Option<f32>,
 }
 #[derive(Clone, Debug, Default)]
 pub struct AxisValues {
     pub data: Vec<String>,
     pub min: f32,
     pub max: f32,
+    // the base of logarithmic axis
+    pub log_base: Option<f32>,
 }
 
 impl AxisValues {
@@ -164,17 +180,141 @@ impl AxisValues {
         self.max - self.min
     }
     pub(crate) fn get_offset_height(&self, value: f32, max_height: f32) -> f32 {
+        if self.log_base.is_some() {
+            // the zero value(e.g. the base of stack) is placed at the bottom
+            if value <= 0.0 {
+                return max_height;
+            }
+            let percent = (value.ln() - self.min.ln()) / (self.max.ln() - self.min.ln());
+            return max_height - percent * max_height;
+        }
         let percent = (value - self.min) / self.get_offset();
         max_height - percent * max_height
     }
 }
 
+/// Checks the values of logarithmic axis, the base should be greater than 0
+/// and not equal to 1, zero and negative values are not supported.
+pub(crate) fn check_log_axis_values(data_list: &[f32], log_base: f32) -> canvas::Result<()> {
+    if log_base <= 0.0 || log_base == 1.0 {
+        return Err(canvas::Error::Params {
+            message: format!(
+                "log base should be greater than 0 and not equal to 1, got {log_base}"
+            ),
+        });
+    }
+    for value in data_list.iter() {
+        if *value != NIL_VALUE && *value <= 0.0 {
+            return Err(canvas::Error::Params {
+                message: format!("log axis does not support zero or negative value, got {value}"),
+            });
+        }
+    }
+    Ok(())
+}
+
 const K_VALUE: f32 = 1000.00_f32;
 const M_VALUE: f32 = K_VALUE * K_VALUE;
 const G_VALUE: f32 = M_VALUE * K_VALUE;
 const T_VALUE: f32 = G_VALUE * K_VALUE;
 
+fn format_axis_value(value: f32, thousands_format: bool) -> String {
+    if thousands_format {
+        return thousands_format_float(value);
+    }
+    let mut unit = "";
+    let value = if value >= T_VALUE {
+        unit = "T";
+        value / T_VALUE
+    } else if value >= G_VALUE {
+        unit = "G";
+        value / G_VALUE
+    } else if value >= M_VALUE {
+        unit = "M";
+        value / M_VALUE
+    } else if value >= K_VALUE {
+        unit = "k";
+        value / K_VALUE
+    } else {
+        value
+    };
+    format_float(value) + unit
+}
+
+/// Gets the values of logarithmic axis, the ticks are the powers of base.
+fn get_log_axis_values(params: AxisValueParams, log_base: f32) -> AxisValues {
+    let mut min = f32::MAX;
+    let mut max = f32::MIN;
+    for item in params.data_list.iter() {
+        let value = item.to_owned();
+        if value == NIL_VALUE || value <= 0.0 {
+            continue;
+        }
+        if value > max {
+            max = value;
+        }
+        if value < min {
+            min = value;
+        }
+    }
+    if let Some(value) = params.min {
+        if value > 0.0 && value < min {
+            min = value;
+        }
+    }
+    if let Some(value) = params.max {
+        if value > max {
+            max = value;
+        }
+    }
+    if min > max {
+        min = 1.0;
+        max = log_base;
+    }
+    // the tolerance avoids the float error of log(e.g. log10(1000) = 2.9999998)
+    let tolerance = 0.0001;
+    let min_exp = (min.ln() / log_base.ln() + tolerance).floor() as i32;
+    let mut max_exp = (max.ln() / log_base.ln() - tolerance).ceil() as i32;
+    if max_exp <= min_exp {
+        max_exp = min_exp + 1;
+    }
+
+    let mut data = vec![];
+    for exp in min_exp..=max_exp {
+        let value = log_base.powi(exp);
+        if value < 0.001 {
+            // the small tick is formatted in exponent form, e.g. 1e-7
+            let str = format!("{:.2e}", value);
+            let (mantissa, exp) = str.split_once('e').unwrap_or((&str, "0"));
+            data.push(format!(
+                "{}e{exp}",
+                mantissa.trim_end_matches('0').trim_end_matches('.')
+            ));
+            continue;
+        }
+        if value < 1.0 {
+            let str = format!("{:.6}", value);
+            data.push(str.trim_end_matches('0').trim_end_matches('.').to_string());
+            continue;
+        }
+        data.push(format_axis_value(value, params.thousands_format));
+    }
+    if params.reverse.unwrap_or_default() {
+        data.reverse();
+    }
+
+    AxisValues {
+        data,
+        min: log_base.powi(min_exp),
+        max: log_base.powi(max_exp),
+        log_base: Some(log_base),
+    }
+}
+
 pub(crate) fn get_axis_values(params: AxisValueParams) -> AxisValues {
+    if let Some(log_base) = params.log_base {
+        return get_log_axis_values(params, log_base);
+    }
     let mut min = f32::MAX;
     let mut max = f32::MIN;
 
@@ -249,28 +389,8 @@ pub(crate) fn get_axis_values(params: AxisValueParams) -> AxisValues {
 
     let mut data = vec![];
     for i in 0..=split_number {
-        let mut value = min + (i as f32) * split_unit;
-        if params.thousands_format {
-            data.push(thousands_format_float(value));
-            continue;
-        }
-        let mut unit = "";
-        value = if value >= T_VALUE {
-            unit = "T";
-            value / T_VALUE
-        } else if value >= G_VALUE {
-            unit = "G";
-            value / G_VALUE
-        } else if value >= M_VALUE {
-            unit = "M";
-            value / M_VALUE
-        } else if value >= K_VALUE {
-            unit = "k";
-            value / K_VALUE
-        } else {
-            value
-        };
-        data.push(format_float(value) + unit);
+        let value = min + (i as f32) * split_unit;
+        data.push(format_axis_value(value, params.thousands_format));
     }
     if params.reverse.unwrap_or_default() {
         data.reverse();
@@ -280,6 +400,297 @@ pub(crate) fn get_axis_values(params: AxisValueParams) -> AxisValues {
         data,
         min,
         max: min + split_unit * split_number as f32,
+        log_base: None,
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TimeAxisValueParams {
+    // epoch timestamps(seconds)
+    pub data_list: Vec<i64>,
+    pub split_number: usize,
+    pub formatter: Option<String>,
+    // the domain is padded by half of the min interval of timestamps,
+    // so the bars centered on timestamps are inside the axis
+    pub boundary_gap: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TimeAxisValues {
+    pub data: Vec<String>,
+    // epoch timestamps(seconds) of the ticks
+    pub ticks: Vec<i64>,
+    pub min: i64,
+    pub max: i64,
+}
+
+impl TimeAxisValues {
+    pub(crate) fn get_offset_width(&self, value: i64, max_width: f32) -> f32 {
+        let offset = self.max - self.min;
+        if offset <= 0 {
+            return 0.0;
+        }
+        ((value - self.min) as f64 / offset as f64 * max_width as f64) as f32
+    }
+    /// Gets the positions(0.0 - 1.0) of ticks.
+    pub(crate) fn get_tick_positions(&self) -> Vec<f32> {
+        self.ticks
+            .iter()
+            .map(|item| self.get_offset_width(*item, 1.0))
+            .collect()
+    }
+    /// Gets the min width between two adjacent timestamps.
+    pub(crate) fn get_min_unit_width(&self, timestamps: &[i64], max_width: f32) -> f32 {
+        let mut values = timestamps.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        let mut unit_width = max_width / self.ticks.len().max(1) as f32;
+        for item in values.windows(2) {
+            let width = self.get_offset_width(item[1], max_width)
+                - self.get_offset_width(item[0], max_width);
+            if width < unit_width {
+                unit_width = width;
+            }
+        }
+        unit_width
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+// 1970-01-05 is monday
+const FIRST_MONDAY_OFFSET: i64 = 4 * SECONDS_PER_DAY;
+
+static TIME_INTERVALS: [(TimeUnit, i64); 26] = [
+    (TimeUnit::Second, 1),
+    (TimeUnit::Second, 5),
+    (TimeUnit::Second, 10),
+    (TimeUnit::Second, 15),
+    (TimeUnit::Second, 30),
+    (TimeUnit::Minute, 1),
+    (TimeUnit::Minute, 5),
+    (TimeUnit::Minute, 10),
+    (TimeUnit::Minute, 15),
+    (TimeUnit::Minute, 30),
+    (TimeUnit::Hour, 1),
+    (TimeUnit::Hour, 2),
+    (TimeUnit::Hour, 3),
+    (TimeUnit::Hour, 6),
+    (TimeUnit::Hour, 12),
+    (TimeUnit::Day, 1),
+    (TimeUnit::Day, 2),
+    (TimeUnit::Day, 7),
+    (TimeUnit::Month, 1),
+    (TimeUnit::Month, 3),
+    (TimeUnit::Month, 6),
+    (TimeUnit::Year, 1),
+    (TimeUnit::Year, 2),
+    (TimeUnit::Year, 5),
+    (TimeUnit::Year, 10),
+    (TimeUnit::Year, 100),
+];
+
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 {
+        yoe + era * 400 + 1
+    } else {
+        yoe + era * 400
+    };
+    (year, month, day)
+}
+
+fn get_fixed_unit_seconds(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Minute => SECONDS_PER_MINUTE,
+        TimeUnit::Hour => SECONDS_PER_HOUR,
+        TimeUnit::Day => SECONDS_PER_DAY,
+        _ => 1,
+    }
+}
+
+/// Floors the timestamp to the start of time interval.
+fn floor_time(timestamp: i64, unit: TimeUnit, step: i64) -> i64 {
+    match unit {
+        TimeUnit::Month | TimeUnit::Year => {
+            let (year, month, _) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+            let (year, month) = if unit == TimeUnit::Year {
+                (year - year.rem_euclid(step), 1)
+            } else {
+                let months = year * 12 + month - 1;
+                let months = months - months.rem_euclid(step);
+                (months.div_euclid(12), months.rem_euclid(12) + 1)
+            };
+            days_from_civil(year, month, 1) * SECONDS_PER_DAY
+        }
+        _ => {
+            let seconds = get_fixed_unit_seconds(unit) * step;
+            // the week starts from monday
+            let offset = if unit == TimeUnit::Day && step == 7 {
+                FIRST_MONDAY_OFFSET
+            } else {
+                0
+            };
+            (timestamp - offset).div_euclid(seconds) * seconds + offset
+        }
+    }
+}
+
+/// Adds the time interval to the timestamp, it should be the start of time interval.
+fn add_time(timestamp: i64, unit: TimeUnit, step: i64) -> i64 {
+    match unit {
+        TimeUnit::Month | TimeUnit::Year => {
+            let (year, month, _) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+            let step = if unit == TimeUnit::Year {
+                step * 12
+            } else {
+                step
+            };
+            let months = year * 12 + month - 1 + step;
+            days_from_civil(months.div_euclid(12), months.rem_euclid(12) + 1, 1) * SECONDS_PER_DAY
+        }
+        _ => timestamp + get_fixed_unit_seconds(unit) * step,
+    }
+}
+
+fn get_time_ticks(min: i64, max: i64, unit: TimeUnit, step: i64) -> Vec<i64> {
+    let mut value = floor_time(min, unit, step);
+    let mut ticks = vec![value];
+    while value < max || ticks.len() < 2 {
+        value = add_time(value, unit, step);
+        ticks.push(value);
+    }
+    ticks
+}
+
+pub(crate) static MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Formats the epoch timestamp(seconds, UTC), the supported specifiers are
+/// %Y, %y, %m, %b, %d, %H, %M, %S and %%.
+pub fn format_timestamp(timestamp: i64, formatter: &str) -> String {
+    let (year, month, day) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+    let seconds = timestamp.rem_euclid(SECONDS_PER_DAY);
+    let mut result = String::new();
+    let mut chars = formatter.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => result.push_str(&year.to_string()),
+            Some('y') => result.push_str(&format!("{:02}", year.rem_euclid(100))),
+            Some('m') => result.push_str(&format!("{:02}", month)),
+            Some('b') => result.push_str(MONTH_NAMES[(month - 1) as usize]),
+            Some('d') => result.push_str(&format!("{:02}", day)),
+            Some('H') => result.push_str(&format!("{:02}", seconds / SECONDS_PER_HOUR)),
+            Some('M') => result.push_str(&format!(
+                "{:02}",
+                seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE
+            )),
+            Some('S') => result.push_str(&format!("{:02}", seconds % SECONDS_PER_MINUTE)),
+            Some('%') => result.push('%'),
+            Some(other) => {
+                result.push('%');
+                result.push(other);
+            }
+            None => result.push('%'),
+        }
+    }
+    result
+}
+
+/// Gets the time axis values, the ticks are placed at calendar intervals
+/// and the labels are formatted by formatter(the default value depends on the interval).
+pub(crate) fn get_time_axis_values(params: TimeAxisValueParams) -> TimeAxisValues {
+    let mut split_number = params.split_number;
+    if split_number == 0 {
+        split_number = 6;
+    }
+    if params.data_list.is_empty() {
+        return TimeAxisValues::default();
+    }
+    let mut min = params.data_list.iter().min().copied().unwrap_or_default();
+    let mut max = params.data_list.iter().max().copied().unwrap_or_default();
+    if params.boundary_gap {
+        let mut values = params.data_list.clone();
+        values.sort_unstable();
+        values.dedup();
+        if let Some(min_interval) = values.windows(2).map(|item| item[1] - item[0]).min() {
+            let half = (min_interval + 1) / 2;
+            min -= half;
+            max += half;
+        }
+    }
+    let mut interval = (TimeUnit::Year, 1);
+    let mut ticks = vec![];
+    for (unit, step) in TIME_INTERVALS.iter() {
+        let is_last = *unit == TimeUnit::Year && *step == 100;
+        // skip the interval which is too small, the max length of month and year is used
+        let max_unit_seconds = match unit {
+            TimeUnit::Month => 31 * SECONDS_PER_DAY,
+            TimeUnit::Year => 366 * SECONDS_PER_DAY,
+            _ => get_fixed_unit_seconds(*unit),
+        };
+        if !is_last && (max - min) / (max_unit_seconds * step) > split_number as i64 {
+            continue;
+        }
+        let values = get_time_ticks(min, max, *unit, *step);
+        if values.len() - 1 <= split_number || is_last {
+            interval = (*unit, *step);
+            ticks = values;
+            break;
+        }
+    }
+    let formatter = params.formatter.unwrap_or_else(|| {
+        match interval.0 {
+            TimeUnit::Second => "%H:%M:%S",
+            TimeUnit::Minute | TimeUnit::Hour => "%H:%M",
+            TimeUnit::Day => "%m-%d",
+            TimeUnit::Month => "%Y-%m",
+            TimeUnit::Year => "%Y",
+        }
+        .to_string()
+    });
+    TimeAxisValues {
+        data: ticks
+            .iter()
+            .map(|item| format_timestamp(*item, &formatter))
+            .collect(),
+        min: ticks[0],
+        max: ticks[ticks.len() - 1],
+        ticks,
     }
 }
 pub fn convert_to_points(values: &[(f32, f32)]) -> Vec<Point> {
@@ -369,8 +780,8 @@ mod tests {
     use crate::thousands_format_float;
 
     use super::{
-        convert_to_points, format_float, get_axis_values, get_box_of_points, AxisValueParams, Box,
-        Point,
+        check_log_axis_values, convert_to_points, format_float, format_timestamp, get_axis_values,
+        get_box_of_points, get_time_axis_values, AxisValueParams, Box, Point, TimeAxisValueParams,
     };
     use pretty_assertions::assert_eq;
 
@@ -439,6 +850,110 @@ mod tests {
         assert_eq!(50.0, values.get_offset_height(12.0, 100.0));
     }
 
+    #[test]
+    fn log_axis_values() {
+        let values = get_axis_values(AxisValueParams {
+            data_list: vec![3.0, 50.0, 12000.0],
+            reverse: Some(true),
+            log_base: Some(10.0),
+            ..Default::default()
+        });
+        assert_eq!(vec!["100k", "10k", "1k", "100", "10", "1"], values.data);
+        assert_eq!(1.0, values.min);
+        assert_eq!(100000.0, values.max);
+        assert_eq!(60.0, values.get_offset_height(100.0, 100.0));
+        assert_eq!(100.0, values.get_offset_height(0.0, 100.0));
+
+        let values = get_axis_values(AxisValueParams {
+            data_list: vec![0.02, 0.5, 100.0],
+            log_base: Some(10.0),
+            ..Default::default()
+        });
+        assert_eq!(vec!["0.01", "0.1", "1", "10", "100"], values.data);
+
+        let values = get_axis_values(AxisValueParams {
+            data_list: vec![0.00000002, 0.0005, 1.0],
+            log_base: Some(10.0),
+            ..Default::default()
+        });
+        assert_eq!(
+            vec!["1e-8", "1e-7", "1e-6", "1e-5", "1e-4", "0.001", "0.01", "0.1", "1"],
+            values.data
+        );
+        let values = get_axis_values(AxisValueParams {
+            data_list: vec![0.0003, 1.0],
+            log_base: Some(2.0),
+            ..Default::default()
+        });
+        assert_eq!("2.44e-4", values.data[0]);
+
+        assert_eq!(true, check_log_axis_values(&[1.0, 10.0], 10.0).is_ok());
+        assert_eq!(
+            "Params is invalid: log axis does not support zero or negative value, got 0",
+            check_log_axis_values(&[1.0, 0.0], 10.0)
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(true, check_log_axis_values(&[1.0, 10.0], 1.0).is_err());
+    }
+
+    #[test]
+    fn format_time() {
+        assert_eq!(
+            "2023-11-14 22:13:20",
+            format_timestamp(1700000000, "%Y-%m-%d %H:%M:%S")
+        );
+        assert_eq!(
+            "Nov 14, 23 100%",
+            format_timestamp(1700000000, "%b %d, %y 100%%")
+        );
+        assert_eq!("1969-01-01", format_timestamp(-86400 * 365, "%Y-%m-%d"));
+    }
+
+    #[test]
+    fn time_axis_values() {
+        let values = get_time_axis_values(TimeAxisValueParams {
+            data_list: vec![1700000000, 1700000000 + 5 * 3600],
+            ..Default::default()
+        });
+        assert_eq!(
+            vec!["22:00", "23:00", "00:00", "01:00", "02:00", "03:00", "04:00"],
+            values.data
+        );
+        assert_eq!(1699999200, values.min);
+        assert_eq!(1700020800, values.max);
+        assert_eq!(50.0, values.get_offset_width(1700010000, 100.0));
+
+        // 2023-01-15, 2023-06-10
+        let values = get_time_axis_values(TimeAxisValueParams {
+            data_list: vec![1673740800, 1686355200],
+            ..Default::default()
+        });
+        assert_eq!(
+            vec!["2023-01", "2023-02", "2023-03", "2023-04", "2023-05", "2023-06", "2023-07"],
+            values.data
+        );
+        assert_eq!(1672531200, values.min);
+        assert_eq!(1688169600, values.max);
+
+        let values = get_time_axis_values(TimeAxisValueParams {
+            data_list: vec![1673740800, 1686355200],
+            split_number: 2,
+            formatter: Some("%b".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(vec!["Jan", "Apr", "Jul"], values.data);
+
+        // the domain is padded by half day, 2023-11-01 - 2023-11-03
+        let values = get_time_axis_values(TimeAxisValueParams {
+            data_list: vec![1698796800, 1698883200, 1698969600],
+            boundary_gap: true,
+            ..Default::default()
+        });
+        assert_eq!(true, values.min <= 1698796800 - 43200);
+        assert_eq!(true, values.max >= 1698969600 + 43200);
+    }
+
     #[test]
     fn get_box() {
         let points: Vec<Point> = convert_to_points(&[
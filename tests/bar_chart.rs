@@ -196,3 +196,142 @@ fn bar_chart_mixin() {
         bar_chart.svg().unwrap()
     );
 }
+
+#[test]
+fn bar_chart_time_axis() {
+    let bar_chart = BarChart::from_json(
+        r###"{
+            "title_text": "Time Axis",
+            "legend_margin": {
+                "top": 35,
+                "bottom": 10
+            },
+            "x_axis_time": true,
+            "x_axis_time_format": "%b %d",
+            "series_list": [
+                {
+                    "name": "Email",
+                    "data": [120.0, 132.0, 101.0, 134.0, 90.0],
+                    "timestamps": [1698796800, 1699056000, 1699142400, 1699747200, 1700006400]
+                },
+                {
+                    "name": "Union Ads",
+                    "data": [220.0, 182.0, 191.0, 234.0, 290.0],
+                    "timestamps": [1698796800, 1699056000, 1699142400, 1699747200, 1700006400]
+                }
+            ]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/bar_chart/time_axis_json.svg"),
+        bar_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn bar_chart_time_axis_first_timestamp() {
+    // the first timestamp is the min of axis and the last one is the max
+    let bar_chart = BarChart::from_json(
+        r###"{
+            "title_text": "Time Axis",
+            "x_axis_time": true,
+            "series_list": [
+                {
+                    "name": "Email",
+                    "data": [120.0, 132.0, 101.0, 134.0, 90.0, 230.0, 210.0],
+                    "timestamps": [1698796800, 1698883200, 1698969600, 1699056000, 1699142400, 1699228800, 1699315200]
+                }
+            ]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/bar_chart/time_axis_first_timestamp_json.svg"),
+        bar_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn bar_chart_time_axis_float_timestamps() {
+    let get_json = |timestamps: &str| {
+        format!(
+            r###"{{
+                "x_axis_time": true,
+                "series_list": [
+                    {{
+                        "name": "Email",
+                        "data": [120.0, 132.0, 101.0],
+                        "timestamps": {timestamps}
+                    }}
+                ]
+            }}"###
+        )
+    };
+    // the whole float numbers are accepted
+    let bar_chart = BarChart::from_json(&get_json("[1698796800, 1698883200, 1698969600]")).unwrap();
+    let float_bar_chart =
+        BarChart::from_json(&get_json("[1.6987968e9, 1698883200.0, 1698969600]")).unwrap();
+    assert_eq!(
+        bar_chart.series_list[0].timestamps,
+        float_bar_chart.series_list[0].timestamps
+    );
+    assert_eq!(bar_chart.svg().unwrap(), float_bar_chart.svg().unwrap());
+
+    assert_eq!(
+        "Params is invalid: timestamps should be integers, 1698796800.5 is invalid",
+        BarChart::from_json(&get_json("[1698796800.5, 1698883200, 1698969600]"))
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "Params is invalid: timestamps should be integers, 1e+20 is invalid",
+        BarChart::from_json(&get_json("[1e20, 1698883200, 1698969600]"))
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "Params is invalid: timestamps should be integers, \"2023-11-01\" is invalid",
+        BarChart::from_json(&get_json(r#"["2023-11-01", 1698883200, 1698969600]"#))
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn bar_chart_accessible_table() {
+    let bar_chart = BarChart::from_json(
+        r###"{
+            "width": 630,
+            "height": 410,
+            "title_text": "Bar Chart",
+            "sub_title_text": "Fake Data",
+            "accessible_table": true,
+            "legend_align": "right",
+            "series_list": [
+                {
+                    "name": "Email",
+                    "data": [120.0, 132.0, null, 134.0, 90.0]
+                },
+                {
+                    "name": "Union Ads",
+                    "data": [220.0, 182.0, 191.0, 234.0, 290.0]
+                }
+            ],
+            "x_axis_data": [
+                "Mon",
+                "Tue",
+                "Wed",
+                "Thu",
+                "Fri"
+            ]
+        }"###,
+    )
+    .unwrap();
+    assert_eq!(
+        include_str!("../asset/bar_chart/accessible_table_json.svg"),
+        bar_chart.svg().unwrap()
+    );
+}
@@ -0,0 +1,76 @@
+use charts_rs::BoxPlotChart;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn box_plot_chart() {
+    let box_plot_chart = BoxPlotChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Latency",
+        "sub_title_text": "ms",
+        "legend_align": "right",
+        "theme": "grafana",
+        "y_axis_configs": [
+            {
+                "axis_formatter": "{c}ms"
+            }
+        ],
+        "series_list": [
+            {
+                "name": "GET",
+                "samples": [
+                    [12, 15, 18, 20, 22, 25, 27, 30, 70],
+                    [30, 32, 35, 36, 38, 40, 41, 45],
+                    [2, 20, 21, 23, 24, 26, 28, 29, 55, 60]
+                ]
+            },
+            {
+                "name": "POST",
+                "data": [
+                    20, 30, 35, 40, 90,
+                    35, 40, 48, 52, 60,
+                    25, 32, 38, 45, 58
+                ]
+            }
+        ],
+        "x_axis_data": [
+            "us-east",
+            "eu-west",
+            "ap-south"
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/box_plot_chart/basic_json.svg"),
+        box_plot_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn box_plot_chart_log_axis() {
+    let box_plot_chart = BoxPlotChart::from_json(
+        r###"{
+            "y_axis_configs": [
+                {
+                    "axis_log_base": 10
+                }
+            ],
+            "series_list": [
+                {
+                    "name": "Latency",
+                    "samples": [[12, 120, 1200, 150, 90]]
+                }
+            ],
+            "x_axis_data": ["api"]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Params is invalid: box plot chart does not support log axis, got 10",
+        box_plot_chart.svg().unwrap_err().to_string()
+    );
+}
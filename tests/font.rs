@@ -0,0 +1,72 @@
+use charts_rs::{
+    add_font, get_font_families, get_or_try_init_fonts, measure_text_width_family, remove_font,
+    FontError,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn font_runtime_register() {
+    let data = include_bytes!("../asset/font/Tuffy.ttf") as &[u8];
+    let text = "Hello World!";
+    let default_width = measure_text_width_family("Tuffy", 14.0, text)
+        .unwrap()
+        .width();
+
+    assert_eq!(vec!["Tuffy".to_string()], add_font(data).unwrap());
+    assert_eq!("Roboto,Tuffy", get_font_families().unwrap().join(","));
+    assert_ne!(
+        default_width,
+        measure_text_width_family("Tuffy", 14.0, text)
+            .unwrap()
+            .width()
+    );
+
+    // duplicate font
+    let err = add_font(data).unwrap_err();
+    assert_eq!(true, matches!(err, FontError::FontExists { .. }));
+    assert_eq!("Error font: Tuffy exists", err.to_string());
+    // the loaded fonts are ignored
+    let fonts = get_or_try_init_fonts(Some(vec![data])).unwrap();
+    assert_eq!(2, get_font_families().unwrap().len());
+    assert_eq!(true, fonts.contains_key("Tuffy"));
+    assert_eq!(
+        true,
+        std::sync::Arc::ptr_eq(&fonts, &get_or_try_init_fonts(None).unwrap())
+    );
+
+    remove_font("Tuffy").unwrap();
+    assert_eq!("Roboto", get_font_families().unwrap().join(","));
+    let fonts = get_or_try_init_fonts(None).unwrap();
+    assert_eq!(1, fonts.len());
+    assert_eq!(true, fonts.contains_key("Roboto"));
+    assert_eq!(
+        default_width,
+        measure_text_width_family("Tuffy", 14.0, text)
+            .unwrap()
+            .width()
+    );
+    assert_eq!(
+        "Error font: Tuffy not found",
+        remove_font("Tuffy").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Error font: default font Roboto can not be removed",
+        remove_font("Roboto").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Error parse font: font data is invalid",
+        add_font(b"invalid font").unwrap_err().to_string()
+    );
+
+    #[cfg(feature = "system-fonts")]
+    {
+        let families = charts_rs::load_system_fonts();
+        assert_eq!(false, families.contains(&"Roboto".to_string()));
+        assert_eq!(families.len() + 1, get_font_families().unwrap().len());
+        let all_families = get_font_families().unwrap();
+        assert_eq!(
+            true,
+            families.iter().all(|family| all_families.contains(family))
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use charts_rs::{add_font, measure_text_width_family, BarChart};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn font_fallback() {
+    let data = include_bytes!("../asset/font/Tuffy.ttf") as &[u8];
+    add_font(data).unwrap();
+
+    // roboto doesn't contain the arrow glyph
+    let arrow = "→";
+    let arrow_width = measure_text_width_family("Tuffy", 14.0, arrow)
+        .unwrap()
+        .width();
+    assert_ne!(
+        arrow_width,
+        measure_text_width_family("Roboto", 14.0, arrow)
+            .unwrap()
+            .width()
+    );
+    assert_eq!(
+        arrow_width,
+        measure_text_width_family("Roboto, Tuffy", 14.0, arrow)
+            .unwrap()
+            .width()
+    );
+    assert_eq!(
+        measure_text_width_family("Roboto", 14.0, "Mon")
+            .unwrap()
+            .width(),
+        measure_text_width_family("Roboto, Tuffy", 14.0, "Mon")
+            .unwrap()
+            .width()
+    );
+    // the mixed text uses both fonts
+    let mixed_width = measure_text_width_family("Roboto, Tuffy", 14.0, "Mon→Tue")
+        .unwrap()
+        .width();
+    assert_ne!(
+        mixed_width,
+        measure_text_width_family("Roboto", 14.0, "Mon→Tue")
+            .unwrap()
+            .width()
+    );
+    assert_ne!(
+        mixed_width,
+        measure_text_width_family("Tuffy", 14.0, "Mon→Tue")
+            .unwrap()
+            .width()
+    );
+
+    let mut bar_chart = BarChart::new(
+        vec![("Email", vec![120.0, 132.0]).into()],
+        vec!["Mon→Tue".to_string(), "Wed→Thu".to_string()],
+    );
+    bar_chart.font_family = "Roboto, Tuffy".to_string();
+    assert_eq!(
+        true,
+        bar_chart
+            .svg()
+            .unwrap()
+            .contains(r#"font-family="Roboto, Tuffy""#)
+    );
+}
@@ -0,0 +1,80 @@
+use charts_rs::FunnelChart;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn funnel_chart() {
+    let funnel_chart = FunnelChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Conversion",
+        "title_align": "left",
+        "legend_align": "right",
+        "theme": "grafana",
+        "sort": "none",
+        "gap": 4,
+        "interactive": true,
+        "series_label_formatter": "{b}: {c} ({d})",
+        "series_list": [
+            {
+                "name": "Visit",
+                "data": [1200]
+            },
+            {
+                "name": "Signup",
+                "data": [620]
+            },
+            {
+                "name": "Trial",
+                "data": [410]
+            },
+            {
+                "name": "Paid",
+                "data": [95]
+            }
+        ],
+        "x_axis_data": [
+            "Landing",
+            "Register",
+            "Activate",
+            "Purchase"
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/funnel_chart/basic_json.svg"),
+        funnel_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn funnel_chart_unsorted_input() {
+    let funnel_chart = FunnelChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Conversion",
+        "series_list": [
+            {
+                "name": "Order",
+                "data": [20]
+            },
+            {
+                "name": "Visit",
+                "data": [120]
+            }
+        ]
+    }"###,
+    )
+    .unwrap();
+    let svg = funnel_chart.svg().unwrap();
+    // the percentage is relative to the largest stage on the top
+    assert_eq!(true, svg.contains("Visit: 100%"));
+    assert_eq!(true, svg.contains("Order: 16.7%"));
+    assert_eq!(
+        include_str!("../asset/funnel_chart/unsorted_input_json.svg"),
+        svg
+    );
+}
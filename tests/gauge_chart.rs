@@ -0,0 +1,55 @@
+use charts_rs::GaugeChart;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn gauge_chart() {
+    let gauge_chart = GaugeChart::from_json(
+        r###"{
+        "width": 400,
+        "height": 360,
+        "title_text": "Error Budget",
+        "theme": "grafana",
+        "start_angle": -120,
+        "end_angle": 120,
+        "min": 0,
+        "max": 50,
+        "split_number": 5,
+        "minor_split_number": 2,
+        "band_width": 20,
+        "interactive": true,
+        "series_label_formatter": "{c}ms",
+        "value_font_size": 24,
+        "bands": [
+            {
+                "value": 20,
+                "color": "#73BF69"
+            },
+            {
+                "value": 35,
+                "color": "#FADE2A"
+            },
+            {
+                "value": 50,
+                "color": "#F2495C"
+            }
+        ],
+        "y_axis_configs": [
+            {
+                "axis_formatter": "{c}ms"
+            }
+        ],
+        "series_list": [
+            {
+                "name": "p99",
+                "data": [27.5]
+            }
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/gauge_chart/basic_json.svg"),
+        gauge_chart.svg().unwrap()
+    );
+}
@@ -40,3 +40,133 @@ fn heatmap_chart() {
         heatmap_chart.svg().unwrap()
     );
 }
+
+#[test]
+fn heatmap_chart_calendar() {
+    let heatmap_chart = HeatmapChart::from_json(
+        r###"{
+            "theme": "dark",
+            "width": 600,
+            "height": 220,
+            "title_text": "Deployments",
+            "calendar": {
+                "start": "2024-02-15",
+                "end": "2024-06-30",
+                "cell_gap": 3
+            },
+            "series": {
+                "max": 8,
+                "min_color": "#1f3b2d",
+                "max_color": "#39d353",
+                "data": [
+                    ["2024-02-15", 2],
+                    ["2024-02-20", 5],
+                    ["2024-02-29", 8],
+                    ["2024-03-04", 1],
+                    ["2024-03-12", 6],
+                    ["2024-03-13", 7],
+                    ["2024-03-27", 3],
+                    ["2024-04-01", 4],
+                    ["2024-04-15", 8],
+                    ["2024-04-16", 2],
+                    ["2024-05-06", 5],
+                    ["2024-05-21", 6],
+                    ["2024-05-22", 1],
+                    ["2024-06-10", 7],
+                    ["2024-06-28", 3],
+                    ["2024-07-01", 9]
+                ]
+            }
+        }"###,
+    )
+    .unwrap();
+    assert_eq!(
+        include_str!("../asset/heatmap_chart/calendar_json.svg"),
+        heatmap_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn heatmap_chart_color_scale() {
+    let heatmap_chart = HeatmapChart::from_json(
+        r###"{
+            "theme": "dark",
+            "width": 600,
+            "height": 300,
+            "title_text": "Latency",
+            "y_axis_data": ["p50", "p75", "p90", "p95", "p99"],
+            "x_axis_data": ["Mon", "Tue", "Wed", "Thu", "Fri"],
+            "series": {
+                "color_scale": {
+                    "palette": "viridis",
+                    "min": 0,
+                    "max": 400,
+                    "steps": 5
+                },
+                "data": [
+                    [0, 45], [1, 52], [2, 48], [3, 61], [4, 55],
+                    [5, 80], [6, 88], [7, 84], [8, 97], [9, 92],
+                    [10, 120], [11, 135], [12, 128], [13, 180], [14, 150],
+                    [15, 210], [16, 230], [17, 205], [18, 290], [19, 240],
+                    [20, 260], [21, 310], [22, 290], [23, 395], [24, 330]
+                ]
+            }
+        }"###,
+    )
+    .unwrap();
+    assert_eq!(
+        include_str!("../asset/heatmap_chart/color_scale_json.svg"),
+        heatmap_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn heatmap_chart_visual_map() {
+    let heatmap_chart = HeatmapChart::from_json(
+        r###"{
+            "width": 600,
+            "height": 240,
+            "title_text": "Commits",
+            "title_align": "left",
+            "calendar": {
+                "start": "2024-03-01",
+                "end": "2024-07-31"
+            },
+            "visual_map": {
+                "position": "bottom",
+                "align": "right",
+                "length": 180
+            },
+            "series": {
+                "color_scale": {
+                    "colors": ["#9be9a8", "#216e39"],
+                    "min": 0,
+                    "max": 20,
+                    "steps": 4
+                },
+                "data": [
+                    ["2024-03-01", 3],
+                    ["2024-03-05", 12],
+                    ["2024-03-06", 8],
+                    ["2024-03-18", 19],
+                    ["2024-04-02", 6],
+                    ["2024-04-03", 15],
+                    ["2024-04-22", 1],
+                    ["2024-05-09", 11],
+                    ["2024-05-10", 20],
+                    ["2024-05-28", 4],
+                    ["2024-06-12", 9],
+                    ["2024-06-13", 16],
+                    ["2024-07-01", 7],
+                    ["2024-07-15", 13],
+                    ["2024-07-30", 2]
+                ]
+            }
+        }"###,
+    )
+    .unwrap();
+    assert_eq!(
+        include_str!("../asset/heatmap_chart/visual_map_json.svg"),
+        heatmap_chart.svg().unwrap()
+    );
+}
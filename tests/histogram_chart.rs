@@ -0,0 +1,94 @@
+use charts_rs::HistogramChart;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn histogram_chart() {
+    let histogram_chart = HistogramChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Response Size",
+        "title_align": "left",
+        "legend_align": "right",
+        "legend_category": "rect",
+        "theme": "dark",
+        "bin_count": 8,
+        "interactive": true,
+        "series_list": [
+            {
+                "name": "Gzip",
+                "data": [
+                    1.2, 2.5, 3.1, 2.8, 4.2, 3.7, 2.2, 3.3, 2.9, 3.5,
+                    4.8, 3.9, 2.4, 3.0, 3.6, 5.5, 2.7, 3.4, 4.1, 3.2
+                ]
+            },
+            {
+                "name": "Raw",
+                "data": [
+                    4.5, 5.2, 6.1, 5.8, 7.4, 6.6, 5.1, 6.3, 7.9, 6.9,
+                    8.2, 5.5, 6.0, 7.1, 6.4, 9.6, 5.9, 6.8, 7.5, 6.2
+                ]
+            }
+        ],
+        "x_axis_config": {
+            "axis_formatter": "{c}KB",
+            "axis_min": 0
+        }
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/histogram_chart/basic_json.svg"),
+        histogram_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn histogram_chart_empty_y_axis_configs() {
+    let histogram_chart = HistogramChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Response Size",
+        "y_axis_configs": [],
+        "series_list": [
+            {
+                "name": "Gzip",
+                "data": [1.2, 2.5, 3.1, 2.8, 4.2, 3.7, 2.2, 3.3]
+            }
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/histogram_chart/empty_y_axis_configs_json.svg"),
+        histogram_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn histogram_chart_log_axis() {
+    let histogram_chart = HistogramChart::from_json(
+        r###"{
+            "y_axis_configs": [
+                {
+                    "axis_log_base": 10
+                }
+            ],
+            "series_list": [
+                {
+                    "name": "Latency",
+                    "data": [12, 120, 1200]
+                }
+            ]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Params is invalid: histogram chart does not support log axis, got 10",
+        histogram_chart.svg().unwrap_err().to_string()
+    );
+}
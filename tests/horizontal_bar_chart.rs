@@ -123,3 +123,29 @@ fn horizontal_bar_chart_nil_value() {
         horizontal_bar_chart.svg().unwrap()
     );
 }
+
+#[test]
+fn horizontal_bar_chart_log_axis() {
+    let horizontal_bar_chart = HorizontalBarChart::from_json(
+        r###"{
+            "y_axis_configs": [
+                {
+                    "axis_log_base": 10
+                }
+            ],
+            "series_list": [
+                {
+                    "name": "Latency",
+                    "data": [12, 120, 1200]
+                }
+            ],
+            "x_axis_data": ["p50", "p90", "p99"]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Params is invalid: horizontal bar chart does not support log axis, got 10",
+        horizontal_bar_chart.svg().unwrap_err().to_string()
+    );
+}
@@ -116,3 +116,150 @@ fn line_chart_nil_value() {
         line_chart.svg().unwrap()
     );
 }
+
+#[test]
+fn line_chart_log_axis_invalid_value() {
+    let line_chart = LineChart::from_json(
+        r###"{
+            "y_axis_configs": [
+                {
+                    "axis_log_base": 10
+                }
+            ],
+            "series_list": [
+                {
+                    "name": "Latency",
+                    "data": [12.0, 0.0, 350.0]
+                }
+            ],
+            "x_axis_data": ["Mon", "Tue", "Wed"]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Params is invalid: log axis does not support zero or negative value, got 0",
+        line_chart.svg().unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn line_chart_animation() {
+    let line_chart = LineChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Line Chart",
+        "legend_align": "right",
+        "interactive": true,
+        "animation": true,
+        "series_smooth": true,
+        "series_fill": true,
+        "series_list": [
+            {
+                "name": "Email",
+                "data": [120.0, 132.0, 101.0, 134.0, 90.0, 230.0, 210.0]
+            },
+            {
+                "name": "Union Ads",
+                "stroke_dash_array": "4,2",
+                "data": [220.0, 182.0, 191.0, 234.0, 290.0, 330.0, 310.0]
+            }
+        ],
+        "x_axis_data": [
+            "Mon",
+            "Tue",
+            "Wed",
+            "Thu",
+            "Fri",
+            "Sat",
+            "Sun"
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/line_chart/animation_json.svg"),
+        line_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn line_chart_pattern() {
+    let line_chart = LineChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Line Chart",
+        "legend_align": "right",
+        "legend_category": "rect",
+        "series_fill": true,
+        "series_patterns": ["cross_hatch", "dots"],
+        "series_list": [
+            {
+                "name": "Email",
+                "data": [120.0, 132.0, 101.0, 134.0, 90.0, 230.0, 210.0]
+            },
+            {
+                "name": "Union Ads",
+                "pattern": "diagonal_hatch",
+                "data": [220.0, 182.0, 191.0, 234.0, 290.0, 330.0, 310.0]
+            }
+        ],
+        "x_axis_data": [
+            "Mon",
+            "Tue",
+            "Wed",
+            "Thu",
+            "Fri",
+            "Sat",
+            "Sun"
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/line_chart/pattern_json.svg"),
+        line_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn line_chart_trend_line() {
+    let line_chart = LineChart::from_json(
+        r###"{
+            "title_text": "Daily active users",
+            "title_align": "left",
+            "legend_align": "right",
+            "x_axis_time": true,
+            "x_axis_time_format": "%b %d",
+            "series_list": [
+                {
+                    "name": "Users",
+                    "data": [120, 131, 139, 172, 180, 215, 236, 251, 302, 329, 398],
+                    "timestamps": [
+                        1704067200, 1704153600, 1704240000, 1704412800, 1704499200, 1704672000,
+                        1704758400, 1704844800, 1705017600, 1705104000, 1705276800
+                    ],
+                    "trend_lines": [
+                        {
+                            "category": "exponential",
+                            "label_show": true
+                        },
+                        {
+                            "category": "moving_average",
+                            "period": 3
+                        }
+                    ]
+                }
+            ]
+        }"###,
+    )
+    .unwrap();
+    assert_eq!(
+        include_str!("../asset/line_chart/trend_line_json.svg"),
+        line_chart.svg().unwrap()
+    );
+}
@@ -303,3 +303,281 @@ fn multi_chart() {
         multi_chart.svg().unwrap()
     );
 }
+
+#[test]
+fn multi_chart_heatmap() {
+    let mut multi_chart = MultiChart::from_json(
+        r###"{
+        "child_charts": [
+            {
+                "type": "bar",
+                "title_text": "Bar Chart",
+                "x_axis_data": ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+                "series_list": [
+                    {
+                        "name": "Email",
+                        "data": [120, 132, 101, 134, 90, 230, 210]
+                    },
+                    {
+                        "name": "Union Ads",
+                        "data": [220, 182, 191, 234, 290, 330, 310]
+                    }
+                ]
+            },
+            {
+                "type": "heatmap",
+                "title_text": "Heatmap Chart",
+                "width": 600,
+                "height": 300,
+                "y_axis_data": ["Friday", "Thursday", "Wednesday", "Tuesday", "Monday"],
+                "x_axis_data": ["12a", "3a", "6a", "9a", "12p", "3p", "6p", "9p"],
+                "series": {
+                    "data": [
+                        [0, 9.0],
+                        [1, 3.0],
+                        [7, 3.0],
+                        [12, 3.0],
+                        [20, 12.0],
+                        [28, 10.0],
+                        [31, 8.0],
+                        [35, 4.0],
+                        [39, 2.0]
+                    ]
+                }
+            }
+        ],
+        "theme": "grafana"
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/multi_chart/heatmap_json.svg"),
+        multi_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn multi_chart_grid() {
+    let mut multi_chart = MultiChart::from_json(
+        r###"{
+        "gap": 10,
+        "margin": {
+            "left": 10,
+            "top": 10,
+            "right": 10,
+            "bottom": 10
+        },
+        "theme": "grafana",
+        "grid": {
+            "width": 800,
+            "height": 600,
+            "columns": [1, 1],
+            "rows": [1, 1]
+        },
+        "child_charts": [
+            {
+                "type": "pie",
+                "title_text": "Pie Chart",
+                "row": 0,
+                "column": 0,
+                "row_span": 2,
+                "series_list": [
+                    {
+                        "name": "Search Engine",
+                        "data": [1048]
+                    },
+                    {
+                        "name": "Direct",
+                        "data": [735]
+                    },
+                    {
+                        "name": "Email",
+                        "data": [580]
+                    }
+                ]
+            },
+            {
+                "type": "bar",
+                "title_text": "Bar Chart",
+                "series_list": [
+                    {
+                        "name": "Email",
+                        "data": [120, 132, 101, 134, 90]
+                    }
+                ],
+                "x_axis_data": ["Mon", "Tue", "Wed", "Thu", "Fri"]
+            },
+            {
+                "type": "line",
+                "title_text": "Line Chart",
+                "series_list": [
+                    {
+                        "name": "Email",
+                        "data": [120, 132, 101, 134, 90]
+                    }
+                ],
+                "x_axis_data": ["Mon", "Tue", "Wed", "Thu", "Fri"]
+            }
+        ]
+    }"###,
+    )
+    .unwrap();
+    assert_eq!(
+        include_str!("../asset/multi_chart/grid_json.svg"),
+        multi_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn multi_chart_gradient() {
+    let mut multi_chart = MultiChart::from_json(
+        r###"{
+        "child_charts": [
+            {
+                "type": "line",
+                "title_text": "Line Chart",
+                "series_fill": true,
+                "x_axis_data": ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+                "series_list": [
+                    {
+                        "name": "Email",
+                        "data": [120, 132, 101, 134, 90, 230, 210],
+                        "gradient": {
+                            "stops": [
+                                {"offset": 0, "color": "#5470c6"},
+                                {"offset": 1, "color": "#ffffff"}
+                            ]
+                        }
+                    }
+                ]
+            },
+            {
+                "type": "pie",
+                "title_text": "Pie Chart",
+                "width": 600,
+                "height": 400,
+                "series_list": [
+                    {
+                        "name": "Rose 1",
+                        "data": [40],
+                        "gradient": {
+                            "category": "radial",
+                            "stops": [
+                                {"offset": 0, "color": "#91cc75"},
+                                {"offset": 1, "color": "#3ba272"}
+                            ]
+                        }
+                    },
+                    {
+                        "name": "Rose 2",
+                        "data": [38]
+                    },
+                    {
+                        "name": "Rose 3",
+                        "data": [32]
+                    }
+                ]
+            }
+        ],
+        "theme": "light"
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/multi_chart/gradient_json.svg"),
+        multi_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn multi_chart_gradient_defs() {
+    let gradient = r###"{
+        "stops": [
+            {"offset": 0, "color": "#5470c6"},
+            {"offset": 1, "color": "#ffffff"}
+        ]
+    }"###;
+    let mut multi_chart = MultiChart::from_json(&format!(
+        r###"{{
+        "child_charts": [
+            {{
+                "type": "line",
+                "title_text": "Line Chart",
+                "series_fill": true,
+                "x_axis_data": ["Mon", "Tue", "Wed"],
+                "series_list": [
+                    {{
+                        "name": "Email",
+                        "data": [120, 132, 101],
+                        "gradient": {gradient}
+                    }}
+                ]
+            }},
+            {{
+                "type": "bar",
+                "title_text": "Bar Chart",
+                "x_axis_data": ["Mon", "Tue", "Wed"],
+                "series_list": [
+                    {{
+                        "name": "Email",
+                        "data": [120, 132, 101],
+                        "gradient": {gradient}
+                    }}
+                ]
+            }}
+        ],
+        "theme": "light"
+        }}"###
+    ))
+    .unwrap();
+
+    let svg = multi_chart.svg().unwrap();
+    assert_eq!(1, svg.matches("<defs>").count());
+    let ids: Vec<&str> = svg
+        .split(" id=\"")
+        .skip(1)
+        .filter_map(|item| item.split('"').next())
+        .collect();
+    assert_eq!(1, ids.len());
+    for id in ids.iter() {
+        assert_eq!(1, svg.matches(&format!(" id=\"{id}\"")).count());
+        // the area of line and three bars
+        assert_eq!(4, svg.matches(&format!("url(#{id})")).count());
+    }
+}
+
+#[test]
+fn multi_chart_pattern_defs() {
+    let bar_chart = r###"{
+        "type": "bar",
+        "x_axis_data": ["Mon", "Tue", "Wed"],
+        "series_list": [
+            {
+                "name": "Email",
+                "data": [120, 132, 101],
+                "pattern": "dots"
+            }
+        ]
+    }"###;
+    let mut multi_chart = MultiChart::from_json(&format!(
+        r###"{{
+        "child_charts": [{bar_chart}, {bar_chart}],
+        "theme": "light"
+        }}"###
+    ))
+    .unwrap();
+
+    let svg = multi_chart.svg().unwrap();
+    assert_eq!(1, svg.matches("<defs>").count());
+    assert_eq!(1, svg.matches("<pattern ").count());
+    let id = svg
+        .split("<pattern id=\"")
+        .nth(1)
+        .and_then(|item| item.split('"').next())
+        .unwrap();
+    assert_eq!(1, svg.matches(&format!(" id=\"{id}\"")).count());
+    // three bars of each chart
+    assert_eq!(6, svg.matches(&format!("url(#{id})")).count());
+}
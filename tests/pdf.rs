@@ -0,0 +1,31 @@
+#[test]
+#[cfg(feature = "pdf-encoder")]
+fn cff_font_pdf() {
+    use charts_rs::{add_font, svg_to_pdf, BarChart};
+    use pretty_assertions::assert_eq;
+
+    let data = include_bytes!("../asset/font/CffSquare.otf") as &[u8];
+    assert_eq!(vec!["Cff Square".to_string()], add_font(data).unwrap());
+
+    let mut bar_chart = BarChart::new(
+        vec![("Email", vec![120.0, 132.0, 101.0]).into()],
+        vec!["Mon".to_string(), "Tue".to_string(), "Wed".to_string()],
+    );
+    bar_chart.font_family = "Cff Square".to_string();
+    let buf = svg_to_pdf(&bar_chart.svg().unwrap()).unwrap();
+    let count = |pattern: &str| {
+        buf.windows(pattern.len())
+            .filter(|item| *item == pattern.as_bytes())
+            .count()
+    };
+    // the cff font is embedded as opentype font program
+    assert_eq!(1, count("/FontFile3"));
+    assert_eq!(1, count("/Subtype /OpenType"));
+    assert_eq!(
+        1,
+        count("/Subtype /CIDFontType0 /BaseFont /CffSquare-Regular")
+    );
+    // the glyph id map is only for truetype font
+    assert_eq!(0, count("/FontFile2"));
+    assert_eq!(0, count("/CIDToGIDMap"));
+}
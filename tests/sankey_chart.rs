@@ -0,0 +1,63 @@
+use charts_rs::SankeyChart;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn sankey_chart() {
+    let sankey_chart = SankeyChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Energy",
+        "title_align": "left",
+        "theme": "dark",
+        "node_width": 12,
+        "node_gap": 16,
+        "interactive": true,
+        "series_label_formatter": "{a} ({c})",
+        "nodes": ["coal", "gas", "solar"],
+        "links": [
+            {
+                "source": "coal",
+                "target": "electricity",
+                "value": 40
+            },
+            {
+                "source": "gas",
+                "target": "electricity",
+                "value": 25
+            },
+            {
+                "source": "gas",
+                "target": "heat",
+                "value": 30
+            },
+            {
+                "source": "solar",
+                "target": "electricity",
+                "value": 15
+            },
+            {
+                "source": "electricity",
+                "target": "homes",
+                "value": 45
+            },
+            {
+                "source": "electricity",
+                "target": "industry",
+                "value": 35
+            },
+            {
+                "source": "heat",
+                "target": "homes",
+                "value": 30
+            }
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/sankey_chart/basic_json.svg"),
+        sankey_chart.svg().unwrap()
+    );
+}
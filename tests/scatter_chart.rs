@@ -59,3 +59,154 @@ fn scatter_chart() {
         scatter_chart.svg().unwrap()
     );
 }
+
+#[test]
+fn scatter_chart_bubble() {
+    let scatter_chart = ScatterChart::from_json(
+        r###"{
+            "width": 630,
+            "height": 410,
+            "theme": "dark",
+            "title_text": "Store revenue by visits and conversion",
+            "title_align": "left",
+            "legend_align": "right",
+            "y_axis_configs": [
+                {
+                    "axis_min": 0,
+                    "axis_max": 5,
+                    "axis_split_number": 5,
+                    "axis_formatter": "{c}%"
+                }
+            ],
+            "x_axis_config": {
+                "axis_min": 0,
+                "axis_max": 100,
+                "axis_split_number": 5,
+                "axis_formatter": "{c}k"
+            },
+            "series_list": [
+                {
+                    "name": "Online",
+                    "data": [
+                        82.0, 1.8, 120.0, 64.5, 2.6, 150.0, 45.0, 3.1, 95.0, 30.2, 4.2, 88.0,
+                        91.0, 1.2, 60.0
+                    ]
+                },
+                {
+                    "name": "Retail",
+                    "data": [
+                        12.0, 3.8, 40.0, 24.5, 2.2, 35.0, 18.0, 4.5, 62.0, 8.2, 1.5, 10.0
+                    ]
+                }
+            ],
+            "series_bubble": {
+                "min_radius": 3,
+                "max_radius": 30,
+                "min_size": 0,
+                "max_size": 200,
+                "legend": {
+                    "position": "right",
+                    "formatter": "${c}k"
+                }
+            }
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/scatter_chart/bubble_json.svg"),
+        scatter_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn scatter_chart_log_axis() {
+    let scatter_chart = ScatterChart::from_json(
+        r###"{
+            "title_text": "Request size and latency",
+            "title_align": "left",
+            "legend_align": "right",
+            "y_axis_configs": [
+                {
+                    "axis_log_base": 10,
+                    "axis_formatter": "{c}ms"
+                }
+            ],
+            "x_axis_config": {
+                "axis_min": 0,
+                "axis_max": 500,
+                "axis_split_number": 5,
+                "axis_formatter": "{c}kb"
+            },
+            "series_list": [
+                {
+                    "name": "Cache",
+                    "data": [12, 1.2, 48, 2.5, 96, 3.1, 150, 4.8, 210, 6.2, 320, 9.5]
+                },
+                {
+                    "name": "Origin",
+                    "data": [20, 35, 80, 120, 160, 380, 260, 720, 340, 1500, 460, 4200]
+                }
+            ]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/scatter_chart/log_axis_json.svg"),
+        scatter_chart.svg().unwrap()
+    );
+}
+
+#[test]
+fn scatter_chart_log_axis_invalid_value() {
+    let scatter_chart = ScatterChart::from_json(
+        r###"{
+            "y_axis_configs": [
+                {
+                    "axis_log_base": 10
+                }
+            ],
+            "series_list": [
+                {
+                    "name": "Latency",
+                    "data": [1, 12, 2, 0, 3, 350]
+                }
+            ]
+        }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Params is invalid: log axis does not support zero or negative value, got 0",
+        scatter_chart.svg().unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn scatter_chart_accessible_table() {
+    let scatter_chart = ScatterChart::from_json(
+        r###"{
+            "width": 630,
+            "height": 410,
+            "title_text": "Height and weight",
+            "accessible_table": true,
+            "series_list": [
+                {
+                    "name": "Female",
+                    "data": [161.2, 51.6, 167.5, 59.0, 159.5, 49.2]
+                },
+                {
+                    "name": "Male",
+                    "data": [174.0, 65.6, 175.3, 71.8]
+                }
+            ],
+            "series_symbol_sizes": [6, 6]
+        }"###,
+    )
+    .unwrap();
+    assert_eq!(
+        include_str!("../asset/scatter_chart/accessible_table_json.svg"),
+        scatter_chart.svg().unwrap()
+    );
+}
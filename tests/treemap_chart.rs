@@ -0,0 +1,70 @@
+use charts_rs::TreemapChart;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn treemap_chart() {
+    let treemap_chart = TreemapChart::from_json(
+        r###"{
+        "width": 630,
+        "height": 410,
+        "title_text": "Cloud Cost",
+        "title_align": "left",
+        "theme": "dark",
+        "parent_header": true,
+        "node_gap": 3,
+        "interactive": true,
+        "series_label_formatter": "{a} ({d})",
+        "data": [
+            {
+                "name": "compute",
+                "children": [
+                    {
+                        "name": "api",
+                        "value": 3200
+                    },
+                    {
+                        "name": "workers",
+                        "value": 2100
+                    },
+                    {
+                        "name": "batch",
+                        "children": [
+                            {
+                                "name": "nightly",
+                                "value": 600
+                            },
+                            {
+                                "name": "reports",
+                                "value": 350
+                            }
+                        ]
+                    }
+                ]
+            },
+            {
+                "name": "storage",
+                "children": [
+                    {
+                        "name": "objects",
+                        "value": 1800
+                    },
+                    {
+                        "name": "backups",
+                        "value": 900
+                    }
+                ]
+            },
+            {
+                "name": "network",
+                "value": 1200
+            }
+        ]
+    }"###,
+    )
+    .unwrap();
+
+    assert_eq!(
+        include_str!("../asset/treemap_chart/basic_json.svg"),
+        treemap_chart.svg().unwrap()
+    );
+}